@@ -0,0 +1,467 @@
+use std::marker::PhantomData;
+
+use leptos::wasm_bindgen::JsCast;
+use leptos::{ev, html, prelude::*};
+use leptos_node_ref::AnyNodeRef;
+use radix_leptos_collapsible::primitive as CollapsiblePrimitive;
+use radix_leptos_collection::{primitive as Collection, use_collection};
+use radix_leptos_context::create_context;
+use radix_leptos_direction::use_direction;
+pub use radix_leptos_direction::Direction;
+use radix_leptos_id::use_id;
+use radix_leptos_primitive::Primitive;
+pub use radix_leptos_roving_focus::Orientation;
+use radix_leptos_use_controllable_state::{
+    use_controllable_optional_state, use_controllable_state, UseControllableOptionalStateParams,
+    UseControllableStateParams,
+};
+
+const ACCORDION_KEYS: [&str; 6] = [
+    "Home",
+    "End",
+    "ArrowDown",
+    "ArrowUp",
+    "ArrowLeft",
+    "ArrowRight",
+];
+
+/* -------------------------------------------------------------------------------------------------
+ * Accordion
+ * -----------------------------------------------------------------------------------------------*/
+
+const ACCORDION_NAME: &str = "Accordion";
+
+/// Whether one or several items of an `Accordion` can be open at the same time.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum AccordionType {
+    #[default]
+    Single,
+    Multiple,
+}
+
+#[derive(Clone, Debug)]
+struct ItemData {
+    disabled: bool,
+}
+
+const ITEM_DATA_PHANTOM: PhantomData<ItemData> = PhantomData;
+
+#[derive(Clone, Copy)]
+struct AccordionContextValue {
+    disabled: Signal<bool>,
+    direction: Signal<Direction>,
+    orientation: Signal<Orientation>,
+}
+
+create_context!(
+    context_type: AccordionContextValue,
+    provider: AccordionProvider,
+    hook: use_accordion_context,
+    root: ACCORDION_NAME
+);
+
+#[derive(Clone, Copy)]
+struct AccordionValueContextValue {
+    /// Values of the open items.
+    value: Signal<Vec<String>>,
+    on_item_open: Callback<String>,
+    on_item_close: Callback<String>,
+    /// Whether an open item can be closed without opening another.
+    collapsible: Signal<bool>,
+}
+
+create_context!(
+    context_type: AccordionValueContextValue,
+    provider: AccordionValueProvider,
+    hook: use_accordion_value_context,
+    root: ACCORDION_NAME
+);
+
+/// A vertically stacked set of interactive headings that each reveal a section of content.
+#[component]
+#[allow(non_snake_case)]
+pub fn Accordion(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// Whether one or several items can be open at the same time. Defaults to `Single`.
+    #[prop(optional, into)]
+    r#type: AccordionType,
+    /// The open item in `Single` mode.
+    #[prop(optional, into)]
+    value: MaybeProp<Option<String>>,
+    #[prop(optional, into)] default_value: MaybeProp<String>,
+    #[prop(optional, into)] on_value_change: Option<Callback<Option<String>>>,
+    /// The open items in `Multiple` mode.
+    #[prop(optional, into)]
+    values: MaybeProp<Vec<String>>,
+    #[prop(optional, into)] default_values: MaybeProp<Vec<String>>,
+    #[prop(optional, into)] on_values_change: Option<Callback<Vec<String>>>,
+    /// In `Single` mode, allows closing the open item by clicking its trigger. Items can always be
+    /// closed in `Multiple` mode.
+    #[prop(optional, into)]
+    collapsible: MaybeProp<bool>,
+    /// When `true`, none of the items can be toggled.
+    #[prop(optional, into)]
+    disabled: MaybeProp<bool>,
+    /// The reading direction, used for horizontal arrow key navigation. Inherits from the closest
+    /// `DirectionProvider` when unset.
+    #[prop(optional, into)]
+    dir: MaybeProp<Direction>,
+    /// The axis arrow key navigation follows. Defaults to `Vertical`.
+    #[prop(optional, into)]
+    orientation: MaybeProp<Orientation>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+
+    let value_context = match r#type {
+        AccordionType::Single => {
+            let (value, set_value) =
+                use_controllable_optional_state(UseControllableOptionalStateParams {
+                    prop: value,
+                    default_prop: MaybeProp::derive(move || Some(default_value.get())),
+                    on_change: on_value_change,
+                });
+            let collapsible = Signal::derive(move || collapsible.get().unwrap_or(false));
+
+            AccordionValueContextValue {
+                value: Signal::derive(move || value.get().into_iter().collect()),
+                on_item_open: Callback::new(move |item_value| set_value.run(Some(item_value))),
+                on_item_close: Callback::new(move |_| {
+                    if collapsible.get_untracked() {
+                        set_value.run(None);
+                    }
+                }),
+                collapsible,
+            }
+        }
+        AccordionType::Multiple => {
+            let (values, set_values) = use_controllable_state(UseControllableStateParams {
+                prop: values,
+                default_prop: default_values,
+                on_change: on_values_change,
+            });
+
+            AccordionValueContextValue {
+                value: values,
+                on_item_open: Callback::new(move |item_value| {
+                    let mut values = values.get_untracked();
+                    values.push(item_value);
+                    set_values.run(values);
+                }),
+                on_item_close: Callback::new(move |item_value| {
+                    let mut values = values.get_untracked();
+                    values.retain(|value| *value != item_value);
+                    set_values.run(values);
+                }),
+                collapsible: Signal::derive(|| true),
+            }
+        }
+    };
+
+    let context_value = AccordionContextValue {
+        disabled: Signal::derive(move || disabled.get().unwrap_or(false)),
+        direction: use_direction(dir),
+        orientation: Signal::derive(move || orientation.get().unwrap_or(Orientation::Vertical)),
+    };
+
+    view! {
+        <AccordionProvider value=context_value>
+            <AccordionValueProvider value=value_context>
+                <Collection::Provider item_data_type=ITEM_DATA_PHANTOM>
+                    <AccordionImpl as_child=as_child node_ref=node_ref>
+                        {children.with_value(|children| children())}
+                    </AccordionImpl>
+                </Collection::Provider>
+            </AccordionValueProvider>
+        </AccordionProvider>
+    }
+}
+
+/* ---------------------------------------------------------------------------------------------- */
+
+#[component]
+#[allow(non_snake_case)]
+fn AccordionImpl<C: IntoView + 'static>(
+    as_child: MaybeProp<bool>,
+    node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_accordion_context(ACCORDION_NAME);
+    let get_items = use_collection::<ItemData>();
+
+    let handle_key_down = move |event: ev::KeyboardEvent| {
+        let key = event.key();
+        if context.disabled.get_untracked() || !ACCORDION_KEYS.contains(&key.as_str()) {
+            return;
+        }
+        let Some(target) = event
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        else {
+            return;
+        };
+
+        let triggers = get_items()
+            .into_iter()
+            .filter(|item| !item.data.disabled)
+            .filter_map(|item| item.r#ref.get_untracked())
+            .collect::<Vec<_>>();
+        let Some(trigger_index) = triggers.iter().position(|trigger| *trigger == target) else {
+            return;
+        };
+        // Prevents page scroll while navigating.
+        event.prevent_default();
+
+        let end_index = triggers.len() - 1;
+        let move_next = || match trigger_index == end_index {
+            true => 0,
+            false => trigger_index + 1,
+        };
+        let move_prev = || match trigger_index == 0 {
+            true => end_index,
+            false => trigger_index - 1,
+        };
+        let is_horizontal = context.orientation.get_untracked() == Orientation::Horizontal;
+        let is_ltr = context.direction.get_untracked() == Direction::Ltr;
+
+        let next_index = match key.as_str() {
+            "Home" => 0,
+            "End" => end_index,
+            "ArrowRight" if is_horizontal && is_ltr => move_next(),
+            "ArrowRight" if is_horizontal => move_prev(),
+            "ArrowLeft" if is_horizontal && is_ltr => move_prev(),
+            "ArrowLeft" if is_horizontal => move_next(),
+            "ArrowDown" if !is_horizontal => move_next(),
+            "ArrowUp" if !is_horizontal => move_prev(),
+            _ => trigger_index,
+        };
+
+        if let Some(trigger) = triggers.get(next_index) {
+            let _ = trigger.unchecked_ref::<web_sys::HtmlElement>().focus();
+        }
+    };
+
+    view! {
+        <Collection::Slot item_data_type=ITEM_DATA_PHANTOM>
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=node_ref
+                attr:data-orientation=move || context.orientation.get().to_string()
+                on:keydown=handle_key_down
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        </Collection::Slot>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * AccordionItem
+ * -----------------------------------------------------------------------------------------------*/
+
+const ITEM_NAME: &str = "AccordionItem";
+
+#[derive(Clone, Copy)]
+struct AccordionItemContextValue {
+    open: Signal<bool>,
+    disabled: Signal<bool>,
+    trigger_id: Signal<String>,
+}
+
+create_context!(
+    context_type: AccordionItemContextValue,
+    provider: AccordionItemProvider,
+    hook: use_accordion_item_context,
+    root: ITEM_NAME
+);
+
+/// A collapsible section of the accordion, identified by its `value`.
+#[component]
+#[allow(non_snake_case)]
+pub fn AccordionItem(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(into)] value: String,
+    /// When `true`, the item can't be toggled.
+    #[prop(optional, into)]
+    disabled: MaybeProp<bool>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_accordion_context(ITEM_NAME);
+    let value_context = use_accordion_value_context(ITEM_NAME);
+    let value = StoredValue::new(value);
+
+    let open = Signal::derive(move || {
+        value_context
+            .value
+            .with(|open_values| value.with_value(|value| open_values.contains(value)))
+    });
+    let item_context = AccordionItemContextValue {
+        open,
+        disabled: Signal::derive(move || context.disabled.get() || disabled.get().unwrap_or(false)),
+        trigger_id: use_id().into(),
+    };
+
+    view! {
+        <AccordionItemProvider value=item_context>
+            <CollapsiblePrimitive::Root
+                open=open
+                disabled=item_context.disabled
+                on_open_change=Callback::new(move |open| {
+                    let value = value.get_value();
+                    match open {
+                        true => value_context.on_item_open.run(value),
+                        false => value_context.on_item_close.run(value),
+                    }
+                })
+                as_child=as_child
+                node_ref=node_ref
+                attr:data-orientation=move || context.orientation.get().to_string()
+            >
+                {children.with_value(|children| children())}
+            </CollapsiblePrimitive::Root>
+        </AccordionItemProvider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * AccordionHeader
+ * -----------------------------------------------------------------------------------------------*/
+
+const HEADER_NAME: &str = "AccordionHeader";
+
+/// Wraps an `AccordionTrigger` in a heading.
+#[component]
+#[allow(non_snake_case)]
+pub fn AccordionHeader(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_accordion_context(HEADER_NAME);
+    let item_context = use_accordion_item_context(HEADER_NAME);
+
+    view! {
+        <Primitive
+            element=html::h3
+            as_child=as_child
+            node_ref=node_ref
+            attr:data-orientation=move || context.orientation.get().to_string()
+            attr:data-state=move || get_state(item_context.open.get())
+            attr:data-disabled=move || item_context.disabled.get().then_some("")
+        >
+            {children.with_value(|children| children())}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * AccordionTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+const TRIGGER_NAME: &str = "AccordionTrigger";
+
+/// Toggles its item. Arrow keys, `Home` and `End` move focus between the triggers.
+#[component]
+#[allow(non_snake_case)]
+pub fn AccordionTrigger(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_accordion_context(TRIGGER_NAME);
+    let value_context = use_accordion_value_context(TRIGGER_NAME);
+    let item_context = use_accordion_item_context(TRIGGER_NAME);
+
+    view! {
+        <Collection::ItemSlot
+            item_data_type=ITEM_DATA_PHANTOM
+            item_data=Signal::derive(move || ItemData {
+                disabled: item_context.disabled.get(),
+            })
+        >
+            <CollapsiblePrimitive::Trigger
+                on_click=on_click
+                as_child=as_child
+                node_ref=node_ref
+                attr:id=item_context.trigger_id
+                attr:aria-disabled=move || {
+                    (item_context.open.get() && !value_context.collapsible.get()).then_some("true")
+                }
+                attr:data-orientation=move || context.orientation.get().to_string()
+            >
+                {children.with_value(|children| children())}
+            </CollapsiblePrimitive::Trigger>
+        </Collection::ItemSlot>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * AccordionContent
+ * -----------------------------------------------------------------------------------------------*/
+
+const CONTENT_NAME: &str = "AccordionContent";
+
+/// The collapsible content of an item. Its measured size is available to animations through the
+/// `--radix-accordion-content-height` and `--radix-accordion-content-width` properties.
+#[component]
+#[allow(non_snake_case)]
+pub fn AccordionContent(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// Keep the content mounted while closed, e.g. to control animations from outside.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_accordion_context(CONTENT_NAME);
+    let item_context = use_accordion_item_context(CONTENT_NAME);
+
+    view! {
+        <CollapsiblePrimitive::Content
+            force_mount=force_mount
+            as_child=as_child
+            node_ref=node_ref
+            attr:role="region"
+            attr:aria-labelledby=item_context.trigger_id
+            attr:data-orientation=move || context.orientation.get().to_string()
+            style:--radix-accordion-content-height="var(--radix-collapsible-content-height)"
+            style:--radix-accordion-content-width="var(--radix-collapsible-content-width)"
+        >
+            {children.with_value(|children| children())}
+        </CollapsiblePrimitive::Content>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Helpers
+ * -----------------------------------------------------------------------------------------------*/
+
+fn get_state(open: bool) -> &'static str {
+    if open {
+        "open"
+    } else {
+        "closed"
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub mod primitive {
+    pub use super::*;
+    pub use Accordion as Root;
+    pub use AccordionContent as Content;
+    pub use AccordionHeader as Header;
+    pub use AccordionItem as Item;
+    pub use AccordionTrigger as Trigger;
+}
@@ -0,0 +1,9 @@
+//! Leptos port of [Radix Accordion](https://www.radix-ui.com/primitives/docs/components/accordion).
+//!
+//! A vertically stacked set of interactive headings that each reveal an associated section of content.
+//!
+//! See [`@radix-ui/react-accordion`](https://www.npmjs.com/package/@radix-ui/react-accordion) for the original package.
+
+mod accordion;
+
+pub use accordion::*;
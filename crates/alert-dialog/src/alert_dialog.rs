@@ -0,0 +1,183 @@
+use leptos::wasm_bindgen::JsCast;
+use leptos::{ev, prelude::*};
+use leptos_node_ref::AnyNodeRef;
+use radix_leptos_compose_refs::use_composed_refs;
+use radix_leptos_context::create_context;
+use radix_leptos_dialog::primitive as DialogPrimitive;
+use radix_leptos_dismissable_layer::{InteractOutsideEvent, PointerDownOutsideEvent};
+use radix_leptos_primitive::compose_callbacks;
+use web_sys::FocusOptions;
+
+/* -------------------------------------------------------------------------------------------------
+ * AlertDialog
+ * -----------------------------------------------------------------------------------------------*/
+
+/// A modal dialog that interrupts the user with important content and expects a response. Unlike
+/// `Dialog`, it can't be dismissed by interacting outside of it.
+#[component]
+#[allow(non_snake_case)]
+pub fn AlertDialog(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] open: MaybeProp<bool>,
+    #[prop(optional, into)] default_open: MaybeProp<bool>,
+    #[prop(optional, into)] on_open_change: Option<Callback<bool>>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+
+    view! {
+        <DialogPrimitive::Root
+            open=open
+            default_open=default_open
+            on_open_change=on_open_change
+            modal=true
+        >
+            {children.with_value(|children| children())}
+        </DialogPrimitive::Root>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * AlertDialogContent
+ * -----------------------------------------------------------------------------------------------*/
+
+const CONTENT_NAME: &str = "AlertDialogContent";
+
+#[derive(Clone, Copy)]
+struct AlertDialogContentContextValue {
+    cancel_ref: AnyNodeRef,
+}
+
+create_context!(
+    context_type: AlertDialogContentContextValue,
+    provider: AlertDialogContentProvider,
+    hook: use_alert_dialog_content_context,
+    root: CONTENT_NAME
+);
+
+#[component]
+#[allow(non_snake_case)]
+pub fn AlertDialogContent(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// Keep the content mounted while closed, e.g. to control animations from outside.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+    /// Event handler called when focus moves into the content on open, by default onto
+    /// `AlertDialogCancel`. Can be prevented.
+    #[prop(optional, into)]
+    on_open_auto_focus: Option<Callback<ev::Event>>,
+    /// Event handler called when focus moves back to the trigger on close. Can be prevented.
+    #[prop(optional, into)]
+    on_close_auto_focus: Option<Callback<ev::Event>>,
+    #[prop(optional, into)] on_escape_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let cancel_ref = AnyNodeRef::new();
+
+    view! {
+        <AlertDialogContentProvider value=AlertDialogContentContextValue { cancel_ref }>
+            <DialogPrimitive::Content
+                role="alertdialog"
+                force_mount=force_mount
+                on_open_auto_focus=Callback::new(compose_callbacks(
+                    on_open_auto_focus,
+                    Some(Callback::new(move |event: ev::Event| {
+                        event.prevent_default();
+                        if let Some(cancel) = cancel_ref.get_untracked() {
+                            let options = FocusOptions::new();
+                            options.set_prevent_scroll(true);
+                            let _ = cancel
+                                .unchecked_into::<web_sys::HtmlElement>()
+                                .focus_with_options(&options);
+                        }
+                    })),
+                    None,
+                ))
+                on_close_auto_focus=on_close_auto_focus
+                on_escape_key_down=on_escape_key_down
+                on_pointer_down_outside=Callback::new(move |event: PointerDownOutsideEvent| {
+                    event.prevent_default()
+                })
+                on_interact_outside=Callback::new(move |event: InteractOutsideEvent| {
+                    event.prevent_default()
+                })
+                node_ref=node_ref
+                as_child=as_child
+            >
+                {children.with_value(|children| children())}
+            </DialogPrimitive::Content>
+        </AlertDialogContentProvider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * AlertDialogAction
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Confirms the alert and closes the dialog.
+#[component]
+#[allow(non_snake_case)]
+pub fn AlertDialogAction(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+
+    view! {
+        <DialogPrimitive::Close on_click=on_click node_ref=node_ref as_child=as_child>
+            {children.with_value(|children| children())}
+        </DialogPrimitive::Close>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * AlertDialogCancel
+ * -----------------------------------------------------------------------------------------------*/
+
+const CANCEL_NAME: &str = "AlertDialogCancel";
+
+/// Dismisses the alert and closes the dialog. Receives focus when the dialog opens.
+#[component]
+#[allow(non_snake_case)]
+pub fn AlertDialogCancel(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_alert_dialog_content_context(CANCEL_NAME);
+    let composed_refs = use_composed_refs((node_ref, context.cancel_ref));
+
+    view! {
+        <DialogPrimitive::Close on_click=on_click node_ref=composed_refs as_child=as_child>
+            {children.with_value(|children| children())}
+        </DialogPrimitive::Close>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use radix_leptos_dialog::{
+    DialogDescription as AlertDialogDescription, DialogOverlay as AlertDialogOverlay,
+    DialogPortal as AlertDialogPortal, DialogTitle as AlertDialogTitle,
+    DialogTrigger as AlertDialogTrigger,
+};
+
+pub mod primitive {
+    pub use super::*;
+    pub use AlertDialog as Root;
+    pub use AlertDialogAction as Action;
+    pub use AlertDialogCancel as Cancel;
+    pub use AlertDialogContent as Content;
+    pub use AlertDialogDescription as Description;
+    pub use AlertDialogOverlay as Overlay;
+    pub use AlertDialogPortal as Portal;
+    pub use AlertDialogTitle as Title;
+    pub use AlertDialogTrigger as Trigger;
+}
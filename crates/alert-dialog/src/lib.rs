@@ -0,0 +1,9 @@
+//! Leptos port of [Radix Alert Dialog](https://www.radix-ui.com/primitives/docs/components/alert-dialog).
+//!
+//! A modal dialog that interrupts the user with important content and expects a response.
+//!
+//! See [`@radix-ui/react-alert-dialog`](https://www.npmjs.com/package/@radix-ui/react-alert-dialog) for the original package.
+
+mod alert_dialog;
+
+pub use alert_dialog::*;
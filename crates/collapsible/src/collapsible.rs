@@ -0,0 +1,223 @@
+use leptos::{ev, html, prelude::*};
+use leptos_node_ref::AnyNodeRef;
+use radix_leptos_compose_refs::use_composed_refs;
+use radix_leptos_context::create_context;
+use radix_leptos_id::use_id;
+use radix_leptos_presence::{use_is_present, Presence};
+use radix_leptos_primitive::{compose_callbacks, Primitive};
+use radix_leptos_use_controllable_state::{use_controllable_state, UseControllableStateParams};
+use radix_leptos_use_size::use_size;
+
+/* -------------------------------------------------------------------------------------------------
+ * Collapsible
+ * -----------------------------------------------------------------------------------------------*/
+
+const COLLAPSIBLE_NAME: &str = "Collapsible";
+
+#[derive(Clone, Copy)]
+struct CollapsibleContextValue {
+    content_id: Signal<String>,
+    disabled: Signal<bool>,
+    open: Signal<bool>,
+    on_open_toggle: Callback<()>,
+}
+
+create_context!(
+    context_type: CollapsibleContextValue,
+    provider: CollapsibleProvider,
+    hook: use_collapsible_context,
+    root: COLLAPSIBLE_NAME
+);
+
+/// An interactive component which expands and collapses a panel.
+#[component]
+#[allow(non_snake_case)]
+pub fn Collapsible(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] open: MaybeProp<bool>,
+    #[prop(optional, into)] default_open: MaybeProp<bool>,
+    #[prop(optional, into)] on_open_change: Option<Callback<bool>>,
+    /// When `true`, the trigger can't toggle the collapsible.
+    #[prop(optional, into)]
+    disabled: MaybeProp<bool>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+
+    let (open, set_open) = use_controllable_state(UseControllableStateParams {
+        prop: open,
+        default_prop: default_open,
+        on_change: on_open_change,
+    });
+
+    let context_value = CollapsibleContextValue {
+        content_id: use_id().into(),
+        disabled,
+        open,
+        on_open_toggle: Callback::new(move |_| set_open.run(!open.get_untracked())),
+    };
+
+    view! {
+        <CollapsibleProvider value=context_value>
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=node_ref
+                attr:data-state=move || get_state(open.get())
+                attr:data-disabled=move || disabled.get().then_some("")
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        </CollapsibleProvider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CollapsibleTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+const TRIGGER_NAME: &str = "CollapsibleTrigger";
+
+#[component]
+#[allow(non_snake_case)]
+pub fn CollapsibleTrigger(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_collapsible_context(TRIGGER_NAME);
+
+    view! {
+        <Primitive
+            element=html::button
+            as_child=as_child
+            node_ref=node_ref
+            attr:r#type="button"
+            attr:aria-controls=context.content_id
+            attr:aria-expanded=move || context.open.get().to_string()
+            attr:data-state=move || get_state(context.open.get())
+            attr:data-disabled=move || context.disabled.get().then_some("")
+            attr:disabled=move || context.disabled.get()
+            on:click=compose_callbacks(
+                on_click,
+                Some(Callback::new(move |_: ev::MouseEvent| context.on_open_toggle.run(()))),
+                None,
+            )
+        >
+            {children.with_value(|children| children())}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CollapsibleContent
+ * -----------------------------------------------------------------------------------------------*/
+
+const CONTENT_NAME: &str = "CollapsibleContent";
+
+/// The panel that expands and collapses. Its measured size is available to animations through the
+/// `--radix-collapsible-content-height` and `--radix-collapsible-content-width` properties.
+#[component]
+#[allow(non_snake_case)]
+pub fn CollapsibleContent(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// Keep the content mounted while closed, e.g. to control animations from outside.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_collapsible_context(CONTENT_NAME);
+    let present = Signal::derive(move || force_mount.get().unwrap_or(false) || context.open.get());
+
+    view! {
+        <Presence present=present>
+            <CollapsibleContentImpl as_child=as_child node_ref=node_ref>
+                {children.with_value(|children| children())}
+            </CollapsibleContentImpl>
+        </Presence>
+    }
+}
+
+#[component]
+#[allow(non_snake_case)]
+fn CollapsibleContentImpl<C: IntoView + 'static>(
+    as_child: MaybeProp<bool>,
+    node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_collapsible_context(CONTENT_NAME);
+    let content_ref = AnyNodeRef::new();
+    let composed_refs = use_composed_refs((node_ref, content_ref));
+    // Stays open while `Presence` plays the close animation, so the content isn't hidden mid-way.
+    let is_present = use_is_present();
+    let is_open = Signal::derive(move || context.open.get() || is_present.get());
+
+    // Content that starts open shouldn't play its open animation on mount.
+    let is_mount_animation_prevented = RwSignal::new(is_open.get_untracked());
+    Effect::new(move |_| {
+        request_animation_frame(move || is_mount_animation_prevented.set(false));
+    });
+
+    // The scroll size is the full size of the content, even while an animation clips it. It's
+    // measured again whenever the content resizes, e.g. when its children change.
+    let size = use_size(content_ref);
+    let dimensions = Memo::new(move |_| {
+        size.track();
+        content_ref
+            .get()
+            .map(|content| (content.scroll_width(), content.scroll_height()))
+    });
+
+    view! {
+        <Primitive
+            element=html::div
+            as_child=as_child
+            node_ref=composed_refs
+            attr:id=context.content_id
+            attr:hidden=move || !is_open.get()
+            attr:data-state=move || get_state(context.open.get())
+            attr:data-disabled=move || context.disabled.get().then_some("")
+            style:--radix-collapsible-content-height=move || {
+                dimensions.get().map(|(_, height)| format!("{height}px")).unwrap_or_default()
+            }
+            style:--radix-collapsible-content-width=move || {
+                dimensions.get().map(|(width, _)| format!("{width}px")).unwrap_or_default()
+            }
+            style:animation-name=move || {
+                if is_mount_animation_prevented.get() { "none" } else { "" }.to_string()
+            }
+        >
+            {children.with_value(|children| children())}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Helpers
+ * -----------------------------------------------------------------------------------------------*/
+
+fn get_state(open: bool) -> &'static str {
+    if open {
+        "open"
+    } else {
+        "closed"
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub mod primitive {
+    pub use super::*;
+    pub use Collapsible as Root;
+    pub use CollapsibleContent as Content;
+    pub use CollapsibleTrigger as Trigger;
+}
@@ -0,0 +1,9 @@
+//! Leptos port of [Radix Collapsible](https://www.radix-ui.com/primitives/docs/components/collapsible).
+//!
+//! An interactive component which expands and collapses a panel.
+//!
+//! See [`@radix-ui/react-collapsible`](https://www.npmjs.com/package/@radix-ui/react-collapsible) for the original package.
+
+mod collapsible;
+
+pub use collapsible::*;
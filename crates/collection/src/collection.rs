@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use leptos::{attr::custom::custom_attribute, context::Provider, prelude::*, wasm_bindgen::JsCast};
+use leptos_node_ref::prelude::*;
+use radix_leptos_compose_refs::use_composed_refs;
+
+const ITEM_DATA_ATTR: &str = "data-radix-collection-item";
+
+static COUNT: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct CollectionItemId(u64);
+
+impl CollectionItemId {
+    fn new() -> Self {
+        Self(COUNT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// A registered item: the node it renders and the data it was registered with.
+#[derive(Clone, Debug)]
+pub struct CollectionItemValue<ItemData> {
+    pub r#ref: AnyNodeRef,
+    pub data: ItemData,
+}
+
+struct CollectionContextValue<ItemData: Send + Sync + 'static> {
+    collection_ref: AnyNodeRef,
+    item_map: RwSignal<HashMap<CollectionItemId, CollectionItemValue<ItemData>>>,
+}
+
+impl<ItemData: Send + Sync + 'static> Clone for CollectionContextValue<ItemData> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<ItemData: Send + Sync + 'static> Copy for CollectionContextValue<ItemData> {}
+
+/* -------------------------------------------------------------------------------------------------
+ * CollectionProvider
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+#[allow(non_snake_case)]
+pub fn CollectionProvider<ItemData, C>(
+    /// Marker for the item data type, e.g. `PhantomData::<ItemData>`.
+    item_data_type: PhantomData<ItemData>,
+    children: TypedChildren<C>,
+) -> impl IntoView
+where
+    ItemData: Clone + Debug + Send + Sync + 'static,
+    C: IntoView + 'static,
+{
+    let _ = item_data_type;
+
+    let context_value = CollectionContextValue::<ItemData> {
+        collection_ref: AnyNodeRef::new(),
+        item_map: RwSignal::new(HashMap::new()),
+    };
+
+    view! { <Provider value=context_value>{children.into_inner()()}</Provider> }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CollectionSlot
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+#[allow(non_snake_case)]
+pub fn CollectionSlot<ItemData, C>(
+    item_data_type: PhantomData<ItemData>,
+    #[prop(optional)] node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView
+where
+    ItemData: Clone + Debug + Send + Sync + 'static,
+    C: IntoView + 'static,
+{
+    let _ = item_data_type;
+
+    let children = StoredValue::new(children.into_inner());
+    let context = expect_context::<CollectionContextValue<ItemData>>();
+    let composed_refs = use_composed_refs([node_ref, context.collection_ref]);
+
+    children.with_value(|children| children().add_any_attr(any_node_ref(composed_refs)))
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CollectionItemSlot
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+#[allow(non_snake_case)]
+pub fn CollectionItemSlot<ItemData, C>(
+    item_data_type: PhantomData<ItemData>,
+    #[prop(into)] item_data: Signal<ItemData>,
+    #[prop(optional)] node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView
+where
+    ItemData: Clone + Debug + Send + Sync + 'static,
+    C: IntoView + 'static,
+{
+    let _ = item_data_type;
+
+    let children = StoredValue::new(children.into_inner());
+    let context = expect_context::<CollectionContextValue<ItemData>>();
+    let id = CollectionItemId::new();
+    let item_ref = AnyNodeRef::new();
+    let composed_refs = use_composed_refs([node_ref, item_ref]);
+
+    // Items register on mount and whenever their data changes. Ordering is resolved from the DOM
+    // when the collection is read, so items inserted or removed by `<For>` stay in the right place.
+    Effect::new(move |_| {
+        let data = item_data.get();
+        context.item_map.update(|item_map| {
+            item_map.insert(
+                id,
+                CollectionItemValue {
+                    r#ref: item_ref,
+                    data,
+                },
+            );
+        });
+    });
+
+    on_cleanup(move || {
+        context.item_map.try_update(|item_map| {
+            item_map.remove(&id);
+        });
+    });
+
+    children.with_value(|children| {
+        children()
+            .add_any_attr(custom_attribute(ITEM_DATA_ATTR, id.0.to_string()))
+            .add_any_attr(any_node_ref(composed_refs))
+    })
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * use_collection
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Returns a getter for the registered items, sorted in DOM order.
+pub fn use_collection<ItemData>() -> impl Fn() -> Vec<CollectionItemValue<ItemData>> + Copy + Send + Sync
+where
+    ItemData: Clone + Debug + Send + Sync + 'static,
+{
+    let context = expect_context::<CollectionContextValue<ItemData>>();
+
+    move || {
+        let Some(collection_node) = context.collection_ref.get_untracked() else {
+            return vec![];
+        };
+
+        // Items carry their id in the data attribute, so one pass over the query maps each
+        // registered item to its position in the DOM.
+        let ordered_nodes = collection_node
+            .query_selector_all(&format!("[{}]", ITEM_DATA_ATTR))
+            .expect("Node should be queried.");
+        let dom_indices = (0..ordered_nodes.length())
+            .filter_map(|index| {
+                let element = ordered_nodes
+                    .item(index)?
+                    .dyn_into::<web_sys::Element>()
+                    .ok()?;
+                let id = element.get_attribute(ITEM_DATA_ATTR)?.parse().ok()?;
+                Some((CollectionItemId(id), index))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut ordered_items = context
+            .item_map
+            .get_untracked()
+            .into_iter()
+            .map(|(id, item)| (dom_indices.get(&id).copied(), item))
+            .collect::<Vec<_>>();
+        // Items that are not (yet) in the DOM go last.
+        ordered_items.sort_by_key(|(index, _)| index.unwrap_or(u32::MAX));
+        ordered_items.into_iter().map(|(_, item)| item).collect()
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub mod primitive {
+    pub use super::*;
+    pub use CollectionItemSlot as ItemSlot;
+    pub use CollectionProvider as Provider;
+    pub use CollectionSlot as Slot;
+}
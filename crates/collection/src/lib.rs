@@ -0,0 +1,9 @@
+//! Leptos port of [Radix Collection](https://www.radix-ui.com/primitives).
+//!
+//! This is an internal utility, not intended for public usage.
+//!
+//! See [`@radix-ui/react-collection`](https://www.npmjs.com/package/@radix-ui/react-collection) for the original package.
+
+mod collection;
+
+pub use collection::*;
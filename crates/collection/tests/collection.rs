@@ -0,0 +1,98 @@
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use js_sys::wasm_bindgen::JsCast;
+use leptos::{mount::mount_to, prelude::*};
+use radix_leptos_collection::*;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+type Reader = Rc<RefCell<Option<Box<dyn Fn() -> Vec<u32>>>>>;
+
+async fn tick() {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        web_sys::window()
+            .unwrap()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, 0)
+            .unwrap();
+    });
+    JsFuture::from(promise).await.unwrap();
+}
+
+fn setup_test() -> web_sys::HtmlElement {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let test_container = document.create_element("div").unwrap();
+    document
+        .body()
+        .unwrap()
+        .append_child(&test_container)
+        .unwrap();
+    test_container.unchecked_into()
+}
+
+/// Hands the collection getter to the test.
+#[component]
+#[allow(non_snake_case)]
+fn CollectionReader(reader: Reader) -> impl IntoView {
+    let get_items = use_collection::<u32>();
+    *reader.borrow_mut() = Some(Box::new(move || {
+        get_items().into_iter().map(|item| item.data).collect()
+    }));
+}
+
+#[wasm_bindgen_test]
+async fn test_items_stay_in_dom_order_through_for() {
+    let test_container = setup_test();
+    let items = RwSignal::new(vec![1, 2, 3]);
+    let reader: Reader = Rc::new(RefCell::new(None));
+
+    let dispose = mount_to(test_container.clone(), {
+        let reader = reader.clone();
+        move || {
+            view! {
+                <CollectionProvider item_data_type=PhantomData::<u32>>
+                    <CollectionSlot item_data_type=PhantomData::<u32>>
+                        <ul>
+                            <For each=move || items.get() key=|item| *item let:item>
+                                <CollectionItemSlot item_data_type=PhantomData::<u32> item_data=item>
+                                    <li>{item}</li>
+                                </CollectionItemSlot>
+                            </For>
+                        </ul>
+                    </CollectionSlot>
+                    <CollectionReader reader=reader />
+                </CollectionProvider>
+            }
+        }
+    });
+    tick().await;
+
+    let read = || reader.borrow().as_ref().map(|read| read()).unwrap();
+    assert_eq!(read(), vec![1, 2, 3]);
+
+    items.update(|items| items.push(4));
+    tick().await;
+    assert_eq!(read(), vec![1, 2, 3, 4]);
+
+    items.update(|items| items.insert(0, 0));
+    tick().await;
+    assert_eq!(read(), vec![0, 1, 2, 3, 4]);
+
+    items.update(|items| items.retain(|item| *item != 2));
+    tick().await;
+    assert_eq!(read(), vec![0, 1, 3, 4]);
+
+    items.update(|items| items.reverse());
+    tick().await;
+    assert_eq!(read(), vec![4, 3, 1, 0]);
+
+    items.set(vec![3, 5, 0]);
+    tick().await;
+    assert_eq!(read(), vec![3, 5, 0]);
+
+    drop(dispose);
+    test_container.remove();
+}
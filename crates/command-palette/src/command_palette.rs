@@ -0,0 +1,1045 @@
+use std::collections::HashMap;
+
+use leptos::wasm_bindgen::JsCast;
+use leptos::{context::Provider, ev, html, prelude::*};
+use leptos_node_ref::AnyNodeRef;
+use radix_leptos_compose_refs::use_composed_refs;
+use radix_leptos_context::create_context;
+use radix_leptos_id::use_id;
+use radix_leptos_menu::{get_next_match, ItemData};
+use radix_leptos_primitive::{compose_callbacks, Primitive, VoidPrimitive};
+use radix_leptos_use_controllable_state::{use_controllable_state, UseControllableStateParams};
+use web_sys::{ScrollIntoViewOptions, ScrollLogicalPosition};
+
+const ITEM_SELECTOR: &str = "[data-radix-command-palette-item]";
+
+/// Scores how well an item matches the search, from `0.0` (hidden) to `1.0` (best match).
+///
+/// Called with the item's `value`, the current search and the item's `keywords`.
+pub type CommandPaletteFilter = Callback<(String, String, Vec<String>), f64>;
+
+/* -------------------------------------------------------------------------------------------------
+ * CommandPalette
+ * -----------------------------------------------------------------------------------------------*/
+
+const COMMAND_PALETTE_NAME: &str = "CommandPalette";
+
+#[derive(Clone)]
+struct CommandPaletteItemEntry {
+    value: String,
+    keywords: Vec<String>,
+    group_id: Option<String>,
+    data: ItemData,
+    on_select: Option<Callback<String>>,
+}
+
+#[derive(Clone, Copy)]
+struct CommandPaletteContextValue {
+    search: Signal<String>,
+    on_search_change: Callback<String>,
+    /// The value of the highlighted item, empty when there is none.
+    value: Signal<String>,
+    on_value_change: Callback<String>,
+    items: RwSignal<HashMap<String, CommandPaletteItemEntry>>,
+    /// Item scores for the current search, keyed by item id.
+    scores: Memo<HashMap<String, f64>>,
+    should_sort: Signal<bool>,
+    list_id: Signal<String>,
+    label_id: Signal<String>,
+    input_id: Signal<String>,
+}
+
+impl CommandPaletteContextValue {
+    fn score(&self, item_id: &str) -> f64 {
+        self.scores
+            .try_with(|scores| scores.get(item_id).copied())
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    /// Highest score of the items in a group, which ranks the group among its siblings.
+    fn group_score(&self, group_id: &str) -> f64 {
+        self.items.with(|items| {
+            items
+                .iter()
+                .filter(|(_, item)| item.group_id.as_deref() == Some(group_id))
+                .map(|(id, _)| self.score(id))
+                .fold(0.0, f64::max)
+        })
+    }
+}
+
+create_context!(
+    context_type: CommandPaletteContextValue,
+    provider: CommandPaletteProvider,
+    hook: use_command_palette_context,
+    root: COMMAND_PALETTE_NAME
+);
+
+#[component]
+#[allow(non_snake_case)]
+pub fn CommandPalette(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// Accessible label for the input, rendered in a visually hidden `<label>`.
+    #[prop(optional, into)]
+    label: MaybeProp<String>,
+    /// The value of the highlighted item.
+    #[prop(optional, into)]
+    value: MaybeProp<String>,
+    #[prop(optional, into)] default_value: MaybeProp<String>,
+    #[prop(optional, into)] on_value_change: Option<Callback<String>>,
+    #[prop(optional, into)] search: MaybeProp<String>,
+    #[prop(optional, into)] default_search: MaybeProp<String>,
+    #[prop(optional, into)] on_search_change: Option<Callback<String>>,
+    /// Set to `false` when the items are already filtered, e.g. by an async search.
+    /// Items are then only highlighted with typeahead. Defaults to `true`.
+    #[prop(optional, into)]
+    should_filter: MaybeProp<bool>,
+    /// Replaces `command_score` for scoring items against the search.
+    #[prop(optional, into)]
+    filter: Option<CommandPaletteFilter>,
+    /// Orders items and groups by their score. Defaults to `true`.
+    #[prop(optional, into)]
+    should_sort: MaybeProp<bool>,
+    /// Wraps keyboard navigation around the ends of the list.
+    #[prop(optional, into)]
+    r#loop: MaybeProp<bool>,
+    /// Also navigate with `Ctrl+N`/`Ctrl+J` and `Ctrl+P`/`Ctrl+K`. Defaults to `true`.
+    #[prop(optional, into)]
+    vim_bindings: MaybeProp<bool>,
+    #[prop(optional, into)] on_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let should_filter = Signal::derive(move || should_filter.get().unwrap_or(true));
+    let should_sort =
+        Signal::derive(move || should_sort.get().unwrap_or(true) && should_filter.get());
+    let r#loop = Signal::derive(move || r#loop.get().unwrap_or(false));
+    let vim_bindings = Signal::derive(move || vim_bindings.get().unwrap_or(true));
+
+    let (value, set_value) = use_controllable_state(UseControllableStateParams {
+        prop: value,
+        default_prop: default_value,
+        on_change: on_value_change,
+    });
+    let (search, set_search) = use_controllable_state(UseControllableStateParams {
+        prop: search,
+        default_prop: default_search,
+        on_change: on_search_change,
+    });
+
+    let root_ref = AnyNodeRef::new();
+    let composed_refs = use_composed_refs((node_ref, root_ref));
+    let items = RwSignal::new(HashMap::<String, CommandPaletteItemEntry>::new());
+    let filter = filter.unwrap_or_else(|| {
+        Callback::new(|(value, search, keywords): (String, String, Vec<String>)| {
+            command_score(&value, &search, &keywords)
+        })
+    });
+
+    let scores = Memo::new(move |_| {
+        let search = search.get();
+        items.with(|items| {
+            items
+                .iter()
+                .map(|(id, item)| {
+                    let score = if !should_filter.get() || search.is_empty() {
+                        1.0
+                    } else {
+                        filter.run((item.value.clone(), search.clone(), item.keywords.clone()))
+                    };
+                    (id.clone(), score)
+                })
+                .collect::<HashMap<_, _>>()
+        })
+    });
+
+    let context_value = CommandPaletteContextValue {
+        search,
+        on_search_change: set_search,
+        value,
+        on_value_change: set_value,
+        items,
+        scores,
+        should_sort,
+        list_id: use_id().into(),
+        label_id: use_id().into(),
+        input_id: use_id().into(),
+    };
+
+    // Visible, enabled items in the order they are displayed.
+    let navigable_items = move || {
+        let Some(root) = root_ref.try_get_untracked().flatten() else {
+            return vec![];
+        };
+        let Ok(nodes) = root.query_selector_all(ITEM_SELECTOR) else {
+            return vec![];
+        };
+        let mut navigable_items = (0..nodes.length())
+            .filter_map(|index| nodes.item(index))
+            .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+            .filter_map(|element| {
+                let id = element.id();
+                let item = items
+                    .try_with_untracked(|items| items.get(&id).cloned())
+                    .flatten()?;
+                let score = context_value.score(&id);
+                (!item.data.disabled && score > 0.0).then_some((element, item, score))
+            })
+            .collect::<Vec<_>>();
+        if should_sort.try_get_untracked().unwrap_or(false) {
+            // Stable, so equal scores keep their DOM order. Groups rank by their best item.
+            navigable_items.sort_by(|(_, a, a_score), (_, b, b_score)| {
+                let rank = |item: &CommandPaletteItemEntry, score: f64| {
+                    let group_score = item
+                        .group_id
+                        .as_deref()
+                        .map_or(score, |group_id| context_value.group_score(group_id));
+                    (group_score, score)
+                };
+                rank(b, *b_score)
+                    .partial_cmp(&rank(a, *a_score))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+        }
+        navigable_items
+    };
+
+    let select = move |element: &web_sys::HtmlElement, item: &CommandPaletteItemEntry| {
+        set_value.run(item.value.clone());
+        let options = ScrollIntoViewOptions::new();
+        options.set_block(ScrollLogicalPosition::Nearest);
+        element.scroll_into_view_with_scroll_into_view_options(&options);
+    };
+
+    let select_by_index = move |index: usize| {
+        if let Some((element, item, _)) = navigable_items().get(index) {
+            select(element, item);
+        }
+    };
+
+    let selected_index = move |items: &[(web_sys::HtmlElement, CommandPaletteItemEntry, f64)]| {
+        let value = value.try_get_untracked()?;
+        items.iter().position(|(_, item, _)| item.value == value)
+    };
+
+    let select_by_item = move |change: isize| {
+        let items = navigable_items();
+        if items.is_empty() {
+            return;
+        }
+        let last_index = items.len() as isize - 1;
+        let next_index = match selected_index(&items) {
+            Some(index) => {
+                let next_index = index as isize + change;
+                if r#loop.get_untracked() {
+                    next_index.rem_euclid(items.len() as isize)
+                } else {
+                    next_index.clamp(0, last_index)
+                }
+            }
+            None if change > 0 => 0,
+            None => last_index,
+        };
+        let (element, item, _) = &items[next_index as usize];
+        select(element, item);
+    };
+
+    let select_by_group = move |change: isize| {
+        let items = navigable_items();
+        let Some(index) = selected_index(&items) else {
+            select_by_item(change);
+            return;
+        };
+        let group_id = items[index].1.group_id.clone();
+        let next_group_item = if change > 0 {
+            items[index + 1..]
+                .iter()
+                .find(|(_, item, _)| item.group_id != group_id)
+        } else {
+            // Go to the first item of the previous group.
+            items[..index]
+                .iter()
+                .rev()
+                .find(|(_, item, _)| item.group_id != group_id)
+                .and_then(|(_, previous, _)| {
+                    items
+                        .iter()
+                        .find(|(_, item, _)| item.group_id == previous.group_id)
+                })
+        };
+        match next_group_item {
+            Some((element, item, _)) => select(element, item),
+            None => select_by_item(change),
+        }
+    };
+
+    // Highlight the best match whenever the search or the items change.
+    Effect::new(move |previous_search: Option<String>| {
+        let search = search.get();
+        scores.track();
+        let search_changed = previous_search.as_ref() != Some(&search);
+
+        // Wait for the filtered items to render before reading them from the DOM.
+        request_animation_frame({
+            let search = search.clone();
+            move || {
+                // The palette may have unmounted before the frame, disposing its signals.
+                let Some(should_filter) = should_filter.try_get_untracked() else {
+                    return;
+                };
+                let items = navigable_items();
+                let is_selected_visible = selected_index(&items).is_some();
+                if !search_changed && is_selected_visible {
+                    return;
+                }
+                if !should_filter && !search.is_empty() {
+                    // Items aren't filtered here, so highlight the first one that starts with the search.
+                    let values = items
+                        .iter()
+                        .map(|(_, item, _)| item.data.text_value.clone())
+                        .collect();
+                    let next_match = get_next_match(values, search, None);
+                    if let Some((element, item, _)) = next_match.and_then(|next_match| {
+                        items
+                            .iter()
+                            .find(|(_, item, _)| item.data.text_value == next_match)
+                    }) {
+                        select(element, item);
+                        return;
+                    }
+                }
+                select_by_index(0);
+            }
+        });
+
+        search
+    });
+
+    let handle_key_down = move |event: ev::KeyboardEvent| {
+        let vim_bindings = vim_bindings.get_untracked() && event.ctrl_key();
+        match event.key().as_str() {
+            "n" | "j" if vim_bindings => {
+                event.prevent_default();
+                select_by_item(1);
+            }
+            "p" | "k" if vim_bindings => {
+                event.prevent_default();
+                select_by_item(-1);
+            }
+            "ArrowDown" => {
+                event.prevent_default();
+                if event.meta_key() {
+                    select_by_index(navigable_items().len().saturating_sub(1));
+                } else if event.alt_key() {
+                    select_by_group(1);
+                } else {
+                    select_by_item(1);
+                }
+            }
+            "ArrowUp" => {
+                event.prevent_default();
+                if event.meta_key() {
+                    select_by_index(0);
+                } else if event.alt_key() {
+                    select_by_group(-1);
+                } else {
+                    select_by_item(-1);
+                }
+            }
+            "Home" => {
+                event.prevent_default();
+                select_by_index(0);
+            }
+            "End" => {
+                event.prevent_default();
+                select_by_index(navigable_items().len().saturating_sub(1));
+            }
+            // Ignore Enter while an IME composition is being confirmed.
+            "Enter" if !event.is_composing() && event.key_code() != 229 => {
+                event.prevent_default();
+                let value = value.get_untracked();
+                let item = navigable_items()
+                    .into_iter()
+                    .find(|(_, item, _)| item.value == value);
+                if let Some((_, item, _)) = item {
+                    if let Some(on_select) = item.on_select {
+                        on_select.run(item.value);
+                    }
+                }
+            }
+            _ => {}
+        }
+    };
+
+    view! {
+        <CommandPaletteProvider value=context_value>
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=composed_refs
+                attr:tabindex="-1"
+                attr:data-radix-command-palette=""
+                on:keydown=compose_callbacks(on_key_down, Some(Callback::new(handle_key_down)), None)
+            >
+                <label
+                    for=context_value.input_id
+                    id=context_value.label_id
+                    style:position="absolute"
+                    style:border="0"
+                    style:width="1px"
+                    style:height="1px"
+                    style:padding="0"
+                    style:margin="-1px"
+                    style:overflow="hidden"
+                    style:clip="rect(0, 0, 0, 0)"
+                    style:white-space="nowrap"
+                    style:word-wrap="normal"
+                >
+                    {move || label.get()}
+                </label>
+                {children.with_value(|children| children())}
+            </Primitive>
+        </CommandPaletteProvider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CommandPaletteInput
+ * -----------------------------------------------------------------------------------------------*/
+
+const COMMAND_PALETTE_INPUT_NAME: &str = "CommandPaletteInput";
+
+/// Search input. Focus stays here while the highlighted item is announced through
+/// `aria-activedescendant`.
+#[component]
+#[allow(non_snake_case)]
+pub fn CommandPaletteInput(
+    #[prop(optional, into)] placeholder: MaybeProp<String>,
+    #[prop(optional, into)] on_input: Option<Callback<ev::Event>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let context = use_command_palette_context(COMMAND_PALETTE_INPUT_NAME);
+
+    let active_descendant = Signal::derive(move || {
+        let value = context.value.get();
+        context.items.with(|items| {
+            items
+                .iter()
+                .find(|(id, item)| item.value == value && context.score(id) > 0.0)
+                .map(|(id, _)| id.clone())
+        })
+    });
+
+    view! {
+        <VoidPrimitive
+            element=html::input
+            as_child=as_child
+            node_ref=node_ref
+            attr:r#type="text"
+            attr:id=context.input_id
+            attr:placeholder=move || placeholder.get()
+            attr:autocomplete="off"
+            attr:autocorrect="off"
+            attr:spellcheck="false"
+            attr:role="combobox"
+            attr:aria-autocomplete="list"
+            attr:aria-expanded="true"
+            attr:aria-controls=context.list_id
+            attr:aria-labelledby=context.label_id
+            attr:aria-activedescendant=move || active_descendant.get()
+            attr:data-radix-command-palette-input=""
+            prop:value=move || context.search.get()
+            on:input=compose_callbacks(
+                on_input,
+                Some(Callback::new(move |event: ev::Event| {
+                    context.on_search_change.run(event_target_value(&event));
+                })),
+                None,
+            )
+        >
+            {()}
+        </VoidPrimitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CommandPaletteList
+ * -----------------------------------------------------------------------------------------------*/
+
+const COMMAND_PALETTE_LIST_NAME: &str = "CommandPaletteList";
+
+/// Contains the items, groups and states. Sorting sets the CSS `order` of items and groups, so the
+/// list lays out its children in a flex column.
+#[component]
+#[allow(non_snake_case)]
+pub fn CommandPaletteList(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// Accessible label of the listbox. Defaults to `"Suggestions"`.
+    #[prop(optional, into)]
+    label: MaybeProp<String>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_command_palette_context(COMMAND_PALETTE_LIST_NAME);
+
+    view! {
+        <Primitive
+            element=html::div
+            as_child=as_child
+            node_ref=node_ref
+            attr:role="listbox"
+            attr:id=context.list_id
+            attr:aria-label=move || label.get().unwrap_or_else(|| "Suggestions".into())
+            attr:tabindex="-1"
+            attr:data-radix-command-palette-list=""
+            style:display="flex"
+            style:flex-direction="column"
+        >
+            {children.with_value(|children| children())}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CommandPaletteEmpty
+ * -----------------------------------------------------------------------------------------------*/
+
+const COMMAND_PALETTE_EMPTY_NAME: &str = "CommandPaletteEmpty";
+
+/// Rendered when no item matches the search.
+#[component]
+#[allow(non_snake_case)]
+pub fn CommandPaletteEmpty(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_command_palette_context(COMMAND_PALETTE_EMPTY_NAME);
+    let is_empty = Signal::derive(move || {
+        context
+            .scores
+            .with(|scores| scores.values().all(|score| *score <= 0.0))
+    });
+
+    view! {
+        <Show when=move || is_empty.get()>
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=node_ref
+                attr:role="presentation"
+                attr:data-radix-command-palette-empty=""
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        </Show>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CommandPaletteLoading
+ * -----------------------------------------------------------------------------------------------*/
+
+const COMMAND_PALETTE_LOADING_NAME: &str = "CommandPaletteLoading";
+
+/// Render while items are being fetched.
+#[component]
+#[allow(non_snake_case)]
+pub fn CommandPaletteLoading(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// Loading progress from `0.0` to `100.0`, when known.
+    #[prop(optional, into)]
+    progress: MaybeProp<f64>,
+    /// Accessible label of the progress bar. Defaults to `"Loading..."`.
+    #[prop(optional, into)]
+    label: MaybeProp<String>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    // Only checks that the loading state is rendered inside a palette.
+    let _ = use_command_palette_context(COMMAND_PALETTE_LOADING_NAME);
+
+    view! {
+        <Primitive
+            element=html::div
+            as_child=as_child
+            node_ref=node_ref
+            attr:role="progressbar"
+            attr:aria-valuenow=move || progress.get()
+            attr:aria-valuemin="0"
+            attr:aria-valuemax="100"
+            attr:aria-label=move || label.get().unwrap_or_else(|| "Loading...".into())
+            attr:data-radix-command-palette-loading=""
+        >
+            <div aria-hidden="true">{children.with_value(|children| children())}</div>
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CommandPaletteGroup
+ * -----------------------------------------------------------------------------------------------*/
+
+const COMMAND_PALETTE_GROUP_NAME: &str = "CommandPaletteGroup";
+
+#[derive(Clone)]
+struct CommandPaletteGroupContextValue {
+    id: String,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn CommandPaletteGroup(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] heading: MaybeProp<String>,
+    /// Keep the group rendered when none of its items match.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_command_palette_context(COMMAND_PALETTE_GROUP_NAME);
+    let id = use_id().get_untracked();
+    let heading_id = use_id();
+    let group_score = Signal::derive({
+        let id = id.clone();
+        move || context.group_score(&id)
+    });
+    let is_hidden =
+        Signal::derive(move || !force_mount.get().unwrap_or(false) && group_score.get() <= 0.0);
+
+    view! {
+        <Provider value=CommandPaletteGroupContextValue { id: id.clone() }>
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=node_ref
+                attr:id=id.clone()
+                attr:role="presentation"
+                attr:hidden=move || is_hidden.get().then_some("")
+                attr:data-radix-command-palette-group=""
+                style:order=move || score_order(context.should_sort.get(), group_score.get())
+            >
+                <Show when=move || heading.get().is_some()>
+                    <div aria-hidden="true" id=heading_id data-radix-command-palette-group-heading="">
+                        {move || heading.get()}
+                    </div>
+                </Show>
+                <div
+                    role="group"
+                    aria-labelledby=move || heading.get().map(|_| heading_id.get())
+                    data-radix-command-palette-group-items=""
+                    style:display="flex"
+                    style:flex-direction="column"
+                >
+                    {children.with_value(|children| children())}
+                </div>
+            </Primitive>
+        </Provider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CommandPaletteSeparator
+ * -----------------------------------------------------------------------------------------------*/
+
+const COMMAND_PALETTE_SEPARATOR_NAME: &str = "CommandPaletteSeparator";
+
+/// Hidden while searching, unless `always_render` is set.
+#[component]
+#[allow(non_snake_case)]
+pub fn CommandPaletteSeparator(
+    #[prop(optional, into)] always_render: MaybeProp<bool>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    let context = use_command_palette_context(COMMAND_PALETTE_SEPARATOR_NAME);
+
+    view! {
+        <Show when=move || always_render.get().unwrap_or(false) || context.search.get().is_empty()>
+            <VoidPrimitive
+                element=html::div
+                as_child=as_child
+                node_ref=node_ref
+                attr:role="separator"
+                attr:data-radix-command-palette-separator=""
+            >
+                {children.with_value(|children| children.as_ref().map(|children| children()))}
+            </VoidPrimitive>
+        </Show>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CommandPaletteItem
+ * -----------------------------------------------------------------------------------------------*/
+
+const COMMAND_PALETTE_ITEM_NAME: &str = "CommandPaletteItem";
+
+#[component]
+#[allow(non_snake_case)]
+pub fn CommandPaletteItem(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// Matched against the search and passed to `on_select`. Must be unique.
+    #[prop(into)]
+    value: String,
+    /// Extra terms the item can be found by.
+    #[prop(optional, into)]
+    keywords: Vec<String>,
+    #[prop(optional, into)] disabled: MaybeProp<bool>,
+    /// Called with the item's value when it is clicked or chosen with Enter.
+    #[prop(optional, into)]
+    on_select: Option<Callback<String>>,
+    /// Keep the item rendered when it doesn't match the search.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_command_palette_context(COMMAND_PALETTE_ITEM_NAME);
+    let group_context = use_context::<CommandPaletteGroupContextValue>();
+    let id = StoredValue::new(use_id().get_untracked());
+    let value = StoredValue::new(value);
+    let disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+
+    // Items register without being rendered, so they can be scored while hidden.
+    Effect::new(move |_| {
+        let entry = CommandPaletteItemEntry {
+            value: value.get_value(),
+            keywords: keywords.clone(),
+            group_id: group_context
+                .as_ref()
+                .map(|group_context| group_context.id.clone()),
+            data: ItemData {
+                disabled: disabled.get(),
+                text_value: value.get_value(),
+            },
+            on_select,
+        };
+        context.items.update(|items| {
+            items.insert(id.get_value(), entry);
+        });
+    });
+
+    on_cleanup(move || {
+        let id = id.get_value();
+        context.items.try_update(|items| {
+            items.remove(&id);
+        });
+    });
+
+    let score = Signal::derive(move || id.with_value(|id| context.score(id)));
+    let is_selected =
+        Signal::derive(move || value.with_value(|value| context.value.get() == *value));
+
+    let handle_select = move || {
+        if !disabled.get_untracked() {
+            if let Some(on_select) = on_select {
+                on_select.run(value.get_value());
+            }
+        }
+    };
+
+    view! {
+        <Show when=move || force_mount.get().unwrap_or(false) || score.get() > 0.0>
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=node_ref
+                attr:id=id.get_value()
+                attr:role="option"
+                attr:aria-disabled=move || disabled.get().to_string()
+                attr:aria-selected=move || is_selected.get().to_string()
+                attr:data-highlighted=move || is_selected.get().then_some("")
+                attr:data-disabled=move || disabled.get().then_some("")
+                attr:data-value=value.get_value()
+                attr:data-radix-command-palette-item=""
+                style:order=move || score_order(context.should_sort.get(), score.get())
+                on:pointermove=move |_: ev::PointerEvent| {
+                    if !disabled.get_untracked() && !is_selected.get_untracked() {
+                        context.on_value_change.run(value.get_value());
+                    }
+                }
+                on:click=move |_: ev::MouseEvent| handle_select()
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        </Show>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Scoring
+ * -----------------------------------------------------------------------------------------------*/
+
+const SCORE_CONTINUE_MATCH: f64 = 1.0;
+const SCORE_SPACE_WORD_JUMP: f64 = 0.9;
+const SCORE_NON_SPACE_WORD_JUMP: f64 = 0.8;
+const SCORE_CHARACTER_JUMP: f64 = 0.17;
+const SCORE_TRANSPOSITION: f64 = 0.1;
+const PENALTY_SKIPPED: f64 = 0.999;
+const PENALTY_CASE_MISMATCH: f64 = 0.9999;
+const PENALTY_NOT_COMPLETE: f64 = 0.99;
+
+/// The default filter, a port of cmdk's `command-score`.
+///
+/// Every character of `search` has to appear in `value` (or its `keywords`) in order. Consecutive
+/// matches and matches at the start of words score higher, skipped characters, case mismatches and
+/// unmatched trailing text lower the score. Returns `0.0` when the search doesn't match.
+pub fn command_score(value: &str, search: &str, keywords: &[String]) -> f64 {
+    let string = if keywords.is_empty() {
+        value.to_string()
+    } else {
+        format!("{value} {}", keywords.join(" "))
+    };
+    let string = string.chars().collect::<Vec<_>>();
+    let abbreviation = search.chars().collect::<Vec<_>>();
+    command_score_inner(
+        &CommandScoreInput {
+            lower_string: format_input(&string),
+            lower_abbreviation: format_input(&abbreviation),
+            string,
+            abbreviation,
+        },
+        0,
+        0,
+        &mut HashMap::new(),
+    )
+}
+
+struct CommandScoreInput {
+    string: Vec<char>,
+    abbreviation: Vec<char>,
+    lower_string: Vec<char>,
+    lower_abbreviation: Vec<char>,
+}
+
+fn command_score_inner(
+    input: &CommandScoreInput,
+    string_index: usize,
+    abbreviation_index: usize,
+    memoized_results: &mut HashMap<(usize, usize), f64>,
+) -> f64 {
+    if abbreviation_index == input.abbreviation.len() {
+        if string_index == input.string.len() {
+            return SCORE_CONTINUE_MATCH;
+        }
+        return PENALTY_NOT_COMPLETE;
+    }
+    if let Some(score) = memoized_results.get(&(string_index, abbreviation_index)) {
+        return *score;
+    }
+
+    let abbreviation_char = input.lower_abbreviation[abbreviation_index];
+    let next_abbreviation_char = input.lower_abbreviation.get(abbreviation_index + 1);
+    let find_from = |from: usize| {
+        input.lower_string[from.min(input.lower_string.len())..]
+            .iter()
+            .position(|c| *c == abbreviation_char)
+            .map(|position| position + from)
+    };
+
+    let mut high_score = 0.0;
+    let mut index = find_from(string_index);
+    while let Some(match_index) = index {
+        let previous_char = match_index
+            .checked_sub(1)
+            .map(|previous| input.string[previous]);
+        let previous_lower_char = match_index
+            .checked_sub(1)
+            .map(|previous| input.lower_string[previous]);
+        let skipped = &input.string[string_index..match_index.saturating_sub(1).max(string_index)];
+
+        let mut score = command_score_inner(
+            input,
+            match_index + 1,
+            abbreviation_index + 1,
+            memoized_results,
+        );
+        if score > high_score {
+            if match_index == string_index {
+                score *= SCORE_CONTINUE_MATCH;
+            } else if previous_char.is_some_and(is_gap) {
+                score *= SCORE_NON_SPACE_WORD_JUMP;
+                let word_breaks = skipped.iter().filter(|c| is_gap(**c)).count();
+                if word_breaks > 0 && string_index > 0 {
+                    score *= PENALTY_SKIPPED.powi(word_breaks as i32);
+                }
+            } else if previous_char.is_some_and(is_space) {
+                score *= SCORE_SPACE_WORD_JUMP;
+                let space_breaks = skipped.iter().filter(|c| is_space(**c)).count();
+                if space_breaks > 0 && string_index > 0 {
+                    score *= PENALTY_SKIPPED.powi(space_breaks as i32);
+                }
+            } else {
+                score *= SCORE_CHARACTER_JUMP;
+                if string_index > 0 {
+                    score *= PENALTY_SKIPPED.powi((match_index - string_index) as i32);
+                }
+            }
+
+            if input.string[match_index] != input.abbreviation[abbreviation_index] {
+                score *= PENALTY_CASE_MISMATCH;
+            }
+        }
+
+        if (score < SCORE_TRANSPOSITION && previous_lower_char.as_ref() == next_abbreviation_char)
+            || (next_abbreviation_char == Some(&abbreviation_char)
+                && previous_lower_char != Some(abbreviation_char))
+        {
+            let transposed_score = command_score_inner(
+                input,
+                match_index + 1,
+                abbreviation_index + 2,
+                memoized_results,
+            );
+            if transposed_score * SCORE_TRANSPOSITION > score {
+                score = transposed_score * SCORE_TRANSPOSITION;
+            }
+        }
+
+        if score > high_score {
+            high_score = score;
+        }
+        index = find_from(match_index + 1);
+    }
+
+    memoized_results.insert((string_index, abbreviation_index), high_score);
+    high_score
+}
+
+fn is_gap(c: char) -> bool {
+    matches!(
+        c,
+        '\\' | '/' | '_' | '+' | '.' | '#' | '"' | '@' | '[' | '(' | '{' | '&'
+    )
+}
+
+fn is_space(c: char) -> bool {
+    c.is_whitespace() || c == '-'
+}
+
+/// Lowercases character by character, so indices keep lining up with the original string.
+fn format_input(string: &[char]) -> Vec<char> {
+    string
+        .iter()
+        .map(|c| {
+            if is_space(*c) {
+                ' '
+            } else {
+                c.to_lowercase().next().unwrap_or(*c)
+            }
+        })
+        .collect()
+}
+
+/// Maps a score to a CSS `order`, so higher scores come first. Empty (DOM order) when not sorting.
+fn score_order(should_sort: bool, score: f64) -> String {
+    if !should_sort {
+        return String::new();
+    }
+    (((1.0 - score.clamp(0.0, 1.0)) * 10_000.0).round() as i32).to_string()
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub mod primitive {
+    pub use super::*;
+    pub use CommandPalette as Root;
+    pub use CommandPaletteEmpty as Empty;
+    pub use CommandPaletteGroup as Group;
+    pub use CommandPaletteInput as Input;
+    pub use CommandPaletteItem as Item;
+    pub use CommandPaletteList as List;
+    pub use CommandPaletteLoading as Loading;
+    pub use CommandPaletteSeparator as Separator;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(value: &str, search: &str) -> f64 {
+        command_score(value, search, &[])
+    }
+
+    #[test]
+    fn exact_matches_beat_prefixes_and_word_jumps() {
+        let exact = score("Settings", "Settings");
+        let prefix = score("Settings", "Set");
+        let word_jump = score("Open Settings", "Set");
+        let character_jump = score("Upset", "Set");
+
+        assert_eq!(exact, SCORE_CONTINUE_MATCH);
+        assert!(exact > prefix, "{exact} > {prefix}");
+        assert!(prefix > word_jump, "{prefix} > {word_jump}");
+        assert!(word_jump > character_jump, "{word_jump} > {character_jump}");
+    }
+
+    #[test]
+    fn space_word_jumps_beat_gap_word_jumps() {
+        let space = score("open settings", "os");
+        let gap = score("open/settings", "os");
+        let character = score("opus", "os");
+
+        assert!(space > gap, "{space} > {gap}");
+        assert!(gap > character, "{gap} > {character}");
+    }
+
+    #[test]
+    fn skipped_characters_lower_the_score() {
+        let short_skip = score("abxc", "ac");
+        let long_skip = score("abxxxxc", "ac");
+
+        assert!(short_skip > 0.0);
+        assert!(short_skip > long_skip, "{short_skip} > {long_skip}");
+        assert_eq!(score("Settings", "xyz"), 0.0);
+    }
+
+    #[test]
+    fn case_mismatches_are_penalised() {
+        let matching_case = score("Settings", "Settings");
+        let mismatched_case = score("Settings", "settings");
+
+        assert_eq!(
+            mismatched_case,
+            SCORE_CONTINUE_MATCH * PENALTY_CASE_MISMATCH
+        );
+        assert!(matching_case > mismatched_case);
+    }
+
+    #[test]
+    fn keywords_match_when_the_value_does_not() {
+        let keywords = ["config".to_string(), "options".to_string()];
+
+        assert_eq!(score("Preferences", "config"), 0.0);
+        assert!(command_score("Preferences", "config", &keywords) > 0.0);
+        assert!(command_score("Preferences", "options", &keywords) > 0.0);
+        assert!(
+            command_score("Preferences", "pref", &keywords)
+                > command_score("Preferences", "config", &keywords)
+        );
+    }
+
+    #[test]
+    fn empty_searches_match_everything() {
+        assert_eq!(score("", ""), SCORE_CONTINUE_MATCH);
+        assert_eq!(score("Settings", ""), PENALTY_NOT_COMPLETE);
+        assert_eq!(
+            command_score("Settings", "", &["config".to_string()]),
+            PENALTY_NOT_COMPLETE
+        );
+    }
+}
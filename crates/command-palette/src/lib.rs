@@ -0,0 +1,9 @@
+//! Leptos command palette in the style of [cmdk](https://cmdk.paco.me).
+//!
+//! A command menu that filters and sorts its items as the user types.
+//!
+//! See [`cmdk`](https://www.npmjs.com/package/cmdk) for the original package.
+
+mod command_palette;
+
+pub use command_palette::*;
@@ -0,0 +1,350 @@
+use std::time::Duration;
+
+use leptos::{ev, html, prelude::*};
+use leptos_node_ref::AnyNodeRef;
+use radix_leptos_context::create_context;
+use radix_leptos_menu::primitive as MenuPrimitive;
+use radix_leptos_popper::{Align, Side, VirtualRect};
+use radix_leptos_primitive::{compose_callbacks, Primitive};
+pub use radix_leptos_checkbox::CheckedState;
+pub use radix_leptos_direction::Direction;
+pub use radix_leptos_menu::{
+    GraceArea, HoverDelay, HoverIntent, HoverIntentProvider, HoverIntentStrategy, SafeTriangle,
+};
+
+/// How long a touch or pen has to rest on the trigger before the menu opens.
+const LONG_PRESS_DELAY: Duration = Duration::from_millis(700);
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenu
+ * -----------------------------------------------------------------------------------------------*/
+
+const CONTEXT_MENU_NAME: &str = "ContextMenu";
+
+#[derive(Clone, Copy)]
+struct ContextMenuContextValue {
+    open: Signal<bool>,
+    on_open_change: Callback<bool>,
+    modal: Signal<bool>,
+}
+
+create_context!(
+    context_type: ContextMenuContextValue,
+    provider: ContextMenuProvider,
+    hook: use_context_menu_context,
+    root: CONTEXT_MENU_NAME
+);
+
+#[component]
+#[allow(non_snake_case)]
+pub fn ContextMenu(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] dir: MaybeProp<Direction>,
+    #[prop(optional, into)] on_open_change: Option<Callback<bool>>,
+    #[prop(optional, into)] modal: MaybeProp<bool>,
+    #[prop(optional, into)] hover_intent: Option<HoverIntentStrategy>,
+) -> impl IntoView {
+    let open = RwSignal::new(false);
+    let modal = Signal::derive(move || modal.get().unwrap_or(true));
+
+    let handle_open_change = Callback::new(move |value: bool| {
+        open.set(value);
+        if let Some(on_open_change) = on_open_change {
+            on_open_change.run(value);
+        }
+    });
+
+    let context_value = ContextMenuContextValue {
+        open: open.into(),
+        on_open_change: handle_open_change,
+        modal,
+    };
+
+    view! {
+        <ContextMenuProvider value=context_value>
+            <MenuPrimitive::Root
+                children=children
+                open=open
+                on_open_change=handle_open_change
+                dir=dir
+                modal=modal
+                hover_intent=hover_intent
+            />
+        </ContextMenuProvider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+const CONTEXT_MENU_TRIGGER_NAME: &str = "ContextMenuTrigger";
+
+#[component]
+#[allow(non_snake_case)]
+pub fn ContextMenuTrigger(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] disabled: MaybeProp<bool>,
+    #[prop(optional, into)] on_context_menu: Option<Callback<ev::MouseEvent>>,
+    #[prop(optional, into)] on_pointer_down: Option<Callback<ev::PointerEvent>>,
+    #[prop(optional, into)] on_pointer_move: Option<Callback<ev::PointerEvent>>,
+    #[prop(optional, into)] on_pointer_cancel: Option<Callback<ev::PointerEvent>>,
+    #[prop(optional, into)] on_pointer_up: Option<Callback<ev::PointerEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_context_menu_context(CONTEXT_MENU_TRIGGER_NAME);
+    let disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+
+    let point = RwSignal::new(VirtualRect::default());
+    let long_press_timer = RwSignal::new(None::<TimeoutHandle>);
+
+    let clear_long_press = move || {
+        if let Some(timer) = long_press_timer.get_untracked() {
+            timer.clear();
+            long_press_timer.set(None);
+        }
+    };
+
+    let handle_open = move |x: i32, y: i32| {
+        point.set(VirtualRect::from_point(x as f64, y as f64));
+        context.on_open_change.run(true);
+    };
+
+    Effect::new(move |_| {
+        if disabled.get() {
+            clear_long_press();
+        }
+    });
+
+    on_cleanup(move || {
+        if let Some(timer) = long_press_timer.get_untracked() {
+            timer.clear();
+        }
+    });
+
+    let clear_long_press_on_touch = Callback::new(move |event: ev::PointerEvent| {
+        if !disabled.get_untracked() && event.pointer_type() != "mouse" {
+            clear_long_press();
+        }
+    });
+
+    view! {
+        <MenuPrimitive::VirtualAnchor rect=point />
+        <Primitive
+            element=html::span
+            as_child=as_child
+            node_ref=node_ref
+            attr:data-state=move || if context.open.get() { "open" } else { "closed" }
+            attr:data-disabled=move || disabled.get().then_some("")
+            style:-webkit-touch-callout="none"
+            on:contextmenu=compose_callbacks(
+                on_context_menu,
+                Some(Callback::new(move |event: ev::MouseEvent| {
+                    if disabled.get_untracked() {
+                        return;
+                    }
+                    // Clearing the long press here because some platforms already support
+                    // long press to trigger a `contextmenu` event.
+                    clear_long_press();
+                    handle_open(event.client_x(), event.client_y());
+                    event.prevent_default();
+                })),
+                None,
+            )
+            on:pointerdown=compose_callbacks(
+                on_pointer_down,
+                Some(Callback::new(move |event: ev::PointerEvent| {
+                    if disabled.get_untracked() || event.pointer_type() == "mouse" {
+                        return;
+                    }
+                    // Clear the long press here in case there's multiple touch points.
+                    clear_long_press();
+                    let (x, y) = (event.client_x(), event.client_y());
+                    long_press_timer.set(
+                        set_timeout_with_handle(move || handle_open(x, y), LONG_PRESS_DELAY).ok(),
+                    );
+                })),
+                None,
+            )
+            on:pointermove=compose_callbacks(on_pointer_move, Some(clear_long_press_on_touch), None)
+            on:pointercancel=compose_callbacks(on_pointer_cancel, Some(clear_long_press_on_touch), None)
+            on:pointerup=compose_callbacks(on_pointer_up, Some(clear_long_press_on_touch), None)
+        >
+            {children.with_value(|children| children())}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuPortal
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::Portal as ContextMenuPortal;
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuContent
+ * -----------------------------------------------------------------------------------------------*/
+
+const CONTEXT_MENU_CONTENT_NAME: &str = "ContextMenuContent";
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn ContextMenuContent(
+    children: ChildrenFn, // NOTE: No passthrough needed
+    #[prop(optional)] node_ref: AnyNodeRef,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    let context = use_context_menu_context(CONTEXT_MENU_CONTENT_NAME);
+
+    let has_interacted_outside = RwSignal::new(false);
+
+    let on_close_auto_focus = move |event: ev::Event| {
+        // There is no trigger to return focus to after interacting outside, so leave it where
+        // the user put it.
+        if has_interacted_outside.get() {
+            event.prevent_default();
+        }
+        has_interacted_outside.set(false);
+    };
+
+    let on_interact_outside = move |_event: ev::Event| {
+        if !context.modal.get() {
+            has_interacted_outside.set(true);
+        }
+    };
+
+    view! {
+        <MenuPrimitive::Content
+            node_ref=node_ref
+            side=Side::Right
+            side_offset=2.0
+            align=Align::Start
+            {..}
+            on:closeautofocus=on_close_auto_focus
+            on:interactoutside=on_interact_outside
+            style:--radix-context-menu-content-transform-origin="var(--radix-popper-transform-origin)"
+            style:--radix-context-menu-content-available-width="var(--radix-popper-available-width)"
+            style:--radix-context-menu-content-available-height="var(--radix-popper-available-height)"
+            style:--radix-context-menu-trigger-width="var(--radix-popper-anchor-width)"
+            style:--radix-context-menu-trigger-height="var(--radix-popper-anchor-height)"
+        >
+            {children.with_value(|children| children())}
+        </MenuPrimitive::Content>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuGroup
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::Group as ContextMenuGroup;
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuLabel
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::Label as ContextMenuLabel;
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuItem
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::Item as ContextMenuItem;
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuCheckboxItem
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::CheckboxItem as ContextMenuCheckboxItem;
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuRadioGroup
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::RadioGroup as ContextMenuRadioGroup;
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuRadioItem
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::RadioItem as ContextMenuRadioItem;
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuItemIndicator
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::ItemIndicator as ContextMenuItemIndicator;
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuSeparator
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::Separator as ContextMenuSeparator;
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuArrow
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::Arrow as ContextMenuArrow;
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuSub
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::Sub as ContextMenuSub;
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuSubTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::SubTrigger as ContextMenuSubTrigger;
+
+/* -------------------------------------------------------------------------------------------------
+ * ContextMenuSubContent
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+#[allow(non_snake_case)]
+pub fn ContextMenuSubContent(
+    children: ChildrenFn, // NOTE: No passthrough needed
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+) -> impl IntoView {
+    view! {
+        <MenuPrimitive::SubContent
+            children=children
+            node_ref=node_ref
+            {..}
+            style:--radix-context-menu-content-transform-origin="var(--radix-popper-transform-origin)"
+            style:--radix-context-menu-content-available-width="var(--radix-popper-available-width)"
+            style:--radix-context-menu-content-available-height="var(--radix-popper-available-height)"
+            style:--radix-context-menu-trigger-width="var(--radix-popper-anchor-width)"
+            style:--radix-context-menu-trigger-height="var(--radix-popper-anchor-height)"
+        />
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub mod primitive {
+    pub use super::*;
+    pub use ContextMenu as Root;
+    pub use ContextMenuArrow as Arrow;
+    pub use ContextMenuCheckboxItem as CheckboxItem;
+    pub use ContextMenuContent as Content;
+    pub use ContextMenuGroup as Group;
+    pub use ContextMenuItem as Item;
+    pub use ContextMenuItemIndicator as ItemIndicator;
+    pub use ContextMenuLabel as Label;
+    pub use ContextMenuPortal as Portal;
+    pub use ContextMenuRadioGroup as RadioGroup;
+    pub use ContextMenuRadioItem as RadioItem;
+    pub use ContextMenuSeparator as Separator;
+    pub use ContextMenuSub as Sub;
+    pub use ContextMenuSubContent as SubContent;
+    pub use ContextMenuSubTrigger as SubTrigger;
+    pub use ContextMenuTrigger as Trigger;
+}
@@ -0,0 +1,9 @@
+//! Leptos port of [Radix Context Menu](https://www.radix-ui.com/primitives/docs/components/context-menu).
+//!
+//! Displays a menu located at the pointer, triggered by a right click or a long press.
+//!
+//! See [`@radix-ui/react-context-menu`](https://www.npmjs.com/package/@radix-ui/react-context-menu) for the original package.
+
+mod context_menu;
+
+pub use context_menu::*;
@@ -0,0 +1,595 @@
+use leptos::wasm_bindgen::JsCast;
+use leptos::{context::Provider, ev, html, prelude::*};
+use leptos_node_ref::AnyNodeRef;
+use leptos_remove_scroll::RemoveScroll;
+use leptos_typed_fallback_show::TypedFallbackShow;
+use radix_leptos_compose_refs::use_composed_refs;
+use radix_leptos_context::create_context;
+use radix_leptos_dismissable_layer::{
+    DismissableLayer, FocusOutsideEvent, InteractOutsideEvent, PointerDownOutsideEvent,
+};
+use radix_leptos_focus_scope::FocusScope;
+use radix_leptos_id::use_id;
+use radix_leptos_portal::Portal as PortalPrimitive;
+use radix_leptos_presence::Presence;
+use radix_leptos_primitive::{compose_callbacks, Primitive};
+use radix_leptos_use_controllable_state::{use_controllable_state, UseControllableStateParams};
+
+/* -------------------------------------------------------------------------------------------------
+ * Dialog
+ * -----------------------------------------------------------------------------------------------*/
+
+const DIALOG_NAME: &str = "Dialog";
+
+#[derive(Clone, Copy)]
+struct DialogContextValue {
+    trigger_ref: AnyNodeRef,
+    content_id: Signal<String>,
+    title_id: Signal<String>,
+    description_id: Signal<String>,
+    open: Signal<bool>,
+    on_open_change: Callback<bool>,
+    on_open_toggle: Callback<()>,
+    modal: Signal<bool>,
+}
+
+create_context!(
+    context_type: DialogContextValue,
+    provider: DialogProvider,
+    hook: use_dialog_context,
+    root: DIALOG_NAME
+);
+
+#[component]
+#[allow(non_snake_case)]
+pub fn Dialog(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] open: MaybeProp<bool>,
+    #[prop(optional, into)] default_open: MaybeProp<bool>,
+    #[prop(optional, into)] on_open_change: Option<Callback<bool>>,
+    /// When `true`, focus is trapped, outside pointer events are blocked and scrolling is locked
+    /// while open. Defaults to `true`.
+    #[prop(optional, into)]
+    modal: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+
+    let (open, set_open) = use_controllable_state(UseControllableStateParams {
+        prop: open,
+        default_prop: default_open,
+        on_change: on_open_change,
+    });
+
+    let context_value = DialogContextValue {
+        trigger_ref: AnyNodeRef::new(),
+        content_id: use_id().into(),
+        title_id: use_id().into(),
+        description_id: use_id().into(),
+        open,
+        on_open_change: set_open,
+        on_open_toggle: Callback::new(move |_| set_open.run(!open.get_untracked())),
+        modal: Signal::derive(move || modal.get().unwrap_or(true)),
+    };
+
+    view! {
+        <DialogProvider value=context_value>
+            {children.with_value(|children| children())}
+        </DialogProvider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DialogTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+const TRIGGER_NAME: &str = "DialogTrigger";
+
+#[component]
+#[allow(non_snake_case)]
+pub fn DialogTrigger(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_dialog_context(TRIGGER_NAME);
+    let composed_refs = use_composed_refs((node_ref, context.trigger_ref));
+
+    view! {
+        <Primitive
+            element=html::button
+            as_child=as_child
+            node_ref=composed_refs
+            attr:r#type="button"
+            attr:aria-haspopup="dialog"
+            attr:aria-expanded=move || context.open.get().to_string()
+            attr:aria-controls=context.content_id
+            attr:data-state=move || get_state(context.open.get())
+            on:click=compose_callbacks(
+                on_click,
+                Some(Callback::new(move |_: ev::MouseEvent| context.on_open_toggle.run(()))),
+                None,
+            )
+        >
+            {children.with_value(|children| children())}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DialogPortal
+ * -----------------------------------------------------------------------------------------------*/
+
+const PORTAL_NAME: &str = "DialogPortal";
+
+#[derive(Clone, Copy)]
+struct DialogPortalContextValue {
+    force_mount: MaybeProp<bool>,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn DialogPortal(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] container: AnyNodeRef,
+    /// Keep the portal mounted while closed, e.g. to control animations from outside.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_dialog_context(PORTAL_NAME);
+    let container = StoredValue::new(container);
+
+    view! {
+        <Provider value=DialogPortalContextValue { force_mount }>
+            <Presence present=Signal::derive(move || {
+                force_mount.get().unwrap_or(false) || context.open.get()
+            })>
+                <PortalPrimitive as_child=true mount=container.with_value(|container| container.get())>
+                    {children.with_value(|children| children())}
+                </PortalPrimitive>
+            </Presence>
+        </Provider>
+    }
+}
+
+/// Resolves `force_mount` against the closest `DialogPortal`.
+fn use_force_mount(force_mount: MaybeProp<bool>) -> Signal<bool> {
+    let portal_context = use_context::<DialogPortalContextValue>();
+    Signal::derive(move || {
+        force_mount
+            .get()
+            .or_else(|| portal_context.and_then(|portal_context| portal_context.force_mount.get()))
+            .unwrap_or(false)
+    })
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DialogOverlay
+ * -----------------------------------------------------------------------------------------------*/
+
+const OVERLAY_NAME: &str = "DialogOverlay";
+
+/// Covers the inert portion of the view while a modal dialog is open, and locks scrolling.
+#[component]
+#[allow(non_snake_case)]
+pub fn DialogOverlay(
+    #[prop(optional)] children: Option<ChildrenFn>,
+    /// Keep the overlay mounted while closed, e.g. to control animations from outside.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    let context = use_dialog_context(OVERLAY_NAME);
+    let force_mount = use_force_mount(force_mount);
+
+    view! {
+        <Show when=move || context.modal.get()>
+            <Presence present=Signal::derive(move || force_mount.get() || context.open.get())>
+                // Make sure `Content` is scrollable even when it doesn't live inside `RemoveScroll`,
+                // i.e. when the overlay is a sibling of the content.
+                <RemoveScroll allow_pinch_zoom=true forward_props=false>
+                    <Primitive
+                        element=html::div
+                        as_child=as_child
+                        node_ref=node_ref
+                        attr:data-state=move || get_state(context.open.get())
+                        // We re-enable pointer-events prevented by `DialogContent` to allow scrolling
+                        // the overlay.
+                        style:pointer-events="auto"
+                    >
+                        {children.with_value(|children| children.as_ref().map(|children| children()))}
+                    </Primitive>
+                </RemoveScroll>
+            </Presence>
+        </Show>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DialogContent
+ * -----------------------------------------------------------------------------------------------*/
+
+const CONTENT_NAME: &str = "DialogContent";
+
+/// Event handlers forwarded to `FocusScope` and `DismissableLayer`.
+#[derive(Clone, Copy, Default)]
+struct DialogContentHandlers {
+    on_open_auto_focus: Option<Callback<ev::Event>>,
+    on_close_auto_focus: Option<Callback<ev::Event>>,
+    on_escape_key_down: Option<Callback<ev::KeyboardEvent>>,
+    on_pointer_down_outside: Option<Callback<PointerDownOutsideEvent>>,
+    on_focus_outside: Option<Callback<FocusOutsideEvent>>,
+    on_interact_outside: Option<Callback<InteractOutsideEvent>>,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn DialogContent(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// Keep the content mounted while closed, e.g. to control animations from outside.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+    /// The ARIA role of the content. Defaults to `dialog`, `AlertDialogContent` sets `alertdialog`.
+    #[prop(optional, into)]
+    role: MaybeProp<&'static str>,
+    /// Event handler called when focus moves into the content on open. Can be prevented.
+    #[prop(optional, into)]
+    on_open_auto_focus: Option<Callback<ev::Event>>,
+    /// Event handler called when focus moves back to the trigger on close. Can be prevented.
+    #[prop(optional, into)]
+    on_close_auto_focus: Option<Callback<ev::Event>>,
+    #[prop(optional, into)] on_escape_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(optional, into)] on_pointer_down_outside: Option<Callback<PointerDownOutsideEvent>>,
+    #[prop(optional, into)] on_focus_outside: Option<Callback<FocusOutsideEvent>>,
+    #[prop(optional, into)] on_interact_outside: Option<Callback<InteractOutsideEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_dialog_context(CONTENT_NAME);
+    let force_mount = use_force_mount(force_mount);
+    let handlers = DialogContentHandlers {
+        on_open_auto_focus,
+        on_close_auto_focus,
+        on_escape_key_down,
+        on_pointer_down_outside,
+        on_focus_outside,
+        on_interact_outside,
+    };
+
+    view! {
+        <Presence present=Signal::derive(move || force_mount.get() || context.open.get())>
+            <TypedFallbackShow
+                when=move || context.modal.get()
+                fallback=move || {
+                    view! {
+                        <DialogContentNonModal
+                            role=role
+                            handlers=handlers
+                            as_child=as_child
+                            node_ref=node_ref
+                        >
+                            {children.with_value(|children| children())}
+                        </DialogContentNonModal>
+                    }
+                }
+            >
+                <DialogContentModal role=role handlers=handlers as_child=as_child node_ref=node_ref>
+                    {children.with_value(|children| children())}
+                </DialogContentModal>
+            </TypedFallbackShow>
+        </Presence>
+    }
+}
+
+/* ---------------------------------------------------------------------------------------------- */
+
+#[component]
+#[allow(non_snake_case)]
+fn DialogContentModal<C: IntoView + 'static>(
+    role: MaybeProp<&'static str>,
+    handlers: DialogContentHandlers,
+    as_child: MaybeProp<bool>,
+    node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_dialog_context(CONTENT_NAME);
+
+    let handlers = DialogContentHandlers {
+        on_close_auto_focus: Some(Callback::new(compose_callbacks(
+            handlers.on_close_auto_focus,
+            Some(Callback::new(move |event: ev::Event| {
+                event.prevent_default();
+                focus_trigger(context.trigger_ref);
+            })),
+            None,
+        ))),
+        on_pointer_down_outside: Some(Callback::new(compose_callbacks(
+            handlers.on_pointer_down_outside,
+            Some(Callback::new(move |event: PointerDownOutsideEvent| {
+                let original_event = event.original_event();
+                let ctrl_left_click = original_event.button() == 0 && original_event.ctrl_key();
+                let is_right_click = original_event.button() == 2 || ctrl_left_click;
+                // If the event is a right-click, we shouldn't close because
+                // it is effectively as if we right-clicked the `Overlay`.
+                if is_right_click {
+                    event.prevent_default();
+                }
+            })),
+            Some(false),
+        ))),
+        // When focus is trapped, a `focusout` event may still happen.
+        // We make sure we don't trigger our `on_dismiss` in such case.
+        on_focus_outside: Some(Callback::new(compose_callbacks(
+            handlers.on_focus_outside,
+            Some(Callback::new(move |event: FocusOutsideEvent| {
+                event.prevent_default();
+            })),
+            Some(false),
+        ))),
+        ..handlers
+    };
+
+    view! {
+        <DialogContentImpl
+            // We make sure focus isn't trapped once `DialogContent` has been closed
+            // (closed != unmounted when animating out).
+            trap_focus=context.open
+            disable_outside_pointer_events=true
+            role=role
+            handlers=handlers
+            as_child=as_child
+            node_ref=node_ref
+        >
+            {children.with_value(|children| children())}
+        </DialogContentImpl>
+    }
+}
+
+#[component]
+#[allow(non_snake_case)]
+fn DialogContentNonModal<C: IntoView + 'static>(
+    role: MaybeProp<&'static str>,
+    handlers: DialogContentHandlers,
+    as_child: MaybeProp<bool>,
+    node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_dialog_context(CONTENT_NAME);
+    let has_interacted_outside = StoredValue::new(false);
+    let has_pointer_down_outside = StoredValue::new(false);
+
+    let handlers = DialogContentHandlers {
+        on_close_auto_focus: Some(Callback::new(compose_callbacks(
+            handlers.on_close_auto_focus,
+            Some(Callback::new(move |event: ev::Event| {
+                if !has_interacted_outside.get_value() {
+                    focus_trigger(context.trigger_ref);
+                }
+                // Always prevent auto focus because we either focus manually or want user agent focus.
+                event.prevent_default();
+
+                has_interacted_outside.set_value(false);
+                has_pointer_down_outside.set_value(false);
+            })),
+            None,
+        ))),
+        on_interact_outside: Some(Callback::new(compose_callbacks(
+            handlers.on_interact_outside,
+            Some(Callback::new(move |event: InteractOutsideEvent| {
+                has_interacted_outside.set_value(true);
+                if matches!(event, InteractOutsideEvent::PointerDownOutside(_)) {
+                    has_pointer_down_outside.set_value(true);
+                }
+
+                // Prevent dismissing when clicking the trigger.
+                // As the trigger is already setup to close, without doing so would
+                // cause it to close and immediately open.
+                let target = event
+                    .original_event()
+                    .target()
+                    .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+                let target_is_trigger = context
+                    .trigger_ref
+                    .get_untracked()
+                    .is_some_and(|trigger| trigger.contains(target.as_ref()));
+                if target_is_trigger {
+                    event.prevent_default();
+                }
+
+                // On Safari if the trigger is inside a container with tabIndex={0}, when clicked
+                // we will get the pointer down outside event on the trigger, but then a subsequent
+                // focus outside event on the container, we ignore any focus outside event when we've
+                // already had a pointer down outside event.
+                if matches!(event, InteractOutsideEvent::FocusOutside(_))
+                    && has_pointer_down_outside.get_value()
+                {
+                    event.prevent_default();
+                }
+            })),
+            Some(false),
+        ))),
+        ..handlers
+    };
+
+    view! {
+        <DialogContentImpl
+            trap_focus=false
+            disable_outside_pointer_events=false
+            role=role
+            handlers=handlers
+            as_child=as_child
+            node_ref=node_ref
+        >
+            {children.with_value(|children| children())}
+        </DialogContentImpl>
+    }
+}
+
+/* ---------------------------------------------------------------------------------------------- */
+
+#[component]
+#[allow(non_snake_case)]
+fn DialogContentImpl<C: IntoView + 'static>(
+    #[prop(into)] trap_focus: MaybeProp<bool>,
+    #[prop(into)] disable_outside_pointer_events: MaybeProp<bool>,
+    role: MaybeProp<&'static str>,
+    handlers: DialogContentHandlers,
+    as_child: MaybeProp<bool>,
+    node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_dialog_context(CONTENT_NAME);
+
+    view! {
+        <FocusScope
+            as_child=true
+            r#loop=true
+            trapped=trap_focus
+            on_mount_auto_focus=handlers.on_open_auto_focus
+            on_unmount_auto_focus=handlers.on_close_auto_focus
+        >
+            <DismissableLayer
+                disable_outside_pointer_events=disable_outside_pointer_events
+                on_escape_key_down=handlers.on_escape_key_down
+                on_pointer_down_outside=handlers.on_pointer_down_outside
+                on_focus_outside=handlers.on_focus_outside
+                on_interact_outside=handlers.on_interact_outside
+                on_dismiss=Callback::new(move |_| context.on_open_change.run(false))
+                as_child=as_child
+                node_ref=node_ref
+                attr:role=move || role.get().unwrap_or("dialog")
+                attr:id=context.content_id
+                attr:aria-describedby=context.description_id
+                attr:aria-labelledby=context.title_id
+                attr:aria-modal=move || context.modal.get().then_some("true")
+                attr:data-state=move || get_state(context.open.get())
+            >
+                {children.with_value(|children| children())}
+            </DismissableLayer>
+        </FocusScope>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DialogTitle
+ * -----------------------------------------------------------------------------------------------*/
+
+const TITLE_NAME: &str = "DialogTitle";
+
+/// An accessible title announced when the dialog opens.
+#[component]
+#[allow(non_snake_case)]
+pub fn DialogTitle(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_dialog_context(TITLE_NAME);
+
+    view! {
+        <Primitive element=html::h2 as_child=as_child node_ref=node_ref attr:id=context.title_id>
+            {children.with_value(|children| children())}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DialogDescription
+ * -----------------------------------------------------------------------------------------------*/
+
+const DESCRIPTION_NAME: &str = "DialogDescription";
+
+/// An optional accessible description announced when the dialog opens.
+#[component]
+#[allow(non_snake_case)]
+pub fn DialogDescription(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_dialog_context(DESCRIPTION_NAME);
+
+    view! {
+        <Primitive element=html::p as_child=as_child node_ref=node_ref attr:id=context.description_id>
+            {children.with_value(|children| children())}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DialogClose
+ * -----------------------------------------------------------------------------------------------*/
+
+const CLOSE_NAME: &str = "DialogClose";
+
+#[component]
+#[allow(non_snake_case)]
+pub fn DialogClose(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_dialog_context(CLOSE_NAME);
+
+    view! {
+        <Primitive
+            element=html::button
+            as_child=as_child
+            node_ref=node_ref
+            attr:r#type="button"
+            on:click=compose_callbacks(
+                on_click,
+                Some(Callback::new(move |_: ev::MouseEvent| context.on_open_change.run(false))),
+                None,
+            )
+        >
+            {children.with_value(|children| children())}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Helpers
+ * -----------------------------------------------------------------------------------------------*/
+
+fn get_state(open: bool) -> &'static str {
+    if open {
+        "open"
+    } else {
+        "closed"
+    }
+}
+
+fn focus_trigger(trigger_ref: AnyNodeRef) {
+    if let Some(trigger) = trigger_ref.get_untracked() {
+        let _ = trigger.unchecked_into::<web_sys::HtmlElement>().focus();
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub mod primitive {
+    pub use super::*;
+    pub use Dialog as Root;
+    pub use DialogClose as Close;
+    pub use DialogContent as Content;
+    pub use DialogDescription as Description;
+    pub use DialogOverlay as Overlay;
+    pub use DialogPortal as Portal;
+    pub use DialogTitle as Title;
+    pub use DialogTrigger as Trigger;
+}
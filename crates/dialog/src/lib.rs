@@ -0,0 +1,9 @@
+//! Leptos port of [Radix Dialog](https://www.radix-ui.com/primitives/docs/components/dialog).
+//!
+//! A window overlaid on either the primary window or another dialog window, rendering the content underneath inert.
+//!
+//! See [`@radix-ui/react-dialog`](https://www.npmjs.com/package/@radix-ui/react-dialog) for the original package.
+
+mod dialog;
+
+pub use dialog::*;
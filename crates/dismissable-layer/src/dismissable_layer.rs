@@ -0,0 +1,623 @@
+use std::cell::{Cell, RefCell};
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use leptos::{
+    context::Provider,
+    ev::{FocusEvent, KeyboardEvent, PointerEvent},
+    html,
+    prelude::*,
+};
+use leptos_node_ref::prelude::*;
+use radix_leptos_compose_refs::use_composed_refs;
+use radix_leptos_primitive::Primitive;
+use send_wrapper::SendWrapper;
+use web_sys::{
+    js_sys::Function,
+    wasm_bindgen::{closure::Closure, JsCast, JsValue},
+    AddEventListenerOptions, CustomEvent, CustomEventInit, EventListenerOptions,
+};
+
+const POINTER_DOWN_OUTSIDE: &str = "pointerdownoutside";
+const FOCUS_OUTSIDE: &str = "focusoutside";
+const INTERACT_OUTSIDE: &str = "interactoutside";
+
+static COUNT: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct LayerId(u64);
+
+impl LayerId {
+    fn new() -> Self {
+        Self(COUNT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+struct Layer {
+    id: LayerId,
+    /// The closest enclosing layer. Interactions inside nested layers (e.g. portalled submenus)
+    /// count as inside their ancestors.
+    parent: Option<LayerId>,
+    node: web_sys::Element,
+    disable_outside_pointer_events: bool,
+}
+
+/// Global layer stack, in mount order. The last layer is the topmost one.
+struct DismissableLayerState {
+    layers: Vec<Layer>,
+    branches: Vec<(LayerId, web_sys::Element)>,
+    original_body_pointer_events: Option<String>,
+}
+
+thread_local! {
+    static STATE: RefCell<DismissableLayerState> = const {
+        RefCell::new(DismissableLayerState {
+            layers: Vec::new(),
+            branches: Vec::new(),
+            original_body_pointer_events: None,
+        })
+    };
+    static LAYERS_UPDATED: ArcTrigger = ArcTrigger::new();
+}
+
+#[derive(Clone, Copy)]
+struct DismissableLayerContextValue {
+    id: LayerId,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Events
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Event passed to `on_pointer_down_outside`. Calling `prevent_default` keeps the layer open.
+#[derive(Clone, Debug)]
+pub struct PointerDownOutsideEvent(CustomEvent);
+
+impl PointerDownOutsideEvent {
+    fn new(original_event: &PointerEvent) -> Self {
+        Self(create_outside_event(POINTER_DOWN_OUTSIDE, original_event))
+    }
+
+    /// The `pointerdown` event that happened outside the layer.
+    pub fn original_event(&self) -> PointerEvent {
+        self.0.detail().unchecked_into()
+    }
+}
+
+impl Deref for PointerDownOutsideEvent {
+    type Target = CustomEvent;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<PointerDownOutsideEvent> for web_sys::Event {
+    fn from(value: PointerDownOutsideEvent) -> Self {
+        value.0.into()
+    }
+}
+
+/// Event passed to `on_focus_outside`. Calling `prevent_default` keeps the layer open.
+#[derive(Clone, Debug)]
+pub struct FocusOutsideEvent(CustomEvent);
+
+impl FocusOutsideEvent {
+    fn new(original_event: &FocusEvent) -> Self {
+        Self(create_outside_event(FOCUS_OUTSIDE, original_event))
+    }
+
+    /// The `focusin` event that happened outside the layer.
+    pub fn original_event(&self) -> FocusEvent {
+        self.0.detail().unchecked_into()
+    }
+}
+
+impl Deref for FocusOutsideEvent {
+    type Target = CustomEvent;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<FocusOutsideEvent> for web_sys::Event {
+    fn from(value: FocusOutsideEvent) -> Self {
+        value.0.into()
+    }
+}
+
+/// Event passed to `on_interact_outside`, for both outside pointer downs and outside focus.
+/// Calling `prevent_default` keeps the layer open.
+#[derive(Clone, Debug)]
+pub enum InteractOutsideEvent {
+    PointerDownOutside(PointerDownOutsideEvent),
+    FocusOutside(FocusOutsideEvent),
+}
+
+impl InteractOutsideEvent {
+    /// The `pointerdown` or `focusin` event that happened outside the layer.
+    pub fn original_event(&self) -> web_sys::Event {
+        self.detail().unchecked_into()
+    }
+}
+
+impl Deref for InteractOutsideEvent {
+    type Target = CustomEvent;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            InteractOutsideEvent::PointerDownOutside(event) => &event.0,
+            InteractOutsideEvent::FocusOutside(event) => &event.0,
+        }
+    }
+}
+
+impl From<InteractOutsideEvent> for web_sys::Event {
+    fn from(value: InteractOutsideEvent) -> Self {
+        value.deref().clone().into()
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DismissableLayer
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+#[allow(non_snake_case)]
+pub fn DismissableLayer<C: IntoView + 'static>(
+    /// When `true`, hover/focus/click interactions will be disabled on elements outside
+    /// the `DismissableLayer`. Users will need to click twice on outside elements to
+    /// interact with them: once to close the `DismissableLayer`, and again to trigger the element.
+    #[prop(into, optional)]
+    disable_outside_pointer_events: MaybeProp<bool>,
+    /// Event handler called when the escape key is down.
+    /// Can be prevented.
+    #[prop(into, optional)]
+    on_escape_key_down: Option<Callback<KeyboardEvent>>,
+    /// Event handler called when a `pointerdown` event happens outside of the `DismissableLayer`.
+    /// Can be prevented.
+    #[prop(into, optional)]
+    on_pointer_down_outside: Option<Callback<PointerDownOutsideEvent>>,
+    /// Event handler called when the focus moves outside of the `DismissableLayer`.
+    /// Can be prevented.
+    #[prop(into, optional)]
+    on_focus_outside: Option<Callback<FocusOutsideEvent>>,
+    /// Event handler called when an interaction happens outside the `DismissableLayer`.
+    /// Specifically, when a `pointerdown` event happens outside or focus moves outside of it.
+    /// Can be prevented.
+    #[prop(into, optional)]
+    on_interact_outside: Option<Callback<InteractOutsideEvent>>,
+    /// Handler called when the `DismissableLayer` should be dismissed.
+    #[prop(into, optional)]
+    on_dismiss: Option<Callback<()>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(optional)] node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let disable_outside_pointer_events =
+        Signal::derive(move || disable_outside_pointer_events.get().unwrap_or(false));
+
+    let id = LayerId::new();
+    let parent = use_context::<DismissableLayerContextValue>().map(|context| context.id);
+    let layer_ref = AnyNodeRef::new();
+    let composed_refs = use_composed_refs([node_ref, layer_ref]);
+
+    Effect::new(move |_| {
+        let Some(node) = layer_ref.get() else {
+            return;
+        };
+        let disable_outside_pointer_events = disable_outside_pointer_events.get();
+
+        STATE.with_borrow_mut(|state| {
+            match state.layers.iter_mut().find(|layer| layer.id == id) {
+                Some(layer) => {
+                    layer.node = node;
+                    layer.disable_outside_pointer_events = disable_outside_pointer_events;
+                }
+                None => state.layers.push(Layer {
+                    id,
+                    parent,
+                    node,
+                    disable_outside_pointer_events,
+                }),
+            }
+            sync_body_pointer_events(state);
+        });
+        LAYERS_UPDATED.with(|trigger| trigger.notify());
+    });
+
+    on_cleanup(move || {
+        STATE.with_borrow_mut(|state| {
+            state.layers.retain(|layer| layer.id != id);
+            sync_body_pointer_events(state);
+        });
+        LAYERS_UPDATED.with(|trigger| trigger.notify());
+    });
+
+    let handle_pointer_down_outside = move |event: &PointerEvent| {
+        let is_pointer_down_on_branch = event
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+            .is_some_and(|target| is_inside_branch(&target));
+        if !is_pointer_events_enabled(id) || is_pointer_down_on_branch {
+            return;
+        }
+
+        let pointer_down_outside = PointerDownOutsideEvent::new(event);
+        if let Some(on_pointer_down_outside) = on_pointer_down_outside {
+            on_pointer_down_outside.run(pointer_down_outside.clone());
+        }
+        if let Some(on_interact_outside) = on_interact_outside {
+            on_interact_outside.run(InteractOutsideEvent::PointerDownOutside(
+                pointer_down_outside.clone(),
+            ));
+        }
+        dispatch_outside_events(layer_ref, &pointer_down_outside);
+        if !pointer_down_outside.default_prevented() {
+            if let Some(on_dismiss) = on_dismiss {
+                on_dismiss.run(());
+            }
+        }
+    };
+
+    let handle_focus_outside = move |event: &FocusEvent| {
+        let Some(target) = event
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+        else {
+            return;
+        };
+        if is_inside_layer_tree(id, layer_ref, &target) || is_inside_branch(&target) {
+            return;
+        }
+
+        let focus_outside = FocusOutsideEvent::new(event);
+        if let Some(on_focus_outside) = on_focus_outside {
+            on_focus_outside.run(focus_outside.clone());
+        }
+        if let Some(on_interact_outside) = on_interact_outside {
+            on_interact_outside.run(InteractOutsideEvent::FocusOutside(focus_outside.clone()));
+        }
+        dispatch_outside_events(layer_ref, &focus_outside);
+        if !focus_outside.default_prevented() {
+            if let Some(on_dismiss) = on_dismiss {
+                on_dismiss.run(());
+            }
+        }
+    };
+
+    let handle_escape_key_down = move |event: &KeyboardEvent| {
+        if event.key() != "Escape" || !is_highest_layer(id) {
+            return;
+        }
+        if let Some(on_escape_key_down) = on_escape_key_down {
+            on_escape_key_down.run(event.clone());
+        }
+        if !event.default_prevented() {
+            if let Some(on_dismiss) = on_dismiss {
+                event.prevent_default();
+                on_dismiss.run(());
+            }
+        }
+    };
+
+    Effect::new(move |_| {
+        // The listener is armed on the next tick, so the `pointerdown` that opened the layer is not
+        // treated as an outside interaction.
+        let is_armed = Rc::new(Cell::new(false));
+        let arm_timer = set_timeout_with_handle(
+            {
+                let is_armed = is_armed.clone();
+                move || is_armed.set(true)
+            },
+            Duration::ZERO,
+        )
+        .ok();
+
+        let pending_touch_event: Rc<RefCell<Option<PointerEvent>>> = Rc::new(RefCell::new(None));
+        let handle_click: Closure<dyn Fn()> = Closure::new({
+            let pending_touch_event = pending_touch_event.clone();
+            move || {
+                if let Some(event) = pending_touch_event.take() {
+                    handle_pointer_down_outside(&event);
+                }
+            }
+        });
+        let click_listener: Function = handle_click.as_ref().unchecked_ref::<Function>().clone();
+
+        let handle_pointer_down: Closure<dyn Fn(PointerEvent)> = Closure::new({
+            let click_listener = click_listener.clone();
+            move |event: PointerEvent| {
+                let Some(target) = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+                else {
+                    return;
+                };
+                if !is_armed.get() || is_inside_layer_tree(id, layer_ref, &target) {
+                    return;
+                }
+
+                // We need to remove the event listener in case the outside click has been canceled.
+                document()
+                    .remove_event_listener_with_callback("click", &click_listener)
+                    .expect("Click event listener should be removed.");
+
+                if event.pointer_type() == "touch" {
+                    // On touch devices, we need to wait for a click event because browsers implement
+                    // a ~350ms delay between the time the user stops touching the display and when the
+                    // browser executes events. We need to ensure we don't reactivate pointer-events within
+                    // this timeframe otherwise the browser may execute events that should have been prevented.
+                    *pending_touch_event.borrow_mut() = Some(event);
+                    let options = AddEventListenerOptions::new();
+                    options.set_once(true);
+                    document()
+                        .add_event_listener_with_callback_and_add_event_listener_options(
+                            "click",
+                            &click_listener,
+                            &options,
+                        )
+                        .expect("Click event listener should be added.");
+                } else {
+                    handle_pointer_down_outside(&event);
+                }
+            }
+        });
+
+        let handle_focus_in: Closure<dyn Fn(FocusEvent)> =
+            Closure::new(move |event: FocusEvent| handle_focus_outside(&event));
+
+        let handle_key_down: Closure<dyn Fn(KeyboardEvent)> =
+            Closure::new(move |event: KeyboardEvent| handle_escape_key_down(&event));
+
+        let capture_options = AddEventListenerOptions::new();
+        capture_options.set_capture(true);
+
+        document()
+            .add_event_listener_with_callback("pointerdown", handle_pointer_down.as_ref().unchecked_ref())
+            .expect("Pointer down event listener should be added.");
+        document()
+            .add_event_listener_with_callback("focusin", handle_focus_in.as_ref().unchecked_ref())
+            .expect("Focus in event listener should be added.");
+        document()
+            .add_event_listener_with_callback_and_add_event_listener_options(
+                "keydown",
+                handle_key_down.as_ref().unchecked_ref(),
+                &capture_options,
+            )
+            .expect("Key down event listener should be added.");
+
+        let listeners = SendWrapper::new((handle_pointer_down, handle_focus_in, handle_key_down, handle_click));
+        on_cleanup(move || {
+            let (handle_pointer_down, handle_focus_in, handle_key_down, handle_click) =
+                listeners.take();
+
+            if let Some(arm_timer) = arm_timer {
+                arm_timer.clear();
+            }
+
+            let capture_options = EventListenerOptions::new();
+            capture_options.set_capture(true);
+
+            document()
+                .remove_event_listener_with_callback(
+                    "pointerdown",
+                    handle_pointer_down.as_ref().unchecked_ref(),
+                )
+                .expect("Pointer down event listener should be removed.");
+            document()
+                .remove_event_listener_with_callback("focusin", handle_focus_in.as_ref().unchecked_ref())
+                .expect("Focus in event listener should be removed.");
+            document()
+                .remove_event_listener_with_callback_and_event_listener_options(
+                    "keydown",
+                    handle_key_down.as_ref().unchecked_ref(),
+                    &capture_options,
+                )
+                .expect("Key down event listener should be removed.");
+            document()
+                .remove_event_listener_with_callback("click", handle_click.as_ref().unchecked_ref())
+                .expect("Click event listener should be removed.");
+        });
+    });
+
+    view! {
+        <Provider value=DismissableLayerContextValue { id }>
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=composed_refs
+                style:pointer-events=move || {
+                    LAYERS_UPDATED.with(|trigger| trigger.track());
+                    match (is_body_pointer_events_disabled(), is_pointer_events_enabled(id)) {
+                        (false, _) => "",
+                        (true, true) => "auto",
+                        (true, false) => "none",
+                    }
+                }
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        </Provider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DismissableLayerBranch
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Marks content outside of a `DismissableLayer` (e.g. a toolbar rendered in a portal)
+/// where interactions should not dismiss any layer.
+#[component]
+#[allow(non_snake_case)]
+pub fn DismissableLayerBranch<C: IntoView + 'static>(
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(optional)] node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let id = LayerId::new();
+    let branch_ref = AnyNodeRef::new();
+    let composed_refs = use_composed_refs([node_ref, branch_ref]);
+
+    Effect::new(move |_| {
+        if let Some(node) = branch_ref.get() {
+            STATE.with_borrow_mut(|state| {
+                state.branches.retain(|(branch_id, _)| *branch_id != id);
+                state.branches.push((id, node));
+            });
+        }
+    });
+
+    on_cleanup(move || {
+        STATE.with_borrow_mut(|state| {
+            state.branches.retain(|(branch_id, _)| *branch_id != id);
+        });
+    });
+
+    view! {
+        <Primitive element=html::div as_child=as_child node_ref=composed_refs>
+            {children.with_value(|children| children())}
+        </Primitive>
+    }
+}
+
+/* -----------------------------------------------------------------------------------------------*/
+
+fn create_outside_event(name: &str, original_event: &JsValue) -> CustomEvent {
+    let init = CustomEventInit::new();
+    init.set_bubbles(false);
+    init.set_cancelable(true);
+    init.set_detail(original_event);
+    CustomEvent::new_with_event_init_dict(name, &init).expect("Outside event should be created.")
+}
+
+/// Dispatches the outside event and a matching `interactoutside` event on the layer node, so
+/// wrappers can listen with `on:pointerdownoutside`, `on:focusoutside` and `on:interactoutside`.
+fn dispatch_outside_events(layer_ref: AnyNodeRef, event: &CustomEvent) {
+    let Some(node) = layer_ref.get_untracked() else {
+        return;
+    };
+
+    node.dispatch_event(event)
+        .expect("Outside event should be dispatched.");
+
+    let interact_outside = create_outside_event(INTERACT_OUTSIDE, &event.detail());
+    node.dispatch_event(&interact_outside)
+        .expect("Interact outside event should be dispatched.");
+    if interact_outside.default_prevented() {
+        event.prevent_default();
+    }
+}
+
+fn sync_body_pointer_events(state: &mut DismissableLayerState) {
+    let Some(body) = document().body() else {
+        return;
+    };
+    let is_disabled = state
+        .layers
+        .iter()
+        .any(|layer| layer.disable_outside_pointer_events);
+
+    if is_disabled && state.original_body_pointer_events.is_none() {
+        state.original_body_pointer_events = Some(
+            body.style()
+                .get_property_value("pointer-events")
+                .unwrap_or_default(),
+        );
+        body.style()
+            .set_property("pointer-events", "none")
+            .expect("Pointer events should be set.");
+    } else if !is_disabled {
+        if let Some(original_body_pointer_events) = state.original_body_pointer_events.take() {
+            body.style()
+                .set_property("pointer-events", &original_body_pointer_events)
+                .expect("Pointer events should be restored.");
+        }
+    }
+}
+
+fn is_body_pointer_events_disabled() -> bool {
+    STATE.with_borrow(|state| {
+        state
+            .layers
+            .iter()
+            .any(|layer| layer.disable_outside_pointer_events)
+    })
+}
+
+/// Whether pointer events are enabled for the layer, i.e. it is not below the highest layer
+/// that disables outside pointer events.
+fn is_pointer_events_enabled(id: LayerId) -> bool {
+    STATE.with_borrow(|state| {
+        let index = state.layers.iter().position(|layer| layer.id == id);
+        let highest_disabled_index = state
+            .layers
+            .iter()
+            .rposition(|layer| layer.disable_outside_pointer_events);
+        match (index, highest_disabled_index) {
+            (_, None) => true,
+            (Some(index), Some(highest_disabled_index)) => index >= highest_disabled_index,
+            (None, Some(_)) => false,
+        }
+    })
+}
+
+fn is_highest_layer(id: LayerId) -> bool {
+    STATE.with_borrow(|state| state.layers.last().is_some_and(|layer| layer.id == id))
+}
+
+fn is_inside_layer_tree(id: LayerId, layer_ref: AnyNodeRef, target: &web_sys::Node) -> bool {
+    if layer_ref
+        .get_untracked()
+        .is_some_and(|node| node.contains(Some(target)))
+    {
+        return true;
+    }
+
+    STATE.with_borrow(|state| {
+        state
+            .layers
+            .iter()
+            .filter(|layer| layer.id == id || is_descendant_of(state, layer, id))
+            .any(|layer| layer.node.contains(Some(target)))
+    })
+}
+
+fn is_descendant_of(state: &DismissableLayerState, layer: &Layer, ancestor: LayerId) -> bool {
+    let mut parent = layer.parent;
+    while let Some(parent_id) = parent {
+        if parent_id == ancestor {
+            return true;
+        }
+        parent = state
+            .layers
+            .iter()
+            .find(|layer| layer.id == parent_id)
+            .and_then(|layer| layer.parent);
+    }
+    false
+}
+
+fn is_inside_branch(target: &web_sys::Node) -> bool {
+    STATE.with_borrow(|state| {
+        state
+            .branches
+            .iter()
+            .any(|(_, branch)| branch.contains(Some(target)))
+    })
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub mod primitive {
+    pub use super::*;
+    pub use DismissableLayer as Root;
+    pub use DismissableLayerBranch as Branch;
+}
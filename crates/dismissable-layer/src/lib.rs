@@ -0,0 +1,9 @@
+//! Leptos port of [Radix Dismissable Layer](https://www.radix-ui.com/primitives).
+//!
+//! This is an internal utility, not intended for public usage.
+//!
+//! See [`@radix-ui/react-dismissable-layer`](https://www.npmjs.com/package/@radix-ui/react-dismissable-layer) for the original package.
+
+mod dismissable_layer;
+
+pub use dismissable_layer::*;
@@ -0,0 +1,635 @@
+use leptos::wasm_bindgen::JsCast;
+use leptos::{ev, html, prelude::*};
+use leptos_node_ref::AnyNodeRef;
+use leptos_remove_scroll::{handle_scroll, Axis, TouchAction, TouchTracker};
+use radix_leptos_compose_refs::use_composed_refs;
+use radix_leptos_context::create_context;
+use radix_leptos_dialog::primitive as DialogPrimitive;
+use radix_leptos_dismissable_layer::{InteractOutsideEvent, PointerDownOutsideEvent};
+use radix_leptos_primitive::{compose_callbacks, Primitive};
+use radix_leptos_use_controllable_state::{use_controllable_state, UseControllableStateParams};
+
+use crate::gesture::{
+    background_scale, background_translate, dismiss_distance, drawer_transform, open_fraction,
+    resist_offset, resolve_release, resolve_snap_point, snap_point_offsets, DragOutcome,
+    DrawerDirection, BACKGROUND_BORDER_RADIUS, CLOSE_THRESHOLD,
+};
+
+/// Transition used when the drawer settles after a drag.
+const TRANSITION: &str = "transform 0.5s cubic-bezier(0.32, 0.72, 0, 1)";
+
+/* -------------------------------------------------------------------------------------------------
+ * Drawer
+ * -----------------------------------------------------------------------------------------------*/
+
+const DRAWER_NAME: &str = "Drawer";
+
+#[derive(Clone, Copy)]
+struct DrawerContextValue {
+    open: Signal<bool>,
+    on_open_change: Callback<bool>,
+    direction: Signal<DrawerDirection>,
+    dismissible: Signal<bool>,
+    close_threshold: Signal<f64>,
+    snap_points: Signal<Vec<f64>>,
+    active_snap_point: Signal<usize>,
+    on_active_snap_point_change: Callback<usize>,
+    /// How open the drawer is while it's being dragged, `None` otherwise.
+    drag_fraction: RwSignal<Option<f64>>,
+}
+
+create_context!(
+    context_type: DrawerContextValue,
+    provider: DrawerProvider,
+    hook: use_drawer_context,
+    root: DRAWER_NAME
+);
+
+/// A dialog attached to a side of the viewport that can be dragged back towards it to dismiss.
+#[component]
+#[allow(non_snake_case)]
+pub fn Drawer(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] open: MaybeProp<bool>,
+    #[prop(optional, into)] default_open: MaybeProp<bool>,
+    #[prop(optional, into)] on_open_change: Option<Callback<bool>>,
+    /// When `true`, focus is trapped, outside pointer events are blocked and scrolling is locked
+    /// while open. Defaults to `true`.
+    #[prop(optional, into)]
+    modal: MaybeProp<bool>,
+    /// The side the drawer is attached to. Defaults to `Bottom`.
+    #[prop(optional, into)]
+    direction: MaybeProp<DrawerDirection>,
+    /// When `false`, the drawer can't be closed by dragging, pressing Escape or interacting
+    /// outside of it. Defaults to `true`.
+    #[prop(optional, into)]
+    dismissible: MaybeProp<bool>,
+    /// Fraction of the drawer's size it has to be dragged to close on release. Defaults to
+    /// [`CLOSE_THRESHOLD`].
+    #[prop(optional, into)]
+    close_threshold: MaybeProp<f64>,
+    /// Positions a drag settles on, as ascending fractions of the viewport the drawer covers.
+    /// The content should span the viewport along the drawer's axis.
+    #[prop(optional, into)]
+    snap_points: MaybeProp<Vec<f64>>,
+    /// Index into `snap_points` of the position the drawer rests at.
+    #[prop(optional, into)]
+    active_snap_point: MaybeProp<usize>,
+    #[prop(optional, into)] default_active_snap_point: MaybeProp<usize>,
+    #[prop(optional, into)] on_active_snap_point_change: Option<Callback<usize>>,
+    /// Scale the page behind the drawer while it's open, through the
+    /// `--radix-drawer-background-*` properties on the document element.
+    #[prop(optional, into)]
+    should_scale_background: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+
+    let (open, set_open) = use_controllable_state(UseControllableStateParams {
+        prop: open,
+        default_prop: default_open,
+        on_change: on_open_change,
+    });
+    let (active_snap_point, set_active_snap_point) =
+        use_controllable_state(UseControllableStateParams {
+            prop: active_snap_point,
+            default_prop: default_active_snap_point,
+            on_change: on_active_snap_point_change,
+        });
+
+    let context_value = DrawerContextValue {
+        open,
+        on_open_change: set_open,
+        direction: Signal::derive(move || direction.get().unwrap_or_default()),
+        dismissible: Signal::derive(move || dismissible.get().unwrap_or(true)),
+        close_threshold: Signal::derive(move || close_threshold.get().unwrap_or(CLOSE_THRESHOLD)),
+        snap_points: Signal::derive(move || snap_points.get().unwrap_or_default()),
+        active_snap_point,
+        on_active_snap_point_change: set_active_snap_point,
+        drag_fraction: RwSignal::new(None),
+    };
+
+    Effect::new(move |_| {
+        if should_scale_background.get().unwrap_or(false) {
+            let fraction = match open.get() {
+                true => context_value.drag_fraction.get().unwrap_or(1.0),
+                false => 0.0,
+            };
+            set_background_properties(fraction);
+        }
+    });
+    on_cleanup(remove_background_properties);
+
+    view! {
+        <DrawerProvider value=context_value>
+            <DialogPrimitive::Root open=open on_open_change=set_open modal=modal>
+                {children.with_value(|children| children())}
+            </DialogPrimitive::Root>
+        </DrawerProvider>
+    }
+}
+
+/// The side the closest `Drawer` is attached to, e.g. to style its content accordingly.
+pub fn use_drawer_direction() -> Signal<DrawerDirection> {
+    use_drawer_context(DRAWER_NAME).direction
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DrawerOverlay
+ * -----------------------------------------------------------------------------------------------*/
+
+const OVERLAY_NAME: &str = "DrawerOverlay";
+
+/// Covers the inert portion of the view and fades out as the drawer is dragged closed.
+#[component]
+#[allow(non_snake_case)]
+pub fn DrawerOverlay(
+    /// Keep the overlay mounted while closed, e.g. to control animations from outside.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let context = use_drawer_context(OVERLAY_NAME);
+
+    view! {
+        <DialogPrimitive::Overlay
+            force_mount=force_mount
+            node_ref=node_ref
+            as_child=as_child
+            style:opacity=move || {
+                context.drag_fraction.get().map(|fraction| fraction.to_string()).unwrap_or_default()
+            }
+            style:transition=move || {
+                if context.drag_fraction.get().is_some() { "none" } else { "" }.to_string()
+            }
+        />
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DrawerContent
+ * -----------------------------------------------------------------------------------------------*/
+
+const CONTENT_NAME: &str = "DrawerContent";
+
+#[derive(Clone, Copy)]
+struct DragState {
+    pointer_id: i32,
+    /// Pointer coordinate along the drawer's axis when the drag started.
+    start: f64,
+    start_time: f64,
+    /// Offset the drawer rested at when the drag started.
+    start_offset: f64,
+    /// Offset at which the drawer is fully hidden.
+    closed_offset: f64,
+    /// Whether the pointer has moved far enough to be a drag rather than a scroll.
+    is_dragging: bool,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn DrawerContent(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// Keep the content mounted while closed, e.g. to control animations from outside.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+    /// Event handler called when focus moves into the content on open. Can be prevented.
+    #[prop(optional, into)]
+    on_open_auto_focus: Option<Callback<ev::Event>>,
+    /// Event handler called when focus moves back to the trigger on close. Can be prevented.
+    #[prop(optional, into)]
+    on_close_auto_focus: Option<Callback<ev::Event>>,
+    #[prop(optional, into)] on_escape_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(optional, into)] on_pointer_down_outside: Option<Callback<PointerDownOutsideEvent>>,
+    #[prop(optional, into)] on_interact_outside: Option<Callback<InteractOutsideEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_drawer_context(CONTENT_NAME);
+    let content_ref = AnyNodeRef::new();
+    let composed_refs = use_composed_refs((node_ref, content_ref));
+
+    let drag = StoredValue::new(None::<DragState>);
+    let drag_offset = RwSignal::new(None::<f64>);
+    let is_dragging = RwSignal::new(false);
+    let touch_tracker = StoredValue::new_local(TouchTracker::new());
+
+    let resting_offset = move || {
+        let direction = context.direction.get();
+        let snap_offsets = snap_point_offsets(&context.snap_points.get(), viewport_size(direction));
+        snap_offsets
+            .get(context.active_snap_point.get())
+            .copied()
+            .unwrap_or(0.0)
+    };
+
+    // Start from the resting position whenever the drawer (re)opens.
+    Effect::new(move |_| {
+        if context.open.get() {
+            drag_offset.set(None);
+        }
+    });
+
+    let cancel_drag = move || {
+        drag.set_value(None);
+        is_dragging.set(false);
+        drag_offset.set(None);
+        context.drag_fraction.set(None);
+    };
+
+    let handle_pointer_down = move |event: ev::PointerEvent| {
+        if event.button() != 0 || drag.with_value(|drag| drag.is_some()) {
+            return;
+        }
+        let Some(content) = content_ref.get_untracked() else {
+            return;
+        };
+        let direction = context.direction.get_untracked();
+        let rect = content.get_bounding_client_rect();
+        let has_snap_points = context
+            .snap_points
+            .with_untracked(|snap_points| !snap_points.is_empty());
+
+        drag.set_value(Some(DragState {
+            pointer_id: event.pointer_id(),
+            start: pointer_position(&event, direction),
+            start_time: event.time_stamp(),
+            start_offset: untrack(resting_offset),
+            closed_offset: match (has_snap_points, direction.is_vertical()) {
+                (true, _) => viewport_size(direction),
+                (false, true) => rect.height(),
+                (false, false) => rect.width(),
+            },
+            is_dragging: false,
+        }));
+    };
+
+    let handle_pointer_move = move |event: ev::PointerEvent| {
+        let Some(mut state) = drag.get_value() else {
+            return;
+        };
+        if event.pointer_id() != state.pointer_id {
+            return;
+        }
+        let Some(content) = content_ref.get_untracked() else {
+            return;
+        };
+        let content = content.unchecked_into::<web_sys::HtmlElement>();
+        let direction = context.direction.get_untracked();
+        let position = pointer_position(&event, direction);
+        let distance = dismiss_distance(direction, state.start, position);
+
+        if !state.is_dragging {
+            if distance == 0.0 {
+                return;
+            }
+            if !should_drag(&event, &content, direction, position - state.start) {
+                drag.set_value(None);
+                return;
+            }
+            state.is_dragging = true;
+            drag.set_value(Some(state));
+            is_dragging.set(true);
+            let _ = content.set_pointer_capture(state.pointer_id);
+        }
+
+        let max_offset = (!context.dismissible.get_untracked()).then(|| {
+            context.snap_points.with_untracked(|snap_points| {
+                snap_point_offsets(snap_points, viewport_size(direction))
+                    .first()
+                    .copied()
+                    .unwrap_or(0.0)
+            })
+        });
+        let offset = resist_offset(state.start_offset + distance, max_offset);
+        drag_offset.set(Some(offset));
+        context
+            .drag_fraction
+            .set(Some(open_fraction(offset, state.closed_offset)));
+    };
+
+    let handle_pointer_up = move |event: ev::PointerEvent| {
+        let Some(state) = drag.get_value() else {
+            return;
+        };
+        if event.pointer_id() != state.pointer_id {
+            return;
+        }
+        if !state.is_dragging {
+            drag.set_value(None);
+            return;
+        }
+
+        let direction = context.direction.get_untracked();
+        let distance =
+            dismiss_distance(direction, state.start, pointer_position(&event, direction));
+        let velocity = distance / (event.time_stamp() - state.start_time).max(1.0);
+        let offset = drag_offset.get_untracked().unwrap_or(state.start_offset);
+        let dismissible = context.dismissible.get_untracked();
+        let snap_points = context.snap_points.get_untracked();
+
+        let outcome = if snap_points.is_empty() {
+            resolve_release(
+                distance,
+                velocity,
+                state.closed_offset,
+                context.close_threshold.get_untracked(),
+                dismissible,
+            )
+        } else {
+            resolve_snap_point(
+                &snap_point_offsets(&snap_points, viewport_size(direction)),
+                context.active_snap_point.get_untracked(),
+                offset,
+                velocity,
+                state.closed_offset,
+                dismissible,
+            )
+        };
+
+        match outcome {
+            DragOutcome::Close => {
+                // Keep the dragged offset so the exit animation starts from where it was released.
+                drag.set_value(None);
+                is_dragging.set(false);
+                context.drag_fraction.set(None);
+                context.on_open_change.run(false);
+            }
+            DragOutcome::Open => cancel_drag(),
+            DragOutcome::SnapTo(index) => {
+                cancel_drag();
+                context.on_active_snap_point_change.run(index);
+            }
+        }
+    };
+
+    let dismissible = context.dismissible;
+
+    view! {
+        <DialogPrimitive::Content
+            force_mount=force_mount
+            on_open_auto_focus=on_open_auto_focus
+            on_close_auto_focus=on_close_auto_focus
+            on_escape_key_down=Callback::new(compose_callbacks(
+                on_escape_key_down,
+                Some(Callback::new(move |event: ev::KeyboardEvent| {
+                    if !dismissible.get_untracked() {
+                        event.prevent_default();
+                    }
+                })),
+                None,
+            ))
+            on_pointer_down_outside=Callback::new(compose_callbacks(
+                on_pointer_down_outside,
+                Some(Callback::new(move |event: PointerDownOutsideEvent| {
+                    if !dismissible.get_untracked() {
+                        event.prevent_default();
+                    }
+                })),
+                None,
+            ))
+            on_interact_outside=Callback::new(compose_callbacks(
+                on_interact_outside,
+                Some(Callback::new(move |event: InteractOutsideEvent| {
+                    if !dismissible.get_untracked() {
+                        event.prevent_default();
+                    }
+                })),
+                None,
+            ))
+            node_ref=composed_refs
+            as_child=as_child
+            attr:data-direction=move || context.direction.get().as_str()
+            attr:data-dragging=move || is_dragging.get().then_some("")
+            // Leave panning along the drawer's axis to the browser, so scrollable children keep
+            // scrolling natively. Once a drag starts, `touchmove` cancels the pan instead.
+            style:touch-action=move || if context.direction.get().is_vertical() { "pan-y" } else { "pan-x" }
+            style:transform=move || {
+                let offset = drag_offset.get().unwrap_or_else(resting_offset);
+                if offset == 0.0 {
+                    String::new()
+                } else {
+                    drawer_transform(context.direction.get(), offset)
+                }
+            }
+            style:transition=move || if is_dragging.get() { "none" } else { TRANSITION }.to_string()
+            on:pointerdown=handle_pointer_down
+            on:pointermove=handle_pointer_move
+            on:pointerup=handle_pointer_up
+            on:pointercancel=move |_: ev::PointerEvent| cancel_drag()
+            on:touchstart=move |event: ev::TouchEvent| {
+                touch_tracker.update_value(|touch_tracker| {
+                    touch_tracker.track_touch(&event);
+                });
+            }
+            on:touchmove=move |event: ev::TouchEvent| {
+                // Leave pinch and zoom gestures to the browser.
+                let action = touch_tracker
+                    .try_update_value(|touch_tracker| touch_tracker.track_touch(&event));
+                if matches!(action, Some(TouchAction::Pinch { .. } | TouchAction::Zoom)) {
+                    cancel_drag();
+                } else if is_dragging.get_untracked() {
+                    event.prevent_default();
+                }
+            }
+        >
+            {children.with_value(|children| children())}
+        </DialogPrimitive::Content>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * DrawerHandle
+ * -----------------------------------------------------------------------------------------------*/
+
+const HANDLE_NAME: &str = "DrawerHandle";
+
+/// A visual affordance for dragging. Clicking it cycles through the snap points.
+#[component]
+#[allow(non_snake_case)]
+pub fn DrawerHandle(
+    #[prop(optional)] children: Option<ChildrenFn>,
+    #[prop(optional, into)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    let context = use_drawer_context(HANDLE_NAME);
+
+    view! {
+        <Primitive
+            element=html::div
+            as_child=as_child
+            node_ref=node_ref
+            attr:aria-hidden="true"
+            attr:data-drawer-handle=""
+            style:touch-action="none"
+            on:click=compose_callbacks(
+                on_click,
+                Some(Callback::new(move |_: ev::MouseEvent| {
+                    let count = context.snap_points.with_untracked(|snap_points| snap_points.len());
+                    if count > 0 {
+                        let next = (context.active_snap_point.get_untracked() + 1) % count;
+                        context.on_active_snap_point_change.run(next);
+                    }
+                })),
+                None,
+            )
+        >
+            {children.with_value(|children| children.as_ref().map(|children| children()))}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Helpers
+ * -----------------------------------------------------------------------------------------------*/
+
+fn viewport_size(direction: DrawerDirection) -> f64 {
+    let window = window();
+    let size = match direction.is_vertical() {
+        true => window.inner_height(),
+        false => window.inner_width(),
+    };
+    size.ok().and_then(|size| size.as_f64()).unwrap_or_default()
+}
+
+fn pointer_position(event: &ev::PointerEvent, direction: DrawerDirection) -> f64 {
+    match direction.is_vertical() {
+        true => event.client_y() as f64,
+        false => event.client_x() as f64,
+    }
+}
+
+/// Whether moving the pointer `delta` pixels along the drawer's axis should drag it, rather than
+/// scroll an element inside `content` that can still scroll that way.
+fn should_drag(
+    event: &ev::PointerEvent,
+    content: &web_sys::HtmlElement,
+    direction: DrawerDirection,
+    delta: f64,
+) -> bool {
+    let Some(target) = event
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+    else {
+        return true;
+    };
+    let axis = || match direction.is_vertical() {
+        true => Axis::V,
+        false => Axis::H,
+    };
+
+    match get_scrollable_ancestor(&target, content, axis()) {
+        // Dragging the pointer one way scrolls the content the other way.
+        Some(scrollable) => handle_scroll(axis(), &scrollable, event, -delta, false),
+        None => true,
+    }
+}
+
+/// The closest element from `target` up to and including `content` that scrolls along `axis`.
+fn get_scrollable_ancestor(
+    target: &web_sys::HtmlElement,
+    content: &web_sys::HtmlElement,
+    axis: Axis,
+) -> Option<web_sys::HtmlElement> {
+    let window = window();
+    let mut current = Some(target.clone());
+
+    while let Some(element) = current {
+        let (overflow, scroll_size, client_size) = match axis {
+            Axis::V => (
+                "overflow-y",
+                element.scroll_height(),
+                element.client_height(),
+            ),
+            Axis::H => ("overflow-x", element.scroll_width(), element.client_width()),
+        };
+        let is_scrollable = scroll_size > client_size
+            && window
+                .get_computed_style(&element)
+                .ok()
+                .flatten()
+                .and_then(|style| style.get_property_value(overflow).ok())
+                .is_some_and(|overflow| overflow == "auto" || overflow == "scroll");
+        if is_scrollable {
+            return Some(element);
+        }
+        if &element == content {
+            break;
+        }
+        current = element
+            .parent_element()
+            .and_then(|parent| parent.dyn_into::<web_sys::HtmlElement>().ok());
+    }
+
+    None
+}
+
+const BACKGROUND_PROPERTIES: [&str; 3] = [
+    "--radix-drawer-background-scale",
+    "--radix-drawer-background-translate",
+    "--radix-drawer-background-border-radius",
+];
+
+fn set_background_properties(open_fraction: f64) {
+    let Some(root) = document()
+        .document_element()
+        .and_then(|root| root.dyn_into::<web_sys::HtmlElement>().ok())
+    else {
+        return;
+    };
+    let width = window()
+        .inner_width()
+        .ok()
+        .and_then(|width| width.as_f64())
+        .unwrap_or_default();
+    let values = [
+        background_scale(width, open_fraction).to_string(),
+        format!("{}px", background_translate(open_fraction)),
+        format!(
+            "{}px",
+            BACKGROUND_BORDER_RADIUS * open_fraction.clamp(0.0, 1.0)
+        ),
+    ];
+
+    let style = root.style();
+    for (property, value) in BACKGROUND_PROPERTIES.iter().zip(values) {
+        let _ = style.set_property(property, &value);
+    }
+}
+
+fn remove_background_properties() {
+    if let Some(root) = document()
+        .document_element()
+        .and_then(|root| root.dyn_into::<web_sys::HtmlElement>().ok())
+    {
+        let style = root.style();
+        for property in BACKGROUND_PROPERTIES {
+            let _ = style.remove_property(property);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use radix_leptos_dialog::{
+    DialogClose as DrawerClose, DialogDescription as DrawerDescription,
+    DialogPortal as DrawerPortal, DialogTitle as DrawerTitle, DialogTrigger as DrawerTrigger,
+};
+
+pub mod primitive {
+    pub use super::*;
+    pub use Drawer as Root;
+    pub use DrawerClose as Close;
+    pub use DrawerContent as Content;
+    pub use DrawerDescription as Description;
+    pub use DrawerHandle as Handle;
+    pub use DrawerOverlay as Overlay;
+    pub use DrawerPortal as Portal;
+    pub use DrawerTitle as Title;
+    pub use DrawerTrigger as Trigger;
+}
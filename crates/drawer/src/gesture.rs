@@ -0,0 +1,190 @@
+//! Drag gesture math for `Drawer`, kept free of DOM types so it can be tested natively.
+
+/// Flicks faster than this, in pixels per millisecond, dismiss the drawer or move it to the next
+/// snap point regardless of how far it was dragged.
+pub const VELOCITY_THRESHOLD: f64 = 0.4;
+
+/// Fraction of the drawer's size it has to be dragged towards its side to close on release.
+pub const CLOSE_THRESHOLD: f64 = 0.25;
+
+/// How far the scaled background is pushed away from the drawer's side, in pixels.
+pub const BACKGROUND_OFFSET: f64 = 26.0;
+
+/// Border radius of the scaled background, in pixels.
+pub const BACKGROUND_BORDER_RADIUS: f64 = 8.0;
+
+/// The side of the viewport a drawer is attached to. It slides in from, and is dragged back
+/// towards, this side.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum DrawerDirection {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+impl DrawerDirection {
+    /// Whether the drawer is dragged along the vertical axis.
+    pub fn is_vertical(self) -> bool {
+        matches!(self, DrawerDirection::Top | DrawerDirection::Bottom)
+    }
+
+    /// `1.0` when moving the pointer towards this side increases its client coordinate.
+    fn sign(self) -> f64 {
+        match self {
+            DrawerDirection::Bottom | DrawerDirection::Right => 1.0,
+            DrawerDirection::Top | DrawerDirection::Left => -1.0,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DrawerDirection::Top => "top",
+            DrawerDirection::Bottom => "bottom",
+            DrawerDirection::Left => "left",
+            DrawerDirection::Right => "right",
+        }
+    }
+}
+
+/// Where a drag settles once the pointer is released.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DragOutcome {
+    /// Close the drawer.
+    Close,
+    /// Return to the open position.
+    Open,
+    /// Settle on the snap point at this index.
+    SnapTo(usize),
+}
+
+/// The distance the pointer moved from `start` to `current` towards the drawer's side, along its
+/// axis. Negative when dragged away from the side, i.e. further open.
+pub fn dismiss_distance(direction: DrawerDirection, start: f64, current: f64) -> f64 {
+    (current - start) * direction.sign()
+}
+
+/// Logarithmic resistance for dragging `overshoot` pixels past a limit.
+pub fn rubber_band(overshoot: f64) -> f64 {
+    8.0 * (overshoot.max(0.0) + 1.0).ln()
+}
+
+/// Applies resistance to an `offset` towards the drawer's side when it goes past the fully open
+/// position, or past `max_offset` when the drawer can't be dismissed.
+pub fn resist_offset(offset: f64, max_offset: Option<f64>) -> f64 {
+    if offset < 0.0 {
+        return -rubber_band(-offset);
+    }
+    match max_offset {
+        Some(max_offset) if offset > max_offset => max_offset + rubber_band(offset - max_offset),
+        _ => offset,
+    }
+}
+
+/// The CSS transform that moves a drawer `offset` pixels towards its side.
+pub fn drawer_transform(direction: DrawerDirection, offset: f64) -> String {
+    let offset = offset * direction.sign();
+    if direction.is_vertical() {
+        format!("translate3d(0, {offset}px, 0)")
+    } else {
+        format!("translate3d({offset}px, 0, 0)")
+    }
+}
+
+/// Offsets towards the drawer's side for each snap point, given as the fraction of the viewport
+/// the drawer should cover. Snap points are expected in ascending order.
+pub fn snap_point_offsets(snap_points: &[f64], viewport_size: f64) -> Vec<f64> {
+    snap_points
+        .iter()
+        .map(|snap_point| viewport_size - snap_point.clamp(0.0, 1.0) * viewport_size)
+        .collect()
+}
+
+/// Resolves a released drag for a drawer without snap points.
+///
+/// `distance` is the [`dismiss_distance`] of the drag, `velocity` its speed towards the drawer's
+/// side in pixels per millisecond, and `size` the drawer's size along its axis.
+pub fn resolve_release(
+    distance: f64,
+    velocity: f64,
+    size: f64,
+    close_threshold: f64,
+    dismissible: bool,
+) -> DragOutcome {
+    if !dismissible || distance <= 0.0 {
+        return DragOutcome::Open;
+    }
+    if velocity > VELOCITY_THRESHOLD || distance >= size * close_threshold {
+        DragOutcome::Close
+    } else {
+        DragOutcome::Open
+    }
+}
+
+/// Resolves a released drag for a drawer with snap points.
+///
+/// `snap_offsets` come from [`snap_point_offsets`], `active` is the snap point the drag started
+/// from, `offset` where the drawer was released and `velocity` its signed speed towards the
+/// drawer's side. `closed_offset` is the offset at which the drawer is fully hidden.
+pub fn resolve_snap_point(
+    snap_offsets: &[f64],
+    active: usize,
+    offset: f64,
+    velocity: f64,
+    closed_offset: f64,
+    dismissible: bool,
+) -> DragOutcome {
+    let Some(last) = snap_offsets.len().checked_sub(1) else {
+        return DragOutcome::Open;
+    };
+    let active = active.min(last);
+
+    // A flick moves one snap point in its direction.
+    if velocity.abs() > VELOCITY_THRESHOLD {
+        return if velocity > 0.0 {
+            match active {
+                0 if dismissible => DragOutcome::Close,
+                0 => DragOutcome::SnapTo(0),
+                _ => DragOutcome::SnapTo(active - 1),
+            }
+        } else {
+            DragOutcome::SnapTo((active + 1).min(last))
+        };
+    }
+
+    // Otherwise settle on the closest snap point, or close when that is nearer.
+    let (closest, distance) = snap_offsets
+        .iter()
+        .enumerate()
+        .map(|(index, snap_offset)| (index, (snap_offset - offset).abs()))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("snap offsets are not empty");
+    if dismissible && (closed_offset - offset).abs() < distance {
+        DragOutcome::Close
+    } else {
+        DragOutcome::SnapTo(closest)
+    }
+}
+
+/// How open the drawer is from `0.0`, fully hidden, to `1.0`, given its `offset` towards its side.
+pub fn open_fraction(offset: f64, closed_offset: f64) -> f64 {
+    if closed_offset <= 0.0 {
+        return 1.0;
+    }
+    (1.0 - offset / closed_offset).clamp(0.0, 1.0)
+}
+
+/// Scale of the background behind a drawer that is `open_fraction` open.
+pub fn background_scale(viewport_width: f64, open_fraction: f64) -> f64 {
+    if viewport_width <= 0.0 {
+        return 1.0;
+    }
+    let scale = (viewport_width - BACKGROUND_OFFSET) / viewport_width;
+    1.0 - (1.0 - scale) * open_fraction.clamp(0.0, 1.0)
+}
+
+/// How far the background behind a drawer that is `open_fraction` open is pushed back, in pixels.
+pub fn background_translate(open_fraction: f64) -> f64 {
+    BACKGROUND_OFFSET * open_fraction.clamp(0.0, 1.0)
+}
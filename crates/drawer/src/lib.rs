@@ -0,0 +1,11 @@
+//! Leptos port of [Vaul](https://vaul.emilkowal.ski/), a drawer built on [Radix Dialog](https://www.radix-ui.com/primitives).
+//!
+//! A dialog that slides in from an edge of the screen and can be dismissed by swiping it away.
+//!
+//! See [`vaul`](https://www.npmjs.com/package/vaul) for the original package.
+
+mod drawer;
+mod gesture;
+
+pub use drawer::*;
+pub use gesture::*;
@@ -0,0 +1,151 @@
+use radix_leptos_drawer::*;
+
+const EPSILON: f64 = 1e-9;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < EPSILON,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn dismiss_distance_points_towards_the_side() {
+    assert_close(
+        dismiss_distance(DrawerDirection::Bottom, 100.0, 160.0),
+        60.0,
+    );
+    assert_close(dismiss_distance(DrawerDirection::Top, 100.0, 160.0), -60.0);
+    assert_close(dismiss_distance(DrawerDirection::Right, 100.0, 40.0), -60.0);
+    assert_close(dismiss_distance(DrawerDirection::Left, 100.0, 40.0), 60.0);
+}
+
+#[test]
+fn resist_offset_dampens_past_the_limits() {
+    assert_close(resist_offset(50.0, None), 50.0);
+
+    let open_overshoot = resist_offset(-100.0, None);
+    assert!(open_overshoot < 0.0 && open_overshoot > -100.0);
+
+    let max_overshoot = resist_offset(150.0, Some(100.0));
+    assert!(max_overshoot > 100.0 && max_overshoot < 150.0);
+    assert_close(resist_offset(80.0, Some(100.0)), 80.0);
+}
+
+#[test]
+fn drawer_transform_follows_the_direction() {
+    assert_eq!(
+        drawer_transform(DrawerDirection::Bottom, 20.0),
+        "translate3d(0, 20px, 0)"
+    );
+    assert_eq!(
+        drawer_transform(DrawerDirection::Top, 20.0),
+        "translate3d(0, -20px, 0)"
+    );
+    assert_eq!(
+        drawer_transform(DrawerDirection::Left, 20.0),
+        "translate3d(-20px, 0, 0)"
+    );
+    assert_eq!(
+        drawer_transform(DrawerDirection::Right, 20.0),
+        "translate3d(20px, 0, 0)"
+    );
+}
+
+#[test]
+fn resolve_release_closes_on_distance_or_velocity() {
+    // Slow and short: stays open.
+    assert_eq!(
+        resolve_release(50.0, 0.1, 400.0, CLOSE_THRESHOLD, true),
+        DragOutcome::Open
+    );
+    // Slow but past the threshold.
+    assert_eq!(
+        resolve_release(100.0, 0.1, 400.0, CLOSE_THRESHOLD, true),
+        DragOutcome::Close
+    );
+    // Short but fast.
+    assert_eq!(
+        resolve_release(20.0, VELOCITY_THRESHOLD + 0.1, 400.0, CLOSE_THRESHOLD, true),
+        DragOutcome::Close
+    );
+    // Dragged further open.
+    assert_eq!(
+        resolve_release(-100.0, 1.0, 400.0, CLOSE_THRESHOLD, true),
+        DragOutcome::Open
+    );
+    // Not dismissible.
+    assert_eq!(
+        resolve_release(300.0, 1.0, 400.0, CLOSE_THRESHOLD, false),
+        DragOutcome::Open
+    );
+}
+
+#[test]
+fn snap_point_offsets_are_measured_from_the_viewport() {
+    let offsets = snap_point_offsets(&[0.25, 0.5, 1.0], 800.0);
+    assert_eq!(offsets, vec![600.0, 400.0, 0.0]);
+}
+
+#[test]
+fn resolve_snap_point_settles_on_the_closest() {
+    let offsets = [600.0, 400.0, 0.0];
+
+    assert_eq!(
+        resolve_snap_point(&offsets, 1, 430.0, 0.0, 800.0, true),
+        DragOutcome::SnapTo(1)
+    );
+    assert_eq!(
+        resolve_snap_point(&offsets, 1, 150.0, 0.0, 800.0, true),
+        DragOutcome::SnapTo(2)
+    );
+    assert_eq!(
+        resolve_snap_point(&offsets, 0, 750.0, 0.0, 800.0, true),
+        DragOutcome::Close
+    );
+    assert_eq!(
+        resolve_snap_point(&offsets, 0, 750.0, 0.0, 800.0, false),
+        DragOutcome::SnapTo(0)
+    );
+}
+
+#[test]
+fn resolve_snap_point_flicks_to_the_neighbour() {
+    let offsets = [600.0, 400.0, 0.0];
+    let fast = VELOCITY_THRESHOLD + 0.1;
+
+    assert_eq!(
+        resolve_snap_point(&offsets, 1, 390.0, fast, 800.0, true),
+        DragOutcome::SnapTo(0)
+    );
+    assert_eq!(
+        resolve_snap_point(&offsets, 1, 410.0, -fast, 800.0, true),
+        DragOutcome::SnapTo(2)
+    );
+    assert_eq!(
+        resolve_snap_point(&offsets, 2, 0.0, -fast, 800.0, true),
+        DragOutcome::SnapTo(2)
+    );
+    assert_eq!(
+        resolve_snap_point(&offsets, 0, 610.0, fast, 800.0, true),
+        DragOutcome::Close
+    );
+    assert_eq!(
+        resolve_snap_point(&offsets, 0, 610.0, fast, 800.0, false),
+        DragOutcome::SnapTo(0)
+    );
+}
+
+#[test]
+fn background_follows_the_open_fraction() {
+    assert_close(open_fraction(0.0, 400.0), 1.0);
+    assert_close(open_fraction(100.0, 400.0), 0.75);
+    assert_close(open_fraction(500.0, 400.0), 0.0);
+
+    assert_close(background_scale(400.0, 0.0), 1.0);
+    assert_close(
+        background_scale(400.0, 1.0),
+        (400.0 - BACKGROUND_OFFSET) / 400.0,
+    );
+    assert_close(background_translate(0.5), BACKGROUND_OFFSET / 2.0);
+}
@@ -8,8 +8,11 @@ use radix_leptos_menu::primitive as MenuPrimitive;
 use radix_leptos_use_controllable_state::{use_controllable_state, UseControllableStateParams};
 use radix_leptos_context::create_context;
 use leptos_maybe_callback::MaybeCallback;
-// pub use radix_leptos_checkbox::CheckedState;
+pub use radix_leptos_checkbox::CheckedState;
 pub use radix_leptos_direction::Direction;
+pub use radix_leptos_menu::{
+    GraceArea, HoverDelay, HoverIntent, HoverIntentProvider, HoverIntentStrategy, SafeTriangle,
+};
 pub use leptos_node_ref::AnyNodeRef;
 use leptos::context::Provider;
 
@@ -46,6 +49,7 @@ pub fn DropdownMenu(
     #[prop(optional, into)] default_open: MaybeProp<bool>,
     #[prop(optional, into)] on_open_change: MaybeCallback<bool>,
     #[prop(optional, into)] modal: MaybeProp<bool>,
+    #[prop(optional, into)] hover_intent: Option<HoverIntentStrategy>,
 ) -> impl IntoView {
     let (open, set_open) = use_controllable_state(UseControllableStateParams {
         prop: open,
@@ -77,6 +81,7 @@ pub fn DropdownMenu(
                 on_open_change=set_open
                 dir=dir
                 modal=modal
+                hover_intent=hover_intent
             />
         </DropdownMenuProvider>
     }
@@ -235,7 +240,7 @@ pub use MenuPrimitive::Item as DropdownMenuItem;
  * DropdownMenuCheckboxItem
  * -----------------------------------------------------------------------------------------------*/
 
-// pub use MenuPrimitive::CheckboxItem as DropdownMenuCheckboxItem;
+pub use MenuPrimitive::CheckboxItem as DropdownMenuCheckboxItem;
 
 /* -------------------------------------------------------------------------------------------------
  * DropdownMenuRadioGroup
@@ -247,7 +252,7 @@ pub use MenuPrimitive::RadioGroup as DropdownMenuRadioGroup;
  * DropdownMenuRadioItem
  * -----------------------------------------------------------------------------------------------*/
 
-// pub use MenuPrimitive::RadioItem as DropdownMenuRadioItem;
+pub use MenuPrimitive::RadioItem as DropdownMenuRadioItem;
 
 /* -------------------------------------------------------------------------------------------------
  * DropdownMenuItemIndicator
@@ -311,7 +316,7 @@ pub mod primitive {
     pub use super::*;
     pub use DropdownMenu as Root;
     pub use DropdownMenuArrow as Arrow;
-    // pub use DropdownMenuCheckboxItem as CheckboxItem;
+    pub use DropdownMenuCheckboxItem as CheckboxItem;
     pub use DropdownMenuContent as Content;
     pub use DropdownMenuGroup as Group;
     pub use DropdownMenuItem as Item;
@@ -319,7 +324,7 @@ pub mod primitive {
     pub use DropdownMenuLabel as Label;
     pub use DropdownMenuPortal as Portal;
     pub use DropdownMenuRadioGroup as RadioGroup;
-    // pub use DropdownMenuRadioItem as RadioItem;
+    pub use DropdownMenuRadioItem as RadioItem;
     pub use DropdownMenuSeparator as Separator;
     pub use DropdownMenuSub as Sub;
     pub use DropdownMenuSubContent as SubContent;
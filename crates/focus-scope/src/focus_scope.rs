@@ -0,0 +1,512 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use leptos::{ev::Event, ev::KeyboardEvent, html, prelude::*};
+use leptos_node_ref::prelude::*;
+use radix_leptos_compose_refs::use_composed_refs;
+use radix_leptos_primitive::{compose_callbacks, Primitive};
+use send_wrapper::SendWrapper;
+use web_sys::{
+    wasm_bindgen::{closure::Closure, JsCast},
+    CustomEvent, CustomEventInit, FocusOptions, MutationObserver, MutationObserverInit,
+    MutationRecord,
+};
+
+const AUTOFOCUS_ON_MOUNT: &str = "focusScope.autoFocusOnMount";
+const AUTOFOCUS_ON_UNMOUNT: &str = "focusScope.autoFocusOnUnmount";
+
+/* -------------------------------------------------------------------------------------------------
+ * FocusScope
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+#[allow(non_snake_case)]
+pub fn FocusScope<C: IntoView + 'static>(
+    /// When `true`, tabbing from last item will focus first tabbable
+    /// and shift+tab from first item will focus last tabbable.
+    #[prop(into, optional)]
+    r#loop: MaybeProp<bool>,
+    /// When `true`, focus cannot escape the focus scope via keyboard,
+    /// pointer, or a programmatic focus.
+    #[prop(into, optional)]
+    trapped: MaybeProp<bool>,
+    /// Event handler called when auto-focusing on mount.
+    /// Can be prevented.
+    #[prop(into, optional)]
+    on_mount_auto_focus: Option<Callback<Event>>,
+    /// Event handler called when auto-focusing on unmount.
+    /// Can be prevented.
+    #[prop(into, optional)]
+    on_unmount_auto_focus: Option<Callback<Event>>,
+    #[prop(into, optional)] on_key_down: Option<Callback<KeyboardEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(optional)] node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let r#loop = Signal::derive(move || r#loop.get().unwrap_or(false));
+    let trapped = Signal::derive(move || trapped.get().unwrap_or(false));
+
+    let container_ref = AnyNodeRef::new();
+    let composed_refs = use_composed_refs([node_ref, container_ref]);
+    let last_focused_element: Rc<RefCell<Option<web_sys::HtmlElement>>> =
+        Rc::new(RefCell::new(None));
+    let focus_scope = FocusScopeApi::new();
+
+    // Takes care of trapping focus if focus is moved outside programmatically for example.
+    Effect::new({
+        let focus_scope = focus_scope.clone();
+
+        move |_| {
+            if !trapped.get() {
+                return;
+            }
+            let Some(container) = container_ref.get() else {
+                return;
+            };
+            let container: web_sys::HtmlElement = container.unchecked_into();
+
+            let handle_focus_in: Closure<dyn Fn(web_sys::FocusEvent)> = Closure::new({
+                let focus_scope = focus_scope.clone();
+                let container = container.clone();
+                let last_focused_element = last_focused_element.clone();
+
+                move |event: web_sys::FocusEvent| {
+                    if focus_scope.is_paused() {
+                        return;
+                    }
+                    let target = event
+                        .target()
+                        .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok());
+                    if target
+                        .as_ref()
+                        .is_some_and(|target| container.contains(Some(target.unchecked_ref())))
+                    {
+                        *last_focused_element.borrow_mut() = target;
+                    } else {
+                        focus(last_focused_element.borrow().clone(), true);
+                    }
+                }
+            });
+
+            let handle_focus_out: Closure<dyn Fn(web_sys::FocusEvent)> = Closure::new({
+                let focus_scope = focus_scope.clone();
+                let container = container.clone();
+                let last_focused_element = last_focused_element.clone();
+
+                move |event: web_sys::FocusEvent| {
+                    if focus_scope.is_paused() {
+                        return;
+                    }
+
+                    // A `focusout` event with a `None` `related_target` will happen in at least two cases:
+                    //
+                    // 1. When the user switches app/tabs/windows/the browser itself loses focus.
+                    // 2. In Google Chrome, when the focused element is removed from the DOM.
+                    //
+                    // We let the browser do its thing here because:
+                    //
+                    // 1. The browser already keeps a memory of what's focused for when the page gets refocused.
+                    // 2. In Google Chrome, if we try to focus the deleted focused element (as per below), it
+                    //    throws the CPU to 100%, so we avoid doing anything for this reason here too.
+                    let Some(related_target) = event
+                        .related_target()
+                        .and_then(|related_target| related_target.dyn_into::<web_sys::Node>().ok())
+                    else {
+                        return;
+                    };
+
+                    // If the focus has moved to an actual legitimate element (`related_target` is not `None`)
+                    // that is outside the container, we move focus to the last valid focused element inside.
+                    if !container.contains(Some(&related_target)) {
+                        focus(last_focused_element.borrow().clone(), true);
+                    }
+                }
+            });
+
+            // When the focused element gets removed from the DOM, browsers move focus
+            // back to the document.body. In this case, we move focus to the container
+            // to keep focus trapped correctly.
+            let handle_mutations: Closure<dyn Fn(js_sys::Array)> = Closure::new({
+                let container = container.clone();
+
+                move |mutations: js_sys::Array| {
+                    let focused_element = document().active_element();
+                    if focused_element != document().body().map(|body| body.unchecked_into()) {
+                        return;
+                    }
+                    let has_removed_nodes = mutations.iter().any(|mutation| {
+                        mutation
+                            .unchecked_into::<MutationRecord>()
+                            .removed_nodes()
+                            .length()
+                            > 0
+                    });
+                    if has_removed_nodes {
+                        focus(Some(container.clone()), false);
+                    }
+                }
+            });
+
+            document()
+                .add_event_listener_with_callback("focusin", handle_focus_in.as_ref().unchecked_ref())
+                .expect("Focus in event listener should be added.");
+            document()
+                .add_event_listener_with_callback("focusout", handle_focus_out.as_ref().unchecked_ref())
+                .expect("Focus out event listener should be added.");
+
+            let mutation_observer = MutationObserver::new(handle_mutations.as_ref().unchecked_ref())
+                .expect("Mutation observer should be created.");
+            let options = MutationObserverInit::new();
+            options.set_child_list(true);
+            options.set_subtree(true);
+            mutation_observer
+                .observe_with_options(&container, &options)
+                .expect("Mutation observer should observe.");
+
+            let listeners = SendWrapper::new((
+                handle_focus_in,
+                handle_focus_out,
+                handle_mutations,
+                mutation_observer,
+            ));
+            on_cleanup(move || {
+                let (handle_focus_in, handle_focus_out, _handle_mutations, mutation_observer) =
+                    listeners.take();
+
+                document()
+                    .remove_event_listener_with_callback(
+                        "focusin",
+                        handle_focus_in.as_ref().unchecked_ref(),
+                    )
+                    .expect("Focus in event listener should be removed.");
+                document()
+                    .remove_event_listener_with_callback(
+                        "focusout",
+                        handle_focus_out.as_ref().unchecked_ref(),
+                    )
+                    .expect("Focus out event listener should be removed.");
+                mutation_observer.disconnect();
+            });
+        }
+    });
+
+    Effect::new({
+        let focus_scope = focus_scope.clone();
+
+        move |_| {
+            let Some(container) = container_ref.get() else {
+                return;
+            };
+            let container: web_sys::HtmlElement = container.unchecked_into();
+
+            FOCUS_SCOPES_STACK.with_borrow_mut(|stack| stack.add(focus_scope.clone()));
+            let previously_focused_element = document().active_element();
+            let has_focused_candidate = previously_focused_element
+                .as_ref()
+                .is_some_and(|element| container.contains(Some(element.unchecked_ref())));
+
+            if !has_focused_candidate {
+                let mount_event = create_auto_focus_event(AUTOFOCUS_ON_MOUNT);
+                if let Some(on_mount_auto_focus) = on_mount_auto_focus {
+                    on_mount_auto_focus.run(mount_event.clone().into());
+                }
+                if !mount_event.default_prevented() {
+                    focus_first(remove_links(get_tabbable_candidates(&container)), true);
+                    if document().active_element() == previously_focused_element {
+                        focus(Some(container.clone()), false);
+                    }
+                }
+            }
+
+            let previously_focused_element = SendWrapper::new(previously_focused_element);
+            let focus_scope = focus_scope.clone();
+            on_cleanup(move || {
+                let previously_focused_element = previously_focused_element.take();
+
+                let unmount_event = create_auto_focus_event(AUTOFOCUS_ON_UNMOUNT);
+                if let Some(on_unmount_auto_focus) = on_unmount_auto_focus {
+                    on_unmount_auto_focus.run(unmount_event.clone().into());
+                }
+                if !unmount_event.default_prevented() {
+                    focus(
+                        previously_focused_element
+                            .and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok())
+                            .or_else(|| document().body()),
+                        true,
+                    );
+                }
+
+                FOCUS_SCOPES_STACK.with_borrow_mut(|stack| stack.remove(&focus_scope));
+            });
+        }
+    });
+
+    let handle_key_down = Callback::new(move |event: KeyboardEvent| {
+        if !r#loop.get_untracked() && !trapped.get_untracked() {
+            return;
+        }
+        if focus_scope.is_paused() {
+            return;
+        }
+
+        let is_tab_key =
+            event.key() == "Tab" && !event.alt_key() && !event.ctrl_key() && !event.meta_key();
+        let focused_element = document().active_element();
+
+        if let (true, Some(focused_element)) = (is_tab_key, focused_element) {
+            let container = event
+                .current_target()
+                .map(|current_target| current_target.unchecked_into::<web_sys::HtmlElement>())
+                .expect("Event should have current target.");
+            let (first, last) = get_tabbable_edges(&container);
+
+            match (first, last) {
+                (Some(first), Some(last)) => {
+                    if !event.shift_key() && focused_element == *last {
+                        event.prevent_default();
+                        if r#loop.get_untracked() {
+                            focus(Some(first), true);
+                        }
+                    } else if event.shift_key() && focused_element == *first {
+                        event.prevent_default();
+                        if r#loop.get_untracked() {
+                            focus(Some(last), true);
+                        }
+                    }
+                }
+                _ => {
+                    if focused_element == *container {
+                        event.prevent_default();
+                    }
+                }
+            }
+        }
+    });
+
+    view! {
+        <Primitive
+            element=html::div
+            as_child=as_child
+            node_ref=composed_refs
+            attr:tabindex="-1"
+            on:keydown=compose_callbacks(on_key_down, Some(handle_key_down), None)
+        >
+            {children.with_value(|children| children())}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Utils
+ * -----------------------------------------------------------------------------------------------*/
+
+fn create_auto_focus_event(name: &str) -> CustomEvent {
+    let init = CustomEventInit::new();
+    init.set_bubbles(false);
+    init.set_cancelable(true);
+    CustomEvent::new_with_event_init_dict(name, &init).expect("Auto focus event should be created.")
+}
+
+/// Attempts focusing the first element in a list of candidates.
+/// Stops when focus has actually moved.
+fn focus_first(candidates: Vec<web_sys::HtmlElement>, select: bool) -> bool {
+    let previously_focused_element = document().active_element();
+    for candidate in candidates {
+        focus(Some(candidate), select);
+        if document().active_element() != previously_focused_element {
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns the first and last tabbable elements inside a container.
+fn get_tabbable_edges(
+    container: &web_sys::HtmlElement,
+) -> (Option<web_sys::HtmlElement>, Option<web_sys::HtmlElement>) {
+    let candidates = get_tabbable_candidates(container);
+    let first = find_visible(candidates.iter(), container);
+    let last = find_visible(candidates.iter().rev(), container);
+    (first, last)
+}
+
+/// Returns a list of potential tabbable candidates.
+///
+/// NOTE: This is only a close approximation. For example it doesn't take into account cases like when
+/// elements are not visible. This cannot be worked out easily by just reading a property, but rather
+/// necessitate runtime knowledge (computed styles, etc). We deal with these cases separately.
+///
+/// See: https://developer.mozilla.org/en-US/docs/Web/API/TreeWalker
+/// Credit: https://github.com/discord/focus-layers/blob/master/src/util/wrapFocus.tsx#L1
+fn get_tabbable_candidates(container: &web_sys::HtmlElement) -> Vec<web_sys::HtmlElement> {
+    let nodes = container
+        .query_selector_all("*")
+        .expect("Node should be queried.");
+
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        .filter(|element| {
+            let is_hidden_input = element
+                .dyn_ref::<web_sys::HtmlInputElement>()
+                .is_some_and(|input| input.type_() == "hidden");
+            if element.has_attribute("disabled") || element.hidden() || is_hidden_input {
+                return false;
+            }
+            // `.tab_index()` is not the same as the `tabindex` attribute. It works on the
+            // runtime's understanding of tabbability, so this automatically accounts
+            // for any kind of element that could be tabbed to.
+            element.tab_index() >= 0
+        })
+        .collect()
+}
+
+/// Returns the first visible element in a list.
+///
+/// NOTE: Only checks visibility up to the `container`.
+fn find_visible<'a>(
+    elements: impl Iterator<Item = &'a web_sys::HtmlElement>,
+    container: &web_sys::HtmlElement,
+) -> Option<web_sys::HtmlElement> {
+    elements
+        .find(|element| !is_hidden(element, Some(container)))
+        .cloned()
+}
+
+fn is_hidden(node: &web_sys::HtmlElement, up_to: Option<&web_sys::HtmlElement>) -> bool {
+    let computed_style = |element: &web_sys::Element, property: &str| {
+        window()
+            .get_computed_style(element)
+            .ok()
+            .flatten()
+            .and_then(|style| style.get_property_value(property).ok())
+            .unwrap_or_default()
+    };
+
+    if computed_style(node.unchecked_ref(), "visibility") == "hidden" {
+        return true;
+    }
+
+    let mut node: Option<web_sys::Element> = Some(node.clone().into());
+    while let Some(element) = node {
+        // We stop at `up_to` (excluding it).
+        if up_to.is_some_and(|up_to| **up_to == element) {
+            return false;
+        }
+        if computed_style(&element, "display") == "none" {
+            return true;
+        }
+        node = element.parent_element();
+    }
+    false
+}
+
+fn is_selectable_input(element: &web_sys::HtmlElement) -> Option<&web_sys::HtmlInputElement> {
+    element.dyn_ref::<web_sys::HtmlInputElement>()
+}
+
+fn focus(element: Option<web_sys::HtmlElement>, select: bool) {
+    let Some(element) = element else {
+        return;
+    };
+
+    let previously_focused_element = document().active_element();
+    // NOTE: We prevent scrolling on focus, to minimize jarring transitions for users.
+    let options = FocusOptions::new();
+    options.set_prevent_scroll(true);
+    element
+        .focus_with_options(&options)
+        .expect("Element should be focused.");
+
+    // Only select if its not the same element, it supports selection and we need to select.
+    if previously_focused_element.as_ref() != element.dyn_ref::<web_sys::Element>() && select {
+        if let Some(input) = is_selectable_input(&element) {
+            input.select();
+        }
+    }
+}
+
+fn remove_links(items: Vec<web_sys::HtmlElement>) -> Vec<web_sys::HtmlElement> {
+    items
+        .into_iter()
+        .filter(|item| item.tag_name() != "A")
+        .collect()
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * FocusScope stack
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone)]
+struct FocusScopeApi {
+    paused: Arc<AtomicBool>,
+}
+
+impl FocusScopeApi {
+    fn new() -> Self {
+        Self {
+            paused: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    fn pause(&self) {
+        self.paused.store(true, Ordering::Relaxed);
+    }
+
+    fn resume(&self) {
+        self.paused.store(false, Ordering::Relaxed);
+    }
+}
+
+impl PartialEq for FocusScopeApi {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.paused, &other.paused)
+    }
+}
+
+struct FocusScopesStack(Vec<FocusScopeApi>);
+
+impl FocusScopesStack {
+    fn add(&mut self, focus_scope: FocusScopeApi) {
+        // Pause the currently active focus scope (at the top of the stack).
+        if let Some(active_focus_scope) = self.0.first() {
+            if *active_focus_scope != focus_scope {
+                active_focus_scope.pause();
+            }
+        }
+        // Remove in case it already exists (because we'll re-add it at the top of the stack).
+        self.remove_without_resume(&focus_scope);
+        self.0.insert(0, focus_scope);
+    }
+
+    fn remove(&mut self, focus_scope: &FocusScopeApi) {
+        self.remove_without_resume(focus_scope);
+        if let Some(active_focus_scope) = self.0.first() {
+            active_focus_scope.resume();
+        }
+    }
+
+    fn remove_without_resume(&mut self, focus_scope: &FocusScopeApi) {
+        self.0.retain(|item| item != focus_scope);
+    }
+}
+
+thread_local! {
+    static FOCUS_SCOPES_STACK: RefCell<FocusScopesStack> = const { RefCell::new(FocusScopesStack(Vec::new())) };
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub mod primitive {
+    pub use super::*;
+    pub use FocusScope as Root;
+}
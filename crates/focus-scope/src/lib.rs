@@ -0,0 +1,9 @@
+//! Leptos port of [Radix Focus Scope](https://www.radix-ui.com/primitives).
+//!
+//! This is an internal utility, not intended for public usage.
+//!
+//! See [`@radix-ui/react-focus-scope`](https://www.npmjs.com/package/@radix-ui/react-focus-scope) for the original package.
+
+mod focus_scope;
+
+pub use focus_scope::*;
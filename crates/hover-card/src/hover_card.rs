@@ -0,0 +1,544 @@
+use std::time::Duration;
+
+use leptos::wasm_bindgen::JsCast;
+use leptos::{context::Provider, ev, html, prelude::*};
+use leptos_node_ref::AnyNodeRef;
+use radix_leptos_compose_refs::use_composed_refs;
+use radix_leptos_context::create_context;
+use radix_leptos_dismissable_layer::{
+    DismissableLayer, FocusOutsideEvent, InteractOutsideEvent, PointerDownOutsideEvent,
+};
+pub use radix_leptos_popper::{Align, Padding, Side};
+use radix_leptos_popper::{Popper, PopperAnchor, PopperArrow, PopperContent};
+use radix_leptos_portal::Portal as PortalPrimitive;
+use radix_leptos_presence::Presence;
+use radix_leptos_primitive::{compose_callbacks, Primitive};
+use radix_leptos_use_controllable_state::{use_controllable_state, UseControllableStateParams};
+
+const DEFAULT_OPEN_DELAY: f64 = 700.0;
+const DEFAULT_CLOSE_DELAY: f64 = 300.0;
+
+/* -------------------------------------------------------------------------------------------------
+ * HoverCard
+ * -----------------------------------------------------------------------------------------------*/
+
+const HOVER_CARD_NAME: &str = "HoverCard";
+
+#[derive(Clone, Copy)]
+struct HoverCardContextValue {
+    open: Signal<bool>,
+    on_open: Callback<()>,
+    on_close: Callback<()>,
+    on_dismiss: Callback<()>,
+    /// Text selected inside the content keeps the card open after the pointer leaves.
+    has_selection: StoredValue<bool>,
+    is_pointer_down_on_content: StoredValue<bool>,
+}
+
+create_context!(
+    context_type: HoverCardContextValue,
+    provider: HoverCardProvider,
+    hook: use_hover_card_context,
+    root: HOVER_CARD_NAME
+);
+
+#[component]
+#[allow(non_snake_case)]
+pub fn HoverCard(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] open: MaybeProp<bool>,
+    #[prop(optional, into)] default_open: MaybeProp<bool>,
+    #[prop(optional, into)] on_open_change: Option<Callback<bool>>,
+    /// Milliseconds from when the pointer enters the trigger until the card opens. Defaults to `700`.
+    #[prop(optional, into)]
+    open_delay: MaybeProp<f64>,
+    /// Milliseconds from when the pointer leaves the trigger or content until the card closes.
+    /// Defaults to `300`.
+    #[prop(optional, into)]
+    close_delay: MaybeProp<f64>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let open_timer = StoredValue::new(None::<TimeoutHandle>);
+    let close_timer = StoredValue::new(None::<TimeoutHandle>);
+    let has_selection = StoredValue::new(false);
+    let is_pointer_down_on_content = StoredValue::new(false);
+
+    let (open, set_open) = use_controllable_state(UseControllableStateParams {
+        prop: open,
+        default_prop: default_open,
+        on_change: on_open_change,
+    });
+
+    let clear_timer = move |timer: StoredValue<Option<TimeoutHandle>>| {
+        if let Some(handle) = timer.try_get_value().flatten() {
+            handle.clear();
+        }
+        timer.try_set_value(None);
+    };
+    on_cleanup(move || {
+        clear_timer(open_timer);
+        clear_timer(close_timer);
+    });
+
+    let handle_open = move || {
+        clear_timer(close_timer);
+        open_timer.set_value(
+            set_timeout_with_handle(
+                move || set_open.run(true),
+                Duration::from_millis(
+                    open_delay.get_untracked().unwrap_or(DEFAULT_OPEN_DELAY) as u64
+                ),
+            )
+            .ok(),
+        );
+    };
+
+    let handle_close = move || {
+        clear_timer(open_timer);
+        if !has_selection.get_value() && !is_pointer_down_on_content.get_value() {
+            close_timer.set_value(
+                set_timeout_with_handle(
+                    move || set_open.run(false),
+                    Duration::from_millis(
+                        close_delay.get_untracked().unwrap_or(DEFAULT_CLOSE_DELAY) as u64,
+                    ),
+                )
+                .ok(),
+            );
+        }
+    };
+
+    let handle_dismiss = move || {
+        clear_timer(open_timer);
+        clear_timer(close_timer);
+        set_open.run(false);
+    };
+
+    let context_value = HoverCardContextValue {
+        open,
+        on_open: Callback::new(move |_| handle_open()),
+        on_close: Callback::new(move |_| handle_close()),
+        on_dismiss: Callback::new(move |_| handle_dismiss()),
+        has_selection,
+        is_pointer_down_on_content,
+    };
+
+    view! {
+        <HoverCardProvider value=context_value>
+            <Popper>{children.with_value(|children| children())}</Popper>
+        </HoverCardProvider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * HoverCardTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+const TRIGGER_NAME: &str = "HoverCardTrigger";
+
+#[component]
+#[allow(non_snake_case)]
+pub fn HoverCardTrigger(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] on_pointer_enter: Option<Callback<ev::PointerEvent>>,
+    #[prop(optional, into)] on_pointer_leave: Option<Callback<ev::PointerEvent>>,
+    #[prop(optional, into)] on_focus: Option<Callback<ev::FocusEvent>>,
+    #[prop(optional, into)] on_blur: Option<Callback<ev::FocusEvent>>,
+    #[prop(optional, into)] on_touch_start: Option<Callback<ev::TouchEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_hover_card_context(TRIGGER_NAME);
+
+    view! {
+        <PopperAnchor as_child=true>
+            <Primitive
+                element=html::a
+                as_child=as_child
+                node_ref=node_ref
+                attr:data-state=move || get_state(context.open.get())
+                on:pointerenter=compose_callbacks(
+                    on_pointer_enter,
+                    Some(Callback::new(exclude_touch(move || context.on_open.run(())))),
+                    None,
+                )
+                on:pointerleave=compose_callbacks(
+                    on_pointer_leave,
+                    Some(Callback::new(exclude_touch(move || context.on_close.run(())))),
+                    None,
+                )
+                on:focus=compose_callbacks(
+                    on_focus,
+                    Some(Callback::new(move |_: ev::FocusEvent| context.on_open.run(()))),
+                    None,
+                )
+                on:blur=compose_callbacks(
+                    on_blur,
+                    Some(Callback::new(move |_: ev::FocusEvent| context.on_close.run(()))),
+                    None,
+                )
+                // Prevent focus event on touch devices.
+                on:touchstart=compose_callbacks(
+                    on_touch_start,
+                    Some(Callback::new(move |event: ev::TouchEvent| event.prevent_default())),
+                    None,
+                )
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        </PopperAnchor>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * HoverCardPortal
+ * -----------------------------------------------------------------------------------------------*/
+
+const PORTAL_NAME: &str = "HoverCardPortal";
+
+#[derive(Clone, Copy)]
+struct HoverCardPortalContextValue {
+    force_mount: MaybeProp<bool>,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn HoverCardPortal(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] container: AnyNodeRef,
+    /// Keep the portal mounted while closed, e.g. to control animations from outside.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_hover_card_context(PORTAL_NAME);
+    let container = StoredValue::new(container);
+
+    view! {
+        <Provider value=HoverCardPortalContextValue { force_mount }>
+            <Presence present=Signal::derive(move || {
+                force_mount.get().unwrap_or(false) || context.open.get()
+            })>
+                <PortalPrimitive as_child=true mount=container.with_value(|container| container.get())>
+                    {children.with_value(|children| children())}
+                </PortalPrimitive>
+            </Presence>
+        </Provider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * HoverCardContent
+ * -----------------------------------------------------------------------------------------------*/
+
+const CONTENT_NAME: &str = "HoverCardContent";
+
+/// Positioning props forwarded to `PopperContent`.
+#[derive(Clone, Copy, Default)]
+struct HoverCardContentPlacement {
+    side: MaybeProp<Side>,
+    side_offset: MaybeProp<f64>,
+    align: MaybeProp<Align>,
+    align_offset: MaybeProp<f64>,
+    avoid_collisions: MaybeProp<bool>,
+    collision_padding: MaybeProp<Padding>,
+    hide_when_detached: MaybeProp<bool>,
+}
+
+/// Event handlers forwarded to `DismissableLayer`.
+#[derive(Clone, Copy, Default)]
+struct HoverCardContentHandlers {
+    on_escape_key_down: Option<Callback<ev::KeyboardEvent>>,
+    on_pointer_down_outside: Option<Callback<PointerDownOutsideEvent>>,
+    on_focus_outside: Option<Callback<FocusOutsideEvent>>,
+    on_interact_outside: Option<Callback<InteractOutsideEvent>>,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn HoverCardContent(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// Keep the content mounted while closed, e.g. to control animations from outside.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+    /// The preferred side of the trigger to render against. Defaults to `Bottom`.
+    #[prop(optional, into)]
+    side: MaybeProp<Side>,
+    #[prop(optional, into)] side_offset: MaybeProp<f64>,
+    #[prop(optional, into)] align: MaybeProp<Align>,
+    #[prop(optional, into)] align_offset: MaybeProp<f64>,
+    #[prop(optional, into)] avoid_collisions: MaybeProp<bool>,
+    #[prop(optional, into)] collision_padding: MaybeProp<Padding>,
+    #[prop(optional, into)] hide_when_detached: MaybeProp<bool>,
+    #[prop(optional, into)] on_escape_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(optional, into)] on_pointer_down_outside: Option<Callback<PointerDownOutsideEvent>>,
+    #[prop(optional, into)] on_focus_outside: Option<Callback<FocusOutsideEvent>>,
+    #[prop(optional, into)] on_interact_outside: Option<Callback<InteractOutsideEvent>>,
+    #[prop(optional, into)] on_pointer_enter: Option<Callback<ev::PointerEvent>>,
+    #[prop(optional, into)] on_pointer_leave: Option<Callback<ev::PointerEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_hover_card_context(CONTENT_NAME);
+    let portal_context = use_context::<HoverCardPortalContextValue>();
+    let force_mount = Signal::derive(move || {
+        force_mount
+            .get()
+            .or_else(|| portal_context.and_then(|portal_context| portal_context.force_mount.get()))
+            .unwrap_or(false)
+    });
+    let placement = HoverCardContentPlacement {
+        side,
+        side_offset,
+        align,
+        align_offset,
+        avoid_collisions,
+        collision_padding,
+        hide_when_detached,
+    };
+    let handlers = HoverCardContentHandlers {
+        on_escape_key_down,
+        on_pointer_down_outside,
+        on_focus_outside,
+        on_interact_outside,
+    };
+
+    view! {
+        <Presence present=Signal::derive(move || force_mount.get() || context.open.get())>
+            <HoverCardContentImpl
+                placement=placement
+                handlers=handlers
+                node_ref=node_ref
+                as_child=as_child
+                on:pointerenter=compose_callbacks(
+                    on_pointer_enter,
+                    Some(Callback::new(exclude_touch(move || context.on_open.run(())))),
+                    None,
+                )
+                on:pointerleave=compose_callbacks(
+                    on_pointer_leave,
+                    Some(Callback::new(exclude_touch(move || context.on_close.run(())))),
+                    None,
+                )
+            >
+                {children.with_value(|children| children())}
+            </HoverCardContentImpl>
+        </Presence>
+    }
+}
+
+#[component]
+#[allow(non_snake_case)]
+fn HoverCardContentImpl<C: IntoView + 'static>(
+    placement: HoverCardContentPlacement,
+    handlers: HoverCardContentHandlers,
+    node_ref: AnyNodeRef,
+    as_child: MaybeProp<bool>,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_hover_card_context(CONTENT_NAME);
+    let content_ref = AnyNodeRef::new();
+    let composed_refs = use_composed_refs((node_ref, content_ref));
+    let contain_selection = RwSignal::new(false);
+
+    // Restrict text selection to the content while a selection started inside it.
+    Effect::new(move |_| {
+        if !contain_selection.get() {
+            return;
+        }
+        let Some(body) = document().body() else {
+            return;
+        };
+        let style = body.style();
+        let original_user_select = style.get_property_value("user-select").unwrap_or_default();
+        let original_webkit_user_select = style
+            .get_property_value("-webkit-user-select")
+            .unwrap_or_default();
+        let _ = style.set_property("user-select", "none");
+        let _ = style.set_property("-webkit-user-select", "none");
+
+        on_cleanup(move || {
+            if let Some(body) = document().body() {
+                let style = body.style();
+                let _ = style.set_property("user-select", &original_user_select);
+                let _ = style.set_property("-webkit-user-select", &original_webkit_user_select);
+            }
+        });
+    });
+
+    let pointer_up = window_event_listener(ev::pointerup, move |_| {
+        contain_selection.set(false);
+        context.is_pointer_down_on_content.set_value(false);
+
+        // Delay a frame to ensure we always access the latest selection.
+        set_timeout(
+            move || {
+                let has_selection = document()
+                    .get_selection()
+                    .ok()
+                    .flatten()
+                    .is_some_and(|selection| String::from(selection.to_string()) != "");
+                if has_selection {
+                    context.has_selection.try_set_value(true);
+                }
+            },
+            Duration::ZERO,
+        );
+    });
+
+    on_cleanup(move || {
+        pointer_up.remove();
+        context.has_selection.set_value(false);
+        context.is_pointer_down_on_content.set_value(false);
+    });
+
+    // The content isn't part of the tab order, the trigger is the only way in with a keyboard.
+    Effect::new(move |_| {
+        let Some(content) = content_ref.get() else {
+            return;
+        };
+        for element in get_tabbable_nodes(&content) {
+            element.set_tab_index(-1);
+        }
+    });
+
+    view! {
+        <DismissableLayer
+            as_child=true
+            disable_outside_pointer_events=false
+            on_escape_key_down=handlers.on_escape_key_down
+            on_pointer_down_outside=handlers.on_pointer_down_outside
+            on_focus_outside=Callback::new(compose_callbacks(
+                handlers.on_focus_outside,
+                Some(Callback::new(move |event: FocusOutsideEvent| event.prevent_default())),
+                None,
+            ))
+            on_interact_outside=handlers.on_interact_outside
+            on_dismiss=context.on_dismiss
+        >
+            <PopperContent
+                side=placement.side
+                side_offset=placement.side_offset
+                align=placement.align
+                align_offset=placement.align_offset
+                avoid_collisions=placement.avoid_collisions
+                collision_padding=placement.collision_padding
+                hide_when_detached=placement.hide_when_detached
+                as_child=as_child
+                node_ref=composed_refs
+                attr:data-state=move || get_state(context.open.get())
+                on:pointerdown=move |event: ev::PointerEvent| {
+                    // Contain selection to current layer.
+                    let contains_target = event
+                        .current_target()
+                        .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+                        .is_some_and(|current_target| {
+                            current_target.contains(
+                                event
+                                    .target()
+                                    .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+                                    .as_ref(),
+                            )
+                        });
+                    if contains_target {
+                        contain_selection.set(true);
+                    }
+                    context.has_selection.set_value(false);
+                    context.is_pointer_down_on_content.set_value(true);
+                }
+                style:user-select=move || if contain_selection.get() { "text" } else { "" }
+                style:-webkit-user-select=move || if contain_selection.get() { "text" } else { "" }
+                style:--radix-hover-card-content-transform-origin="var(--radix-popper-transform-origin)"
+                style:--radix-hover-card-content-available-width="var(--radix-popper-available-width)"
+                style:--radix-hover-card-content-available-height="var(--radix-popper-available-height)"
+                style:--radix-hover-card-trigger-width="var(--radix-popper-anchor-width)"
+                style:--radix-hover-card-trigger-height="var(--radix-popper-anchor-height)"
+            >
+                {children.with_value(|children| children())}
+            </PopperContent>
+        </DismissableLayer>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * HoverCardArrow
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+#[allow(non_snake_case)]
+pub fn HoverCardArrow(
+    #[prop(optional, into)] width: MaybeProp<f64>,
+    #[prop(optional, into)] height: MaybeProp<f64>,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    view! {
+        <PopperArrow width=width height=height as_child=as_child node_ref=node_ref>
+            {children.with_value(|children| children.as_ref().map(|children| children()))}
+        </PopperArrow>
+    }
+}
+
+/* ---------------------------------------------------------------------------------------------- */
+
+fn get_state(open: bool) -> &'static str {
+    match open {
+        true => "open",
+        false => "closed",
+    }
+}
+
+/// Only run `handler` for mouse and pen pointers, so tapping a trigger on a touch device doesn't
+/// open the card.
+fn exclude_touch(
+    handler: impl Fn() + Send + Sync + 'static,
+) -> impl Fn(ev::PointerEvent) + Send + Sync + 'static {
+    move |event: ev::PointerEvent| {
+        if event.pointer_type() != "touch" {
+            handler();
+        }
+    }
+}
+
+/// Returns a list of potential tabbable candidates.
+///
+/// NOTE: This is only a close approximation. For example it doesn't take into account cases like
+/// when elements are not visible. This cannot be worked out easily by just reading a property, but
+/// rather necessitate runtime knowledge (computed styles, etc). We deal with these cases separately.
+fn get_tabbable_nodes(container: &web_sys::Element) -> Vec<web_sys::HtmlElement> {
+    let mut nodes = vec![];
+    let Ok(elements) = container.query_selector_all("*") else {
+        return nodes;
+    };
+    for index in 0..elements.length() {
+        let Some(element) = elements
+            .item(index)
+            .and_then(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        else {
+            continue;
+        };
+        if element.tab_index() >= 0 {
+            nodes.push(element);
+        }
+    }
+    nodes
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub mod primitive {
+    pub use super::*;
+    pub use HoverCard as Root;
+    pub use HoverCardArrow as Arrow;
+    pub use HoverCardContent as Content;
+    pub use HoverCardPortal as Portal;
+    pub use HoverCardTrigger as Trigger;
+}
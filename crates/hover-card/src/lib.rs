@@ -0,0 +1,9 @@
+//! Leptos port of [Radix Hover Card](https://www.radix-ui.com/primitives/docs/components/hover-card).
+//!
+//! For sighted users to preview content available behind a link.
+//!
+//! See [`@radix-ui/react-hover-card`](https://www.npmjs.com/package/@radix-ui/react-hover-card) for the original package.
+
+mod hover_card;
+
+pub use hover_card::*;
@@ -0,0 +1,175 @@
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+
+use leptos::prelude::*;
+use web_sys::{
+    wasm_bindgen::{closure::Closure, JsCast},
+    AddEventListenerOptions, EventListenerOptions, KeyboardEvent, PointerEvent,
+};
+
+/// The kind of input the user interacted with most recently.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum InputModality {
+    Keyboard,
+    /// Also reported before the first input event.
+    #[default]
+    Mouse,
+    Touch,
+    Pen,
+}
+
+impl InputModality {
+    pub fn is_keyboard(self) -> bool {
+        self == InputModality::Keyboard
+    }
+
+    fn from_pointer_type(pointer_type: &str) -> Self {
+        match pointer_type {
+            "touch" => InputModality::Touch,
+            "pen" => InputModality::Pen,
+            _ => InputModality::Mouse,
+        }
+    }
+}
+
+impl Display for InputModality {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                InputModality::Keyboard => "keyboard",
+                InputModality::Mouse => "mouse",
+                InputModality::Touch => "touch",
+                InputModality::Pen => "pen",
+            }
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Global listeners
+ * -----------------------------------------------------------------------------------------------*/
+
+const POINTER_EVENTS: [&str; 2] = ["pointerdown", "pointermove"];
+
+thread_local! {
+    static MODALITY: ArcRwSignal<InputModality> = ArcRwSignal::new(InputModality::default());
+    static STATE: RefCell<State> = const { RefCell::new(State { subscribers: 0, listeners: None }) };
+}
+
+struct State {
+    subscribers: usize,
+    listeners: Option<Listeners>,
+}
+
+/// The document listeners shared by every subscriber. Dropping them removes them from the document.
+struct Listeners {
+    key_down: Closure<dyn Fn(KeyboardEvent)>,
+    pointer: Closure<dyn Fn(PointerEvent)>,
+}
+
+impl Listeners {
+    fn add() -> Self {
+        let key_down: Closure<dyn Fn(KeyboardEvent)> =
+            Closure::new(|_: KeyboardEvent| set_modality(InputModality::Keyboard));
+        let pointer: Closure<dyn Fn(PointerEvent)> = Closure::new(|event: PointerEvent| {
+            set_modality(InputModality::from_pointer_type(&event.pointer_type()));
+        });
+
+        // Capture phase ensures the modality is updated before any side effects execute
+        // in response to the key or pointer event as they might depend on this value.
+        let options = AddEventListenerOptions::new();
+        options.set_capture(true);
+
+        document()
+            .add_event_listener_with_callback_and_add_event_listener_options(
+                "keydown",
+                key_down.as_ref().unchecked_ref(),
+                &options,
+            )
+            .expect("Key down event listener should be added.");
+        for event_type in POINTER_EVENTS {
+            document()
+                .add_event_listener_with_callback_and_add_event_listener_options(
+                    event_type,
+                    pointer.as_ref().unchecked_ref(),
+                    &options,
+                )
+                .expect("Pointer event listener should be added.");
+        }
+
+        Self { key_down, pointer }
+    }
+}
+
+impl Drop for Listeners {
+    fn drop(&mut self) {
+        let options = EventListenerOptions::new();
+        options.set_capture(true);
+
+        document()
+            .remove_event_listener_with_callback_and_event_listener_options(
+                "keydown",
+                self.key_down.as_ref().unchecked_ref(),
+                &options,
+            )
+            .expect("Key down event listener should be removed.");
+        for event_type in POINTER_EVENTS {
+            document()
+                .remove_event_listener_with_callback_and_event_listener_options(
+                    event_type,
+                    self.pointer.as_ref().unchecked_ref(),
+                    &options,
+                )
+                .expect("Pointer event listener should be removed.");
+        }
+    }
+}
+
+fn set_modality(modality: InputModality) {
+    MODALITY.with(|current| {
+        // Pointer moves fire constantly, so only notify subscribers on an actual change.
+        if current.get_untracked() != modality {
+            current.set(modality);
+        }
+    });
+}
+
+fn subscribe() {
+    STATE.with_borrow_mut(|state| {
+        state.subscribers += 1;
+        if state.listeners.is_none() {
+            state.listeners = Some(Listeners::add());
+        }
+    });
+}
+
+fn unsubscribe() {
+    let listeners = STATE.with_borrow_mut(|state| {
+        state.subscribers = state.subscribers.saturating_sub(1);
+        if state.subscribers == 0 {
+            state.listeners.take()
+        } else {
+            None
+        }
+    });
+    drop(listeners);
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * use_input_modality
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Returns the current input modality.
+///
+/// All callers share one set of document listeners, which is added for the first caller and
+/// removed again once the last caller's owner is cleaned up.
+pub fn use_input_modality() -> Signal<InputModality> {
+    let modality = MODALITY.with(Clone::clone);
+
+    subscribe();
+    on_cleanup(unsubscribe);
+
+    Signal::derive(move || modality.get())
+}
@@ -0,0 +1,9 @@
+//! Shared tracking of the input modality (keyboard, mouse, touch or pen) for Leptos Radix primitives.
+//!
+//! This is an internal utility, not intended for public usage.
+//!
+//! Radix tracks keyboard usage per menu; this crate shares one set of document listeners between all consumers.
+
+mod input_modality;
+
+pub use input_modality::*;
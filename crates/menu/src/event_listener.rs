@@ -0,0 +1,63 @@
+use web_sys::{
+    wasm_bindgen::{closure::Closure, JsCast},
+    AddEventListenerOptions, EventTarget,
+};
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct ListenerOptions {
+    pub capture: bool,
+    pub once: bool,
+}
+
+/// An event listener that is removed from its target when the handle is dropped.
+///
+/// The handle owns the listener's closure, so nothing is leaked once it goes out of scope.
+pub(crate) struct EventListenerHandle {
+    target: EventTarget,
+    event_type: &'static str,
+    capture: bool,
+    closure: Closure<dyn Fn(web_sys::Event)>,
+}
+
+impl EventListenerHandle {
+    pub(crate) fn new<E: JsCast + 'static>(
+        target: &EventTarget,
+        event_type: &'static str,
+        options: ListenerOptions,
+        handler: impl Fn(E) + 'static,
+    ) -> Self {
+        let closure: Closure<dyn Fn(web_sys::Event)> =
+            Closure::new(move |event: web_sys::Event| handler(event.unchecked_into()));
+
+        let add_options = AddEventListenerOptions::new();
+        add_options.set_capture(options.capture);
+        add_options.set_once(options.once);
+        target
+            .add_event_listener_with_callback_and_add_event_listener_options(
+                event_type,
+                closure.as_ref().unchecked_ref(),
+                &add_options,
+            )
+            .expect("Event listener should be added.");
+
+        Self {
+            target: target.clone(),
+            event_type,
+            capture: options.capture,
+            closure,
+        }
+    }
+}
+
+impl Drop for EventListenerHandle {
+    fn drop(&mut self) {
+        // Removing a `once` listener that already fired is a no-op.
+        self.target
+            .remove_event_listener_with_callback_and_bool(
+                self.event_type,
+                self.closure.as_ref().unchecked_ref(),
+                self.capture,
+            )
+            .expect("Event listener should be removed.");
+    }
+}
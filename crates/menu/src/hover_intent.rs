@@ -0,0 +1,412 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+
+use leptos::{context::Provider, prelude::*};
+
+/* -------------------------------------------------------------------------------------------------
+ * Geometry
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+pub type Polygon = Vec<Point>;
+
+/// The side of its trigger a submenu is rendered on.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// Client rect of an open submenu.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rect {
+    pub left: f64,
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+}
+
+impl From<&web_sys::DomRect> for Rect {
+    fn from(rect: &web_sys::DomRect) -> Self {
+        Self {
+            left: rect.left(),
+            top: rect.top(),
+            right: rect.right(),
+            bottom: rect.bottom(),
+        }
+    }
+}
+
+/// A pointer position over menu content, with the event's `timeStamp` in milliseconds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PointerSample {
+    pub point: Point,
+    pub time_stamp: f64,
+}
+
+/// Area the pointer may cross on its way from a sub trigger to its open submenu.
+#[derive(Clone, Debug)]
+pub struct GraceIntent {
+    pub area: Polygon,
+    pub side: Side,
+}
+
+/// Determine if a point is inside of a polygon.
+pub fn is_point_in_polygon(point: Point, polygon: &[Point]) -> bool {
+    let Point { x, y } = point;
+    let mut inside = false;
+
+    if polygon.is_empty() {
+        return inside;
+    }
+
+    let mut i = 0;
+    let mut j = polygon.len() - 1;
+    while i < polygon.len() {
+        let xi = polygon[i].x;
+        let yi = polygon[i].y;
+        let xj = polygon[j].x;
+        let yj = polygon[j].y;
+
+        let intersect = ((yi > y) != (yj > y)) && (x < (xj - xi) * (y - yi) / (yj - yi) + xi);
+        if intersect {
+            inside = !inside;
+        }
+
+        j = i;
+        i += 1;
+    }
+
+    inside
+}
+
+/// Returns the convex hull of `points` in counter-clockwise order (Andrew's monotone chain).
+pub fn get_hull(points: &[Point]) -> Polygon {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+
+    if points.len() <= 1 {
+        return points;
+    }
+
+    fn cross(o: Point, a: Point, b: Point) -> f64 {
+        (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+    }
+
+    let mut lower: Vec<Point> = Vec::with_capacity(points.len());
+    for &point in &points {
+        while lower.len() >= 2 && cross(lower[lower.len() - 2], lower[lower.len() - 1], point) <= 0.0 {
+            lower.pop();
+        }
+        lower.push(point);
+    }
+
+    let mut upper: Vec<Point> = Vec::with_capacity(points.len());
+    for &point in points.iter().rev() {
+        while upper.len() >= 2 && cross(upper[upper.len() - 2], upper[upper.len() - 1], point) <= 0.0 {
+            upper.pop();
+        }
+        upper.push(point);
+    }
+
+    // The last point of each half is the first point of the other.
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
+
+/// Whether the pointer moved horizontally towards `side` between two samples.
+fn is_moving_towards(side: Side, previous: Option<PointerSample>, current: PointerSample) -> bool {
+    match previous {
+        Some(previous) => match side {
+            Side::Right => current.point.x >= previous.point.x,
+            Side::Left => current.point.x <= previous.point.x,
+        },
+        None => true,
+    }
+}
+
+/// The edges of `content` closest to and furthest from the trigger.
+fn content_edges(side: Side, content: Rect) -> (f64, f64) {
+    match side {
+        Side::Right => (content.left, content.right),
+        Side::Left => (content.right, content.left),
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * HoverIntent
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Decides when hovering a `MenuSubTrigger` opens its submenu, and when pointer movement towards an
+/// open submenu should keep it open instead of highlighting the items it passes over.
+pub trait HoverIntent: Send + Sync + 'static {
+    /// How long the pointer has to rest on a sub trigger before its submenu opens.
+    fn open_delay(&self) -> Duration;
+
+    /// How long a grace area stays valid after the pointer leaves the sub trigger.
+    fn grace_timeout(&self) -> Duration;
+
+    /// The area that keeps the submenu open when the pointer leaves its trigger at `exit`.
+    /// Returning `None` closes the submenu as soon as another item is hovered.
+    fn grace_area(&self, exit: Point, side: Side, content: Rect) -> Option<Polygon>;
+
+    /// Whether the pointer, last seen at `previous`, is still heading into the submenu.
+    fn is_intending(
+        &self,
+        intent: &GraceIntent,
+        previous: Option<PointerSample>,
+        current: PointerSample,
+    ) -> bool;
+}
+
+/// Keeps the submenu open while the pointer moves towards it within the convex hull of the exit
+/// point and the submenu's rect. This is the Radix default.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GraceArea {
+    pub open_delay: Duration,
+    pub grace_timeout: Duration,
+    /// How far the exit point is pushed back over the trigger, in pixels.
+    pub bleed: f64,
+}
+
+impl Default for GraceArea {
+    fn default() -> Self {
+        Self {
+            open_delay: Duration::from_millis(100),
+            grace_timeout: Duration::from_millis(300),
+            bleed: 5.0,
+        }
+    }
+}
+
+impl HoverIntent for GraceArea {
+    fn open_delay(&self) -> Duration {
+        self.open_delay
+    }
+
+    fn grace_timeout(&self) -> Duration {
+        self.grace_timeout
+    }
+
+    fn grace_area(&self, exit: Point, side: Side, content: Rect) -> Option<Polygon> {
+        let bleed = match side {
+            Side::Right => -self.bleed,
+            Side::Left => self.bleed,
+        };
+        Some(get_hull(&[
+            Point {
+                x: exit.x + bleed,
+                y: exit.y,
+            },
+            Point {
+                x: content.left,
+                y: content.top,
+            },
+            Point {
+                x: content.right,
+                y: content.top,
+            },
+            Point {
+                x: content.right,
+                y: content.bottom,
+            },
+            Point {
+                x: content.left,
+                y: content.bottom,
+            },
+        ]))
+    }
+
+    fn is_intending(
+        &self,
+        intent: &GraceIntent,
+        previous: Option<PointerSample>,
+        current: PointerSample,
+    ) -> bool {
+        is_moving_towards(intent.side, previous, current)
+            && is_point_in_polygon(current.point, &intent.area)
+    }
+}
+
+/// Keeps the submenu open only while the pointer stays inside the triangle between the exit point
+/// and the submenu's near edge, and keeps moving at least `min_velocity`. Pausing over an item
+/// hands hover back to it, which suits dense menus with many adjacent submenus.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SafeTriangle {
+    pub open_delay: Duration,
+    pub grace_timeout: Duration,
+    /// Minimum pointer speed in pixels per millisecond.
+    pub min_velocity: f64,
+}
+
+impl Default for SafeTriangle {
+    fn default() -> Self {
+        Self {
+            open_delay: Duration::from_millis(100),
+            grace_timeout: Duration::from_millis(300),
+            min_velocity: 0.1,
+        }
+    }
+}
+
+impl HoverIntent for SafeTriangle {
+    fn open_delay(&self) -> Duration {
+        self.open_delay
+    }
+
+    fn grace_timeout(&self) -> Duration {
+        self.grace_timeout
+    }
+
+    fn grace_area(&self, exit: Point, side: Side, content: Rect) -> Option<Polygon> {
+        let (near_x, _) = content_edges(side, content);
+        Some(vec![
+            exit,
+            Point {
+                x: near_x,
+                y: content.top,
+            },
+            Point {
+                x: near_x,
+                y: content.bottom,
+            },
+        ])
+    }
+
+    fn is_intending(
+        &self,
+        intent: &GraceIntent,
+        previous: Option<PointerSample>,
+        current: PointerSample,
+    ) -> bool {
+        let is_fast_enough = previous.is_none_or(|previous| {
+            let elapsed = current.time_stamp - previous.time_stamp;
+            let distance = (current.point.x - previous.point.x)
+                .hypot(current.point.y - previous.point.y);
+            elapsed <= 0.0 || distance / elapsed >= self.min_velocity
+        });
+
+        is_fast_enough
+            && is_moving_towards(intent.side, previous, current)
+            && is_point_in_polygon(current.point, &intent.area)
+    }
+}
+
+/// Opens submenus after the pointer rests on the trigger for `open_delay`, without any grace area.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HoverDelay {
+    pub open_delay: Duration,
+}
+
+impl Default for HoverDelay {
+    fn default() -> Self {
+        Self {
+            open_delay: Duration::from_millis(200),
+        }
+    }
+}
+
+impl HoverIntent for HoverDelay {
+    fn open_delay(&self) -> Duration {
+        self.open_delay
+    }
+
+    fn grace_timeout(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    fn grace_area(&self, _exit: Point, _side: Side, _content: Rect) -> Option<Polygon> {
+        None
+    }
+
+    fn is_intending(
+        &self,
+        _intent: &GraceIntent,
+        _previous: Option<PointerSample>,
+        _current: PointerSample,
+    ) -> bool {
+        false
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * HoverIntentStrategy
+ * -----------------------------------------------------------------------------------------------*/
+
+/// A shared [`HoverIntent`] implementation, as accepted by `Menu` and `HoverIntentProvider`.
+#[derive(Clone)]
+pub struct HoverIntentStrategy(Arc<dyn HoverIntent>);
+
+impl HoverIntentStrategy {
+    pub fn new(hover_intent: impl HoverIntent) -> Self {
+        Self(Arc::new(hover_intent))
+    }
+}
+
+impl Default for HoverIntentStrategy {
+    fn default() -> Self {
+        Self::new(GraceArea::default())
+    }
+}
+
+impl Debug for HoverIntentStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("HoverIntentStrategy").finish_non_exhaustive()
+    }
+}
+
+impl std::ops::Deref for HoverIntentStrategy {
+    type Target = dyn HoverIntent;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.0
+    }
+}
+
+impl From<GraceArea> for HoverIntentStrategy {
+    fn from(value: GraceArea) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<SafeTriangle> for HoverIntentStrategy {
+    fn from(value: SafeTriangle) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<HoverDelay> for HoverIntentStrategy {
+    fn from(value: HoverDelay) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Returns the strategy provided by the closest `HoverIntentProvider`, or the default [`GraceArea`].
+pub fn use_hover_intent(hover_intent: Option<HoverIntentStrategy>) -> HoverIntentStrategy {
+    hover_intent
+        .or_else(use_context::<HoverIntentStrategy>)
+        .unwrap_or_default()
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * HoverIntentProvider
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Sets the hover intent strategy for every menu rendered inside it that doesn't set its own.
+#[component]
+#[allow(non_snake_case)]
+pub fn HoverIntentProvider<C: IntoView + 'static>(
+    #[prop(into)] hover_intent: HoverIntentStrategy,
+    children: TypedChildren<C>,
+) -> impl IntoView {
+    view! { <Provider value=hover_intent>{children.into_inner()()}</Provider> }
+}
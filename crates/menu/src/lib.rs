@@ -4,6 +4,9 @@
 //!
 //! See [`@radix-ui/react-menu`](https://www.npmjs.com/package/@radix-ui/react-menu) for the original package.
 
+mod event_listener;
+mod hover_intent;
 mod menu;
 
+pub use hover_intent::*;
 pub use menu::*;
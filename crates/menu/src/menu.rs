@@ -1,34 +1,44 @@
 // TODO: remove
 #![expect(dead_code, unused_variables)]
 
-use std::rc::Rc;
-use std::{marker::PhantomData};
+use std::time::Duration;
+use std::marker::PhantomData;
 
 use leptos::context::Provider;
 use leptos::tachys::html::node_ref::NodeRefContainer;
 use leptos::{ev, ev::{CustomEvent, Event, FocusEvent, KeyboardEvent, MouseEvent, PointerEvent}, html, prelude::*};
 use leptos_node_ref::prelude::*;
 use leptos_remove_scroll::RemoveScroll;
-// use radix_leptos_checkbox::{get_state as get_checked_state, is_indeterminate, CheckedState};
-// use radix_leptos_collection::primitive as Collection;
+use radix_leptos_checkbox::{get_state as get_checked_state, CheckedState};
+use radix_leptos_collection::{primitive as Collection, use_collection};
 use radix_leptos_compose_refs::{use_composed_refs};
 use radix_leptos_direction::{use_direction, Direction};
-// use radix_leptos_dismissable_layer::{
-//     FocusOutsideEvent, InteractOutsideEvent, PointerDownOutsideEvent,
-// };
+use radix_leptos_dismissable_layer::{
+    DismissableLayer, FocusOutsideEvent, InteractOutsideEvent, PointerDownOutsideEvent,
+};
 use radix_leptos_id::use_id;
+use radix_leptos_input_modality::use_input_modality;
 use radix_leptos_presence::Presence;
 
 // use radix_leptos_focus_guards::use_focus_guards;
-// use radix_leptos_focus_scope::FocusScope;
-use radix_leptos_popper::{Popper, PopperAnchor, PopperArrow, PopperContent};
+use radix_leptos_focus_scope::FocusScope;
+use radix_leptos_popper::{
+    Align, Popper, PopperAnchor, PopperArrow, PopperContent, PopperVirtualAnchor, Side as PopperSide,
+    VirtualRect,
+};
 use radix_leptos_portal::Portal as PortalPrimitive;
 use radix_leptos_primitive::{compose_callbacks, Primitive, VoidPrimitive};
 
-// use radix_leptos_roving_focus::{Orientation, RovingFocusGroup, RovingFocusGroupItem};
+use radix_leptos_roving_focus::{Orientation, RovingFocusGroup, RovingFocusGroupItem};
+use radix_leptos_use_controllable_state::{use_controllable_state, UseControllableStateParams};
+
+use crate::event_listener::{EventListenerHandle, ListenerOptions};
+use crate::hover_intent::{
+    use_hover_intent, GraceIntent, HoverIntentStrategy, Point, PointerSample, Rect, Side,
+};
 use web_sys::{
-    wasm_bindgen::{closure::Closure, JsCast},
-    AddEventListenerOptions, CustomEventInit,
+    wasm_bindgen::JsCast,
+    CustomEventInit, FocusOptions,
 };
 use leptos_typed_fallback_show::TypedFallbackShow;
 // use radix_leptos_focus_guards::use_focus_guards;
@@ -49,6 +59,9 @@ const FIRST_KEYS: &[&str] = &[ARROW_DOWN, PAGE_UP, HOME];
 const LAST_KEYS: &[&str] = &[ARROW_UP, PAGE_DOWN, END];
 const FIRST_LAST_KEYS: &[&str] = &[ARROW_DOWN, PAGE_UP, HOME, ARROW_UP, PAGE_DOWN, END];
 
+/// Dispatched on the content element when focus is about to be restored on close. Can be prevented.
+const CLOSE_AUTO_FOCUS: &str = "closeautofocus";
+
 const SUB_OPEN_KEYS: &[(Direction, &[&str])] = &[
     (Direction::Ltr, &[ENTER, SPACE, ARROW_RIGHT]),
     (Direction::Rtl, &[ENTER, SPACE, ARROW_LEFT]),
@@ -59,14 +72,45 @@ const SUB_CLOSE_KEYS: &[(Direction, &[&str])] = &[
     (Direction::Rtl, &[ARROW_RIGHT]),
 ];
 
+/// Looks up the keys for `dir` in one of the `SUB_*_KEYS` tables.
+fn keys_for_direction(
+    keys: &'static [(Direction, &'static [&'static str])],
+    dir: Direction,
+) -> &'static [&'static str] {
+    keys.iter()
+        .find(|(key_dir, _)| *key_dir == dir)
+        .map(|(_, keys)| *keys)
+        .unwrap_or_default()
+}
+
+/// Keyboard hand-off from an open menu to one of its neighbours, e.g. in a menubar.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum HorizontalNavigation {
+    Previous,
+    Next,
+}
+
+impl HorizontalNavigation {
+    /// Resolves an `ArrowLeft`/`ArrowRight` key press against the reading direction.
+    fn from_key(key: &str, dir: Direction) -> Option<Self> {
+        match (key, dir) {
+            (ARROW_LEFT, Direction::Ltr) | (ARROW_RIGHT, Direction::Rtl) => Some(Self::Previous),
+            (ARROW_RIGHT, Direction::Ltr) | (ARROW_LEFT, Direction::Rtl) => Some(Self::Next),
+            _ => None,
+        }
+    }
+}
+
 /* -------------------------------------------------------------------------------------------------
  * Menu
  * -----------------------------------------------------------------------------------------------*/
 
-#[derive(Clone, Debug)]
-struct ItemData {
-    disabled: bool,
-    text_value: String,
+/// Data registered with the collection for every item, used for keyboard navigation and typeahead.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ItemData {
+    pub disabled: bool,
+    /// Text matched against typeahead searches.
+    pub text_value: String,
 }
 
 const ITEM_DATA_PHANTOM: PhantomData<ItemData> = PhantomData;
@@ -84,6 +128,8 @@ struct MenuRootContextValue {
     dir: Signal<Direction>,
     modal: Signal<bool>,
     on_close: Callback<()>,
+    hover_intent: HoverIntentStrategy,
+    on_horizontal_navigation: Option<Callback<HorizontalNavigation>>,
 }
 
 #[component]
@@ -93,6 +139,14 @@ pub fn Menu<C: IntoView + 'static>(
     #[prop(into, optional)] dir: MaybeProp<Direction>,
     #[prop(into, optional)] modal: MaybeProp<bool>,
     #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
+    /// How hovering sub triggers opens submenus. Defaults to the closest `HoverIntentProvider`,
+    /// or `GraceArea` without one.
+    #[prop(into, optional)]
+    hover_intent: Option<HoverIntentStrategy>,
+    /// Called when `ArrowLeft`/`ArrowRight` should move to a neighbouring menu instead of being
+    /// handled inside this one. Keys that open or close a submenu are never handed off.
+    #[prop(into, optional)]
+    on_horizontal_navigation: Option<Callback<HorizontalNavigation>>,
     children: TypedChildrenFn<C>,
 ) -> impl IntoView {
     let children = StoredValue::new(children.into_inner());
@@ -102,7 +156,7 @@ pub fn Menu<C: IntoView + 'static>(
     let on_open_change = on_open_change.unwrap_or(Callback::new(|_| {}));
 
     let content_ref: NodeRef<html::Div> = NodeRef::new();
-    let is_using_keyboard = RwSignal::new(false);
+    let input_modality = use_input_modality();
     let direction = use_direction(dir);
 
     let context_value = StoredValue::new(MenuContextValue {
@@ -111,85 +165,14 @@ pub fn Menu<C: IntoView + 'static>(
         on_open_change,
     });
     let root_context_value = StoredValue::new(MenuRootContextValue {
-        is_using_keyboard: is_using_keyboard.into(),
+        is_using_keyboard: Signal::derive(move || input_modality.get().is_keyboard()),
         dir: direction,
         modal,
         on_close: Callback::new(move |_| on_open_change.run(false)),
+        hover_intent: use_hover_intent(hover_intent),
+        on_horizontal_navigation,
     });
 
-    let handle_pointer: Rc<Closure<dyn Fn(PointerEvent)>> = Rc::new(Closure::new(move |_| {
-        is_using_keyboard.set(false);
-    }));
-    let cleanup_handle_pointer = handle_pointer.clone();
-
-    let handle_key_down: Rc<Closure<dyn Fn(KeyboardEvent)>> = Rc::new(Closure::new(move |_| {
-        is_using_keyboard.set(true);
-
-        let options = AddEventListenerOptions::new();
-        options.set_capture(true);
-        options.set_once(true);
-
-        document()
-            .add_event_listener_with_callback_and_add_event_listener_options(
-                "pointerdown",
-                (*handle_pointer).as_ref().unchecked_ref(),
-                &options,
-            )
-            .expect("Pointer down event listener should be added.");
-        document()
-            .add_event_listener_with_callback_and_add_event_listener_options(
-                "pointermove",
-                (*handle_pointer).as_ref().unchecked_ref(),
-                &options,
-            )
-            .expect("Pointer move event listener should be added.");
-    }));
-    let cleanup_handle_key_down = handle_key_down.clone();
-
-    Effect::new(move |_| {
-        let options = AddEventListenerOptions::new();
-        options.set_capture(true);
-
-        // Capture phase ensures we set the boolean before any side effects execute
-        // in response to the key or pointer event as they might depend on this value.
-        document()
-            .add_event_listener_with_callback_and_add_event_listener_options(
-                "keydown",
-                (*handle_key_down).as_ref().unchecked_ref(),
-                &options,
-            )
-            .expect("Key down event listener should be added.");
-    });
-
-    // on_cleanup(move || {
-    //     let options = EventListenerOptions::new();
-    //     options.set_capture(true);
-    //
-    //     document()
-    //         .remove_event_listener_with_callback_and_event_listener_options(
-    //             "keydown",
-    //             (*cleanup_handle_key_down).as_ref().unchecked_ref(),
-    //             &options,
-    //         )
-    //         .expect("Key down event listener should be removed.");
-    //
-    //     document()
-    //         .remove_event_listener_with_callback_and_event_listener_options(
-    //             "pointerdown",
-    //             (*cleanup_handle_pointer).as_ref().unchecked_ref(),
-    //             &options,
-    //         )
-    //         .expect("Pointer down event listener should be removed.");
-    //
-    //     document()
-    //         .remove_event_listener_with_callback_and_event_listener_options(
-    //             "pointermove",
-    //             (*cleanup_handle_pointer).as_ref().unchecked_ref(),
-    //             &options,
-    //         )
-    //         .expect("Pointer move event listener should be removed.");
-    // });
-
     view! {
         <Popper>
             <Provider value=context_value.get_value()>
@@ -220,6 +203,15 @@ pub fn MenuAnchor<C: IntoView + 'static>(
     }
 }
 
+/// Anchors the menu to a rectangle instead of an element, e.g. the pointer position for context menus.
+///
+/// See [`PopperVirtualAnchor`] for the limitations under transformed ancestors.
+#[component]
+#[allow(non_snake_case)]
+pub fn MenuVirtualAnchor(#[prop(into)] rect: Signal<VirtualRect>) -> impl IntoView {
+    view! { <PopperVirtualAnchor rect=rect /> }
+}
+
 /* -------------------------------------------------------------------------------------------------
  * MenuPortal
  * -----------------------------------------------------------------------------------------------*/
@@ -249,19 +241,36 @@ pub fn MenuPortal<C: IntoView + 'static>(
  * MenuContent
  * -----------------------------------------------------------------------------------------------*/
 
+/// Positioning props forwarded to `PopperContent`.
+#[derive(Clone, Copy, Default)]
+struct MenuContentPlacement {
+    side: MaybeProp<PopperSide>,
+    side_offset: MaybeProp<f64>,
+    align: MaybeProp<Align>,
+    align_offset: MaybeProp<f64>,
+}
+
 #[derive(Clone)]
 struct MenuContentContextValue {
     on_item_enter: Callback<PointerEvent>,
     on_item_leave: Callback<PointerEvent>,
     on_trigger_leave: Callback<PointerEvent>,
     search: RwSignal<String>,
-    pointer_grace_timer: RwSignal<u64>,
+    pointer_grace_timer: RwSignal<Option<TimeoutHandle>>,
     on_pointer_grace_intent_change: Callback<Option<GraceIntent>>,
 }
 
 #[component]
 #[allow(non_snake_case)]
 pub fn MenuContent<C: IntoView + 'static>(
+    /// The preferred side of the anchor to render against. Defaults to `Bottom`.
+    #[prop(into, optional)]
+    side: MaybeProp<PopperSide>,
+    #[prop(into, optional)] side_offset: MaybeProp<f64>,
+    /// The preferred alignment against the anchor. Defaults to `Center`.
+    #[prop(into, optional)]
+    align: MaybeProp<Align>,
+    #[prop(into, optional)] align_offset: MaybeProp<f64>,
     #[prop(into, optional)] as_child: MaybeProp<bool>,
     #[prop(optional)] node_ref: AnyNodeRef,
     children: TypedChildrenFn<C>,
@@ -269,26 +278,30 @@ pub fn MenuContent<C: IntoView + 'static>(
     let children = StoredValue::new(children.into_inner());
     let root_context = expect_context::<MenuRootContextValue>();
     let context = expect_context::<MenuContextValue>();
+    let placement = MenuContentPlacement {
+        side,
+        side_offset,
+        align,
+        align_offset,
+    };
     view! {
         <Presence present=context.open>
-            // <Collection::Provider item_data_type=ITEM_DATA_PHANTOM>
-            // <Collection::Slot item_data_type=ITEM_DATA_PHANTOM>
+            <Collection::Provider item_data_type=ITEM_DATA_PHANTOM>
             <TypedFallbackShow
                 when=move || root_context.modal.get()
                 fallback=move || {
                     view! {
-                        <MenuRootContentNonModal>
+                        <MenuRootContentNonModal placement=placement>
                             {children.with_value(|children| children())}
                         </MenuRootContentNonModal>
                     }
                 }
             >
-                <MenuRootContentModal as_child=as_child node_ref=node_ref>
+                <MenuRootContentModal placement=placement as_child=as_child node_ref=node_ref>
                     {children.with_value(|children| children())}
                 </MenuRootContentModal>
             </TypedFallbackShow>
-        // </Collection::Slot>
-        // </Collection::Provider>
+            </Collection::Provider>
         </Presence>
     }
 }
@@ -298,7 +311,8 @@ pub fn MenuContent<C: IntoView + 'static>(
 #[component]
 #[allow(non_snake_case)]
 fn MenuRootContentModal<C: IntoView + 'static>(
-    // #[prop(into, optional)] on_focus_outside: Option<Callback<FocusOutsideEvent>>,
+    #[prop(optional)] placement: MenuContentPlacement,
+    #[prop(into, optional)] on_focus_outside: Option<Callback<FocusOutsideEvent>>,
     #[prop(into, optional)] as_child: MaybeProp<bool>,
     #[prop(into, optional)] node_ref: AnyNodeRef,
     children: TypedChildrenFn<C>,
@@ -324,10 +338,11 @@ fn MenuRootContentModal<C: IntoView + 'static>(
             disable_outside_pointer_events=context.open
             disable_outside_scroll=true
             // When focus is trapped, a `focusout` event may still happen. We make sure we don't trigger our `on_dismiss` in such case.
-            // on_focus_outside=compose_callbacks(on_focus_outside, Some(Callback::new(move |event: FocusOutsideEvent| {
-            // event.prevent_default();
-            // })), Some(false))
+            on_focus_outside=Callback::new(compose_callbacks(on_focus_outside, Some(Callback::new(move |event: FocusOutsideEvent| {
+                event.prevent_default();
+            })), Some(false)))
             on_dismiss=Callback::new(move |_| context.on_open_change.run(false))
+            placement=placement
             as_child=as_child
             node_ref=composed_refs
         >
@@ -339,6 +354,7 @@ fn MenuRootContentModal<C: IntoView + 'static>(
 #[component]
 #[allow(non_snake_case)]
 fn MenuRootContentNonModal<C: IntoView + 'static>(
+    #[prop(optional)] placement: MenuContentPlacement,
     #[prop(into, optional)] as_child: MaybeProp<bool>,
     #[prop(optional)] node_ref: AnyNodeRef,
     children: TypedChildrenFn<C>,
@@ -351,6 +367,7 @@ fn MenuRootContentNonModal<C: IntoView + 'static>(
             disable_outside_pointer_events=false
             disable_outside_scroll=false
             on_dismiss=Callback::new(move |_| context.on_open_change.run(false))
+            placement=placement
             as_child=as_child
             node_ref=node_ref
         >
@@ -393,9 +410,9 @@ fn MenuContentImpl<C: IntoView + 'static>(
     on_close_auto_focus: Option<Callback<Event>>,
     #[prop(into, optional)] disable_outside_pointer_events: MaybeProp<bool>,
     #[prop(into, optional)] on_escape_key_down: Option<Callback<KeyboardEvent>>,
-    // #[prop(into, optional)] on_pointer_down_outside: Option<Callback<PointerDownOutsideEvent>>,
-    // #[prop(into, optional)] on_focus_outside: Option<Callback<FocusOutsideEvent>>,
-    // #[prop(into, optional)] on_interact_outside: Option<Callback<InteractOutsideEvent>>,
+    #[prop(into, optional)] on_pointer_down_outside: Option<Callback<PointerDownOutsideEvent>>,
+    #[prop(into, optional)] on_focus_outside: Option<Callback<FocusOutsideEvent>>,
+    #[prop(into, optional)] on_interact_outside: Option<Callback<InteractOutsideEvent>>,
     #[prop(into, optional)] on_dismiss: Option<Callback<()>>,
     #[prop(into, optional)] on_key_down: Option<Callback<KeyboardEvent>>,
     #[prop(into, optional)] on_blur: Option<Callback<FocusEvent>>,
@@ -410,6 +427,7 @@ fn MenuContentImpl<C: IntoView + 'static>(
     /// Whether keyboard navigation should loop around. Defaults to `false`.
     r#loop: MaybeProp<bool>,
     #[prop(into, optional)] on_entry_focus: Option<Callback<Event>>,
+    #[prop(optional)] placement: MenuContentPlacement,
     #[prop(into, optional)] as_child: MaybeProp<bool>,
     #[prop(optional)] node_ref: AnyNodeRef,
     children: TypedChildrenFn<C>,
@@ -418,97 +436,96 @@ fn MenuContentImpl<C: IntoView + 'static>(
     let children = StoredValue::new(children.into_inner());
     let context = expect_context::<MenuContextValue>();
     let root_context = expect_context::<MenuRootContextValue>();
-    // let get_items = StoredValue::new(use_collection::<ItemData>());
+    let get_items = use_collection::<ItemData>();
     let (current_item_id, set_current_item_id) = signal::<Option<String>>(None);
     let content_ref: NodeRef<html::Div> = NodeRef::new();
-    let composed_refs = use_composed_refs((node_ref, content_ref));
-    let timer = RwSignal::new(0);
+    let composed_refs = use_composed_refs((
+        use_composed_refs((node_ref, content_ref)),
+        context.content_ref,
+    ));
+    let timer: RwSignal<Option<TimeoutHandle>> = RwSignal::new(None);
     let search = RwSignal::new("".to_string());
-    let pointer_grace_timer = RwSignal::new(0);
+    let pointer_grace_timer: RwSignal<Option<TimeoutHandle>> = RwSignal::new(None);
     let pointer_grace_intent: RwSignal<Option<GraceIntent>> = RwSignal::new(None);
-    let pointer_dir = RwSignal::new(Side::Right);
-    let last_pointer_x = RwSignal::new(0);
+    let last_pointer_sample: RwSignal<Option<PointerSample>> = RwSignal::new(None);
+    let hover_intent = StoredValue::new(root_context.hover_intent.clone());
 
-    let clear_search: Closure<dyn Fn()> = Closure::new(move || {
-        search.set("".into());
-        window().clear_timeout_with_handle(timer.get());
-    });
+    let clear_timer = move || {
+        if let Some(timer) = timer.get_untracked() {
+            timer.clear();
+        }
+    };
 
-    // let handle_typeahead_search = Callback::new(move |key: String| {
-    //     let search_value = search.get() + &key;
-    //     // let items = get_items.with_value(|get_items| get_items());
-    //     // let items = items
-    //     //     .iter()
-    //     //     .filter(|item| !item.data.disabled)
-    //     //     .collect::<Vec<_>>();
-    //     let current_item = document().active_element();
-    //     // let current_match = items
-    //     //     .iter()
-    //     //     .find(|item| {
-    //     //         item.r#ref.get().map(|html_element| {
-    //     //             let element: &web_sys::Element = html_element.deref();
-    //     //             element.clone()
-    //     //         }) == current_item
-    //     //     })
-    //     //     .map(|item| item.data.text_value.clone());
-    //     // let values = items
-    //     //     .iter()
-    //     //     .map(|item| item.data.text_value.clone())
-    //     //     .collect::<Vec<_>>();
-    //     // let next_match = get_next_match(values, search_value.clone(), current_match);
-    //     // let new_item = items
-    //     //     .iter()
-    //     //     .find(|item| {
-    //     //         next_match
-    //     //             .as_ref()
-    //     //             .is_some_and(|next_match| item.data.text_value == *next_match)
-    //     //     })
-    //     //     .and_then(|item| item.r#ref.get());
-    //
-    //     search.set(search_value.clone());
-    //     window().clear_timeout_with_handle(timer.get());
-    //     if !search_value.is_empty() {
-    //         // Reset search 1 second after it was last updated.
-    //         timer.set(
-    //             window()
-    //                 .set_timeout_with_callback_and_timeout_and_arguments_0(
-    //                     clear_search.as_ref().unchecked_ref(),
-    //                     1000,
-    //                 )
-    //                 .expect("Timeout should be set"),
-    //         );
-    //     }
-    //
-    //     // if let Some(new_item) = new_item {
-    //     //     window()
-    //     //         .set_timeout_with_callback(
-    //     //             Closure::once(move || new_item.deref().focus())
-    //     //                 .as_ref()
-    //     //                 .unchecked_ref(),
-    //     //         )
-    //     //         .expect("Timeout should be set.");
-    //     // }
-    // });
+    let handle_typeahead_search = Callback::new(move |key: String| {
+        let search_value = search.get_untracked() + &key;
+        let items = get_items()
+            .into_iter()
+            .filter(|item| !item.data.disabled)
+            .collect::<Vec<_>>();
+        let current_item = document().active_element();
+        let current_match = items
+            .iter()
+            .find(|item| item.r#ref.get_untracked() == current_item)
+            .map(|item| item.data.text_value.clone());
+        let values = items
+            .iter()
+            .map(|item| item.data.text_value.clone())
+            .collect::<Vec<_>>();
+        let next_match = get_next_match(values, search_value.clone(), current_match);
+        let new_item = items
+            .iter()
+            .find(|item| {
+                next_match
+                    .as_ref()
+                    .is_some_and(|next_match| item.data.text_value == *next_match)
+            })
+            .and_then(|item| item.r#ref.get_untracked());
+
+        search.set(search_value.clone());
+        clear_timer();
+        if !search_value.is_empty() {
+            // Reset search 1 second after it was last updated.
+            timer.set(
+                set_timeout_with_handle(
+                    move || search.set("".into()),
+                    Duration::from_millis(1000),
+                )
+                .ok(),
+            );
+        }
+
+        if let Some(new_item) = new_item {
+            set_timeout(
+                move || {
+                    let _ = new_item.unchecked_into::<web_sys::HtmlElement>().focus();
+                },
+                Duration::ZERO,
+            );
+        }
+    });
 
     on_cleanup(move || {
-        window().clear_timeout_with_handle(timer.get());
+        clear_timer();
+        if let Some(timer) = pointer_grace_timer.get_untracked() {
+            timer.clear();
+        }
     });
 
     // Make sure the whole tree has focus guards as our `MenuContent` may be the last element in the DOM (because of the `Portal`).
     // use_focus_guards();
 
     let is_pointer_moving_to_submenu = move |event: &PointerEvent| -> bool {
-        let is_moving_towards = Some(pointer_dir.get())
-            == pointer_grace_intent
-            .get()
-            .map(|pointer_grace_intent| pointer_grace_intent.side);
-        is_moving_towards
-            && is_pointer_in_grace_area(
-            event,
-            pointer_grace_intent
-                .get()
-                .map(|pointer_grace_intent| pointer_grace_intent.area),
-        )
+        pointer_grace_intent.with_untracked(|intent| {
+            intent.as_ref().is_some_and(|intent| {
+                hover_intent.with_value(|hover_intent| {
+                    hover_intent.is_intending(
+                        intent,
+                        last_pointer_sample.get_untracked(),
+                        pointer_sample(event),
+                    )
+                })
+            })
+        })
     };
 
     let content_context_value = StoredValue::new(MenuContentContextValue {
@@ -538,58 +555,78 @@ fn MenuContentImpl<C: IntoView + 'static>(
         }),
     });
 
-    // // let mut attrs = attrs.clone();
-    // attrs.extend([
-    //     ("role", "menu".into_attribute()),
-    //     ("aria-orientation", "vertical".into_attribute()),
-    //     (
-    //         "data-state",
-    //         (move || get_open_state(context.open.get())).into_attribute(),
-    //     ),
-    //     ("data-radix-menu-content", "".into_attribute()),
-    //     ("dir", (move || root_context.dir.get()).into_attribute()),
-    //     // TODO: style
-    // ]);
-
-    // TODO: ScrollLockWrapper, DismissableLayer
     view! {
         <Provider value=content_context_value.get_value()>
             <ScrollLockWrapper disable_outside_scroll=disable_outside_scroll>
-                // <FocusScope
-                // as_child=true
-                // trapped=trap_focus
-                // on_mount_auto_focus=Callback::new(compose_callbacks(
-                // on_open_auto_focus,
-                // Some(Callback::new(move |event: Event| {
-                // // When opening, explicitly focus the content area only and leave `onEntryFocus` in  control of focusing first item.
-                // event.prevent_default();
-                // 
-                // if let Some(content) = content_ref.get_untracked() {
-                // // TODO: focus with options doesn't exist in web-sys
-                // content.focus().expect("Element should be focused");
-                // }
-                // })),
-                // None,
-                // ))
-                // on_unmount_auto_focus=on_close_auto_focus
-                // >
-                // <RovingFocusGroup
-                // as_child=true
-                // dir=root_context.dir
-                // orientation=Orientation::Vertical
-                // r#loop=r#loop
-                // current_tab_stop_id=current_item_id
-                // on_current_tab_stop_id_change=move |value| set_current_item_id.set(value)
-                // on_entry_focus=compose_callbacks(on_entry_focus, Some(Callback::new(move |event: Event| {
-                // if !root_context.is_using_keyboard.get() {
-                // event.prevent_default();
-                // }
-                // })), None)
-                // prevent_scroll_on_entry_focus=true
-                // >
+                <FocusScope
+                    as_child=true
+                    trapped=trap_focus
+                    on_mount_auto_focus=Callback::new(compose_callbacks(
+                        on_open_auto_focus,
+                        Some(Callback::new(move |event: Event| {
+                            // When opening, explicitly focus the content area only and leave `onEntryFocus` in  control of focusing first item.
+                            event.prevent_default();
+
+                            if let Some(content) = content_ref.get_untracked() {
+                                let options = FocusOptions::new();
+                                options.set_prevent_scroll(true);
+                                content.focus_with_options(&options).expect("Element should be focused.");
+                            }
+                        })),
+                        None,
+                    ))
+                    on_unmount_auto_focus=Callback::new(compose_callbacks(
+                        on_close_auto_focus,
+                        Some(Callback::new(move |event: Event| {
+                            // Let wrappers listening with `on:closeautofocus` on the content decide whether focus should be restored.
+                            if let Some(content) = content_ref.get_untracked() {
+                                if dispatch_cancelable_event(&content, CLOSE_AUTO_FOCUS) {
+                                    event.prevent_default();
+                                }
+                            }
+                        })),
+                        None,
+                    ))
+                >
+                <DismissableLayer
+                    as_child=true
+                    disable_outside_pointer_events=disable_outside_pointer_events
+                    on_escape_key_down=on_escape_key_down
+                    on_pointer_down_outside=on_pointer_down_outside
+                    on_focus_outside=on_focus_outside
+                    on_interact_outside=on_interact_outside
+                    on_dismiss=on_dismiss
+                >
+                <RovingFocusGroup
+                    as_child=true
+                    dir=root_context.dir
+                    orientation=Orientation::Vertical
+                    r#loop=r#loop
+                    current_tab_stop_id=current_item_id
+                    on_current_tab_stop_id_change=Callback::new(move |value| set_current_item_id.set(value))
+                    on_entry_focus=Callback::new(compose_callbacks(
+                        on_entry_focus,
+                        Some(Callback::new(move |event: Event| {
+                            if !root_context.is_using_keyboard.get_untracked() {
+                                event.prevent_default();
+                            }
+                        })),
+                        None,
+                    ))
+                    prevent_scroll_on_entry_focus=true
+                >
+                <Collection::Slot item_data_type=ITEM_DATA_PHANTOM>
                 <PopperContent
+                    side=placement.side
+                    side_offset=placement.side_offset
+                    align=placement.align
+                    align_offset=placement.align_offset
                     as_child=as_child
                     node_ref=composed_refs
+                    attr:role="menu"
+                    attr:aria-orientation="vertical"
+                    attr:data-state=move || get_open_state(context.open.get())
+                    attr:data-radix-menu-content=""
                     on:keydown=compose_callbacks(
                         on_key_down,
                         Some(
@@ -613,14 +650,42 @@ fn MenuContentImpl<C: IntoView + 'static>(
                                     if event.key() == "Tab" {
                                         event.prevent_default();
                                     }
-                                    if !is_modifier_key && is_character_key {}
+                                    if !is_modifier_key && is_character_key {
+                                        handle_typeahead_search.run(event.key());
+                                    }
                                 }
-                                if content_ref.get().is_some_and(|content| *content == target) {
-                                    if !FIRST_LAST_KEYS.contains(&event.key().as_str()) {
+
+                                // Sub triggers and sub content prevent the keys they use to open and close submenus.
+                                if let Some(on_horizontal_navigation) = root_context.on_horizontal_navigation {
+                                    let navigation = HorizontalNavigation::from_key(
+                                        &event.key(),
+                                        root_context.dir.get_untracked(),
+                                    );
+                                    if let Some(navigation) = navigation.filter(|_| !event.default_prevented()) {
+                                        on_horizontal_navigation.run(navigation);
+                                        event.prevent_default();
                                         return;
                                     }
-                                    event.prevent_default();
                                 }
+
+                                // Focus first/last item based on key pressed.
+                                if !content_ref.get().is_some_and(|content| *content == target) {
+                                    return;
+                                }
+                                if !FIRST_LAST_KEYS.contains(&event.key().as_str()) {
+                                    return;
+                                }
+                                event.prevent_default();
+                                let mut candidate_nodes = get_items()
+                                    .into_iter()
+                                    .filter(|item| !item.data.disabled)
+                                    .filter_map(|item| item.r#ref.get_untracked())
+                                    .map(|item| item.unchecked_into::<web_sys::HtmlElement>())
+                                    .collect::<Vec<_>>();
+                                if LAST_KEYS.contains(&event.key().as_str()) {
+                                    candidate_nodes.reverse();
+                                }
+                                focus_first(candidate_nodes);
                             }),
                         ),
                         None,
@@ -640,7 +705,7 @@ fn MenuContentImpl<C: IntoView + 'static>(
                                     })
                                     .expect("Event should have current target.");
                                 if !current_target.contains(Some(&target)) {
-                                    window().clear_timeout_with_handle(timer.get());
+                                    clear_timer();
                                     search.set("".into());
                                 }
                             }),
@@ -661,15 +726,12 @@ fn MenuContentImpl<C: IntoView + 'static>(
                                         current_target.unchecked_into::<web_sys::Node>()
                                     })
                                     .expect("Event should have current target.");
-                                let pointer_x_has_changed = last_pointer_x.get()
-                                    != event.client_x();
+                                let sample = pointer_sample(&event);
+                                let pointer_x_has_changed = last_pointer_sample
+                                    .get_untracked()
+                                    .is_none_or(|last| last.point.x != sample.point.x);
                                 if current_target.contains(Some(&target)) && pointer_x_has_changed {
-                                    let new_dir = match event.client_x() > last_pointer_x.get() {
-                                        true => Side::Right,
-                                        false => Side::Left,
-                                    };
-                                    pointer_dir.set(new_dir);
-                                    last_pointer_x.set(event.client_x());
+                                    last_pointer_sample.set(Some(sample));
                                 }
                             }),
                         ),
@@ -678,8 +740,10 @@ fn MenuContentImpl<C: IntoView + 'static>(
                 >
                     {children.with_value(|children| children())}
                 </PopperContent>
-            // </RovingFocusGroup>
-            // </FocusScope>
+                </Collection::Slot>
+                </RovingFocusGroup>
+                </DismissableLayer>
+                </FocusScope>
             </ScrollLockWrapper>
         </Provider>
     }
@@ -734,6 +798,10 @@ const ITEM_SELECT: &str = "menu.itemSelect";
 #[allow(non_snake_case)]
 pub fn MenuItem<C: IntoView + 'static>(
     #[prop(into, optional)] disabled: MaybeProp<bool>,
+    /// Text used for typeahead. Defaults to the item's text content; set it when the content
+    /// is not plain text (e.g. icons or shortcuts).
+    #[prop(into, optional)]
+    text_value: MaybeProp<String>,
     #[prop(into, optional)] on_select: Option<Callback<Event>>,
     #[prop(into, optional)] on_click: Option<Callback<MouseEvent>>,
     #[prop(into, optional)] on_pointer_down: Option<Callback<PointerEvent>>,
@@ -758,45 +826,43 @@ pub fn MenuItem<C: IntoView + 'static>(
         }
 
         if let Some(item) = item_ref.get() {
-            let on_select_clone = on_select.clone();
-            let closure: Closure<dyn Fn(Event)> = Closure::new(move |event: Event| {
-                if let Some(handler) = &on_select_clone {
-                    handler.run(event);
-                }
-            });
-
-            // Create and configure CustomEvent
-            let mut init = CustomEventInit::new();
-            init.bubbles(true);
-            init.cancelable(true);
-
-            if let Ok(item_select_event) = CustomEvent::new_with_event_init_dict(ITEM_SELECT, &init) {
-                let event = item_select_event.unchecked_into::<Event>();
-
-                // Add one-time event listener
-                let _ = item.add_event_listener_with_callback_and_bool(
-                    ITEM_SELECT,
-                    closure.as_ref().unchecked_ref(),
-                    true, // once
-                );
-
-                if let Ok(dispatched) = item.dispatch_event(&event) {
-                    if !dispatched {
-                        is_pointer_down.set(false);
-                    } else {
-                        root_context.on_close.run(());
+            let init = CustomEventInit::new();
+            init.set_bubbles(true);
+            init.set_cancelable(true);
+            let item_select_event = CustomEvent::new_with_event_init_dict(ITEM_SELECT, &init)
+                .expect("Item select event should be instantiated.");
+
+            // `on_select` runs synchronously during dispatch, so the listener only has to live
+            // for the duration of this call.
+            let _select_listener = EventListenerHandle::new(
+                &item,
+                ITEM_SELECT,
+                ListenerOptions {
+                    once: true,
+                    ..Default::default()
+                },
+                move |event: Event| {
+                    if let Some(on_select) = on_select {
+                        on_select.run(event);
                     }
-                }
+                },
+            );
+
+            let not_prevented = item
+                .dispatch_event(&item_select_event)
+                .expect("Item select event should be dispatched.");
+            if not_prevented {
+                root_context.on_close.run(());
+            } else {
+                is_pointer_down.set(false);
             }
-
-            // Prevent memory leak
-            closure.forget();
         }
     });
 
     view! {
         <MenuItemImpl
             disabled=disabled
+            text_value=text_value
             as_child=as_child
             node_ref=composed_refs
             on:click=compose_callbacks(on_click, Some(handle_select.clone()), None)
@@ -983,6 +1049,7 @@ where
     let children = StoredValue::new(children.into_inner());
     let disabled = Signal::derive(move || disabled.get().unwrap_or(false));
 
+    let root_context = expect_context::<MenuRootContextValue>();
     let content_context = expect_context::<MenuContentContextValue>();
     let item_ref: AnyNodeRef = AnyNodeRef::new();
     let composed_ref = use_composed_refs(vec![node_ref, item_ref]);
@@ -990,11 +1057,11 @@ where
 
     // Get the item's `.textContent` as default strategy for typeahead `textValue`.
     let (text_content, set_text_content) = signal("".to_string());
-    // Effect::new(move |_| {
-    //     if let Some(item) = item_ref.get() {
-    //         set_text_content.set(item.text_content().unwrap_or("".into()).trim().into());
-    //     }
-    // });
+    Effect::new(move |_| {
+        if let Some(item) = item_ref.get() {
+            set_text_content.set(item.text_content().unwrap_or("".into()).trim().into());
+        }
+    });
 
     let item_data = Signal::derive(move || ItemData {
         disabled: disabled.get(),
@@ -1002,8 +1069,8 @@ where
     });
 
     view! {
-        // <Collection::ItemSlot item_data_type=ITEM_DATA_PHANTOM item_data=item_data>
-        // <RovingFocusGroupItem as_child=true focusable=Signal::derive(move || !disabled.get())>
+        <Collection::ItemSlot item_data_type=ITEM_DATA_PHANTOM item_data=item_data>
+        <RovingFocusGroupItem as_child=true focusable=Signal::derive(move || !disabled.get())>
         <Primitive
             element=html::div
             as_child=as_child
@@ -1011,6 +1078,9 @@ where
             node_ref=composed_ref
             attr:role="menuitem"
             attr:data-highlighted=move || is_focused.get().then_some("").unwrap_or_default()
+            attr:data-focus-visible=move || {
+                (is_focused.get() && root_context.is_using_keyboard.get()).then_some("")
+            }
             attr:aria-disabled=move || disabled.get()
             attr:data-disabled=move || disabled.get()
             on:pointermove=compose_callbacks(
@@ -1063,6 +1133,8 @@ where
         >
             {children.with_value(|children| children())}
         </Primitive>
+        </RovingFocusGroupItem>
+        </Collection::ItemSlot>
     }
 }
 
@@ -1070,129 +1142,199 @@ where
 /* -------------------------------------------------------------------------------------------------
  * MenuCheckboxItem
  * -----------------------------------------------------------------------------------------------*/
-// #[component]
-// pub fn MenuCheckboxItem(
-//     #[prop(optional, into)] checked: MaybeProp<CheckedState>,
-//     #[prop(into, optional)] node_ref: AnyNodeRef,
-//     #[prop(optional, into)] on_checked_change: Option<Callback<bool>>,
-//     children: Children,
-// ) -> impl IntoView {
-//     let checked = checked.get().unwrap_or_default();
-//     let child_view = children();
-//     let onclick = {
-//         let on_checked_change = on_checked_change.clone();
-//         move |ev: ev::MouseEvent| {
-//             if let Some(cb) = on_checked_change.as_ref() {
-//                 let new_val = if is_indeterminate(checked) {
-//                     true
-//                 } else {
-//                     !matches!(checked, CheckedState::True)
-//                 };
-//                 cb.run(new_val);
-//             }
-//             ev.prevent_default();
-//         }
-//     };
-//
-//     view! {
-//         <div
-//             node_ref=node_ref
-//             role="menuitemcheckbox"
-//             aria-checked=checked
-//             on:click=onclick
-//             tabindex=0
-//             data-state=get_checked_state(checked)
-//         >
-//             {child_view}
-//         </div>
-//     }
-// }
 
+/// Published by checkbox and radio items so their `MenuItemIndicator` can follow the checked state.
+#[derive(Clone, Copy)]
+struct ItemIndicatorContextValue {
+    checked: Signal<CheckedState>,
+}
 
-// Radio Group Context
-#[derive(Clone)]
-pub struct RadioGroupContext {
-    pub value: Signal<Option<String>>,
-    pub on_value_change: Callback<String>,
+#[component]
+#[allow(non_snake_case)]
+pub fn MenuCheckboxItem<C: IntoView + 'static>(
+    /// The controlled checked state of the item.
+    #[prop(into, optional)]
+    checked: MaybeProp<CheckedState>,
+    /// The checked state of the item when initially rendered. Use when you do not need to control its checked state.
+    #[prop(into, optional)]
+    default_checked: MaybeProp<CheckedState>,
+    /// Event handler called when the checked state changes.
+    #[prop(into, optional)]
+    on_checked_change: Option<Callback<CheckedState>>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] text_value: MaybeProp<String>,
+    #[prop(into, optional)] on_select: Option<Callback<Event>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let (checked, set_checked) = use_controllable_state(UseControllableStateParams {
+        prop: checked,
+        default_prop: default_checked,
+        on_change: on_checked_change,
+    });
+
+    view! {
+        <Provider value=ItemIndicatorContextValue { checked }>
+            <MenuItem
+                disabled=disabled
+                text_value=text_value
+                as_child=as_child
+                node_ref=node_ref
+                on_select=Callback::new(compose_callbacks(
+                    on_select,
+                    Some(Callback::new(move |_| {
+                        set_checked.run(checked.get_untracked().toggled());
+                    })),
+                    Some(false),
+                ))
+                attr:role="menuitemcheckbox"
+                attr:aria-checked=move || match checked.get() {
+                    CheckedState::True => "true",
+                    CheckedState::False => "false",
+                    CheckedState::Indeterminate => "mixed",
+                }
+                attr:data-state=move || get_checked_state(checked.get())
+            >
+                {children.with_value(|children| children())}
+            </MenuItem>
+        </Provider>
+    }
 }
 
 /* -------------------------------------------------------------------------------------------------
  * MenuRadioGroup
  * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone)]
+pub struct RadioGroupContext<T: Send + Sync + 'static> {
+    pub value: Signal<Option<T>>,
+    pub on_value_change: Callback<T>,
+}
+
+/// A group of `MenuRadioItem`s sharing a single value of type `T`.
+///
+/// `T` is usually inferred from `value`, so enums can be bound directly.
 #[component]
-pub fn MenuRadioGroup<C: IntoView + 'static>(
+#[allow(non_snake_case)]
+pub fn MenuRadioGroup<T, C>(
     children: TypedChildrenFn<C>,
     #[prop(into, optional)] as_child: MaybeProp<bool>,
     #[prop(into, optional)] node_ref: AnyNodeRef,
-    #[prop(into, optional)] value: MaybeProp<String>,
-    #[prop(into, optional, default=Callback::new(|_|{}))] on_value_change: Callback<String>,
-) -> impl IntoView {
+    #[prop(into, optional)] value: MaybeProp<T>,
+    #[prop(into, optional)] on_value_change: Option<Callback<T>>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+    C: IntoView + 'static,
+{
     let children = StoredValue::new(children.into_inner());
+    let on_value_change = Callback::new(move |value: T| {
+        if let Some(on_value_change) = on_value_change {
+            on_value_change.run(value);
+        }
+    });
+
     view! {
         <Provider value=RadioGroupContext {
             value: Signal::derive(move || value.get()),
             on_value_change,
         }>
-            <MenuGroup>{children.with_value(|children| children())}</MenuGroup>
+            <MenuGroup as_child=as_child>{children.with_value(|children| children())}</MenuGroup>
         </Provider>
     }
 }
 
-// MenuRadioItem component
-// #[component]
-// pub fn MenuRadioItem(
-//     children: Children,
-//     #[prop(into, optional)] node_ref: AnyNodeRef,
-//     #[prop(into, optional)] value: MaybeProp<String>,
-// ) -> impl IntoView {
-//     let child_view = children();
-//     let radio_ctx = use_context::<RadioGroupContext>().expect("RadioGroupContext not found");
-//
-//     // Derive checked state
-//     let checked = Signal::derive(move || {
-//         radio_ctx.value.get().as_ref() == Some(&value.get().unwrap_or_default())
-//     });
-//
-//     // Click handler
-//     let onclick = {
-//         let cb = radio_ctx.on_value_change.clone();
-//         let val_clone = value.get().unwrap_or_default();
-//         move |_ev: ev::MouseEvent| {
-//             cb.run(val_clone.to_string());
-//         }
-//     };
-//
-//     // Derive state for styling
-//     let state = Signal::<CheckedState>::derive(move || checked.get().into());
-//
-//     view! {
-//         <div
-//             node_ref=node_ref
-//             role="menuitemradio"
-//             aria-checked=move || checked.get()
-//             on:click=onclick
-//             tabindex=0
-//             data-state=move || get_checked_state(state.get())
-//             class="flex relative items-center py-1.5 px-2 text-sm rounded-sm transition-colors cursor-pointer outline-none select-none data-[disabled]:pointer-events-none data-[disabled]:opacity-50 hover:bg-accent focus:bg-accent focus:text-accent-foreground"
-//         >
-//             {child_view}
-//         </div>
-//     }
-// }
+/* -------------------------------------------------------------------------------------------------
+ * MenuRadioItem
+ * -----------------------------------------------------------------------------------------------*/
 
+#[component]
+#[allow(non_snake_case)]
+pub fn MenuRadioItem<T, C>(
+    /// The value this item sets on its `MenuRadioGroup` when selected.
+    value: T,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] text_value: MaybeProp<String>,
+    #[prop(into, optional)] on_select: Option<Callback<Event>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+    C: IntoView + 'static,
+{
+    let children = StoredValue::new(children.into_inner());
+    let value = StoredValue::new(value);
+    let context = expect_context::<RadioGroupContext<T>>();
+    let checked = Signal::derive(move || {
+        context
+            .value
+            .with(|group_value| value.with_value(|value| group_value.as_ref() == Some(value)))
+    });
+
+    view! {
+        <Provider value=ItemIndicatorContextValue {
+            checked: Signal::derive(move || checked.get().into()),
+        }>
+            <MenuItem
+                disabled=disabled
+                text_value=text_value
+                as_child=as_child
+                node_ref=node_ref
+                on_select=Callback::new(compose_callbacks(
+                    on_select,
+                    Some(Callback::new(move |_| {
+                        context.on_value_change.run(value.get_value());
+                    })),
+                    Some(false),
+                ))
+                attr:role="menuitemradio"
+                attr:aria-checked=move || if checked.get() { "true" } else { "false" }
+                attr:data-state=move || get_checked_state(checked.get())
+            >
+                {children.with_value(|children| children())}
+            </MenuItem>
+        </Provider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenuItemIndicator
+ * -----------------------------------------------------------------------------------------------*/
 
 #[component]
 #[allow(non_snake_case)]
 pub fn MenuItemIndicator<C: IntoView + 'static>(
     children: TypedChildrenFn<C>,
-    #[prop(optional)] force_mount: Option<bool>,
+    /// Keep the indicator mounted while unchecked, e.g. to drive exit animations manually.
+    #[prop(into, optional)] force_mount: MaybeProp<bool>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
     #[prop(optional, into)] node_ref: AnyNodeRef,
 ) -> impl IntoView {
     let children = StoredValue::new(children.into_inner());
+    let context = expect_context::<ItemIndicatorContextValue>();
+    let present = Signal::derive(move || {
+        force_mount.get().unwrap_or(false)
+            || matches!(
+                context.checked.get(),
+                CheckedState::True | CheckedState::Indeterminate
+            )
+    });
+
     view! {
-        <Show when=move || force_mount.unwrap_or(false) fallback=|| ()>
-            <span>{children.with_value(|children| children())}</span>
-        </Show>
+        <Presence present=present>
+            <Primitive
+                element=html::span
+                as_child=as_child
+                node_ref=node_ref
+                attr:data-state=move || get_checked_state(context.checked.get())
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        </Presence>
     }
 }
 
@@ -1258,11 +1400,17 @@ pub struct MenuSubContextValue {
 pub fn MenuSub<C: IntoView + 'static>(
     children: TypedChildrenFn<C>,
     #[prop(into, optional)] open: MaybeProp<bool>,
-    #[prop(into, optional, default=Callback::new(|_|{}))] on_open_change: Callback<bool>,
+    #[prop(into, optional)] default_open: MaybeProp<bool>,
+    #[prop(into, optional)] on_open_change: Option<Callback<bool>>,
     #[prop(into, optional)] node_ref: AnyNodeRef,
 ) -> impl IntoView {
     let children = StoredValue::new(children.into_inner());
     let parent_menu_context = expect_context::<MenuContextValue>();
+    let (open, set_open) = use_controllable_state(UseControllableStateParams {
+        prop: open,
+        default_prop: default_open,
+        on_change: on_open_change,
+    });
 
     // State management
     let trigger = RwSignal::new(None::<NodeRef<html::Div>>);
@@ -1281,19 +1429,17 @@ pub fn MenuSub<C: IntoView + 'static>(
     // Effect to handle parent menu state changes
     Effect::new(move |_| {
         if !parent_menu_context.open.get() {
-            on_open_change.run(false);
+            set_open.run(false);
         }
     });
 
-    // Cleanup effect
-    on_cleanup(move || {
-        on_open_change.run(false);
-    });
+    // Close through the controllable state, so uncontrolled submenus reset their own state too.
+    on_cleanup(move || set_open.run(false));
 
     let menu_context = MenuContextValue {
-        open: Signal::derive(move || open.get().unwrap_or_default()),
+        open,
         content_ref: NodeRef::new(),
-        on_open_change,
+        on_open_change: set_open,
     };
     let menu_context = StoredValue::new(menu_context);
     let sub_context = StoredValue::new(sub_context);
@@ -1364,13 +1510,23 @@ pub fn MenuSubTrigger<C: IntoView + 'static>(
     let sub_context = expect_context::<MenuSubContextValue>();
     let content_context = expect_context::<MenuContentContextValue>();
 
-    let open_timer_ref = RwSignal::new(None::<i32>);
+    let open_timer_ref: RwSignal<Option<TimeoutHandle>> = RwSignal::new(None);
+    let hover_intent = StoredValue::new(root_context.hover_intent.clone());
+    let trigger_ref = NodeRef::<html::Div>::new();
+    let composed_refs = use_composed_refs(vec![node_ref, trigger_ref.into_any()]);
+
+    sub_context.on_trigger_change.run(Some(trigger_ref));
 
     // Clear timer on cleanup
     on_cleanup(move || {
-        if let Some(timer) = open_timer_ref.get() {
-            window().clear_timeout_with_handle(timer);
+        if let Some(timer) = open_timer_ref.get_untracked() {
+            timer.clear();
+        }
+        if let Some(timer) = content_context.pointer_grace_timer.get_untracked() {
+            timer.clear();
         }
+        content_context.on_pointer_grace_intent_change.run(None);
+        sub_context.on_trigger_change.run(None);
     });
 
     let trigger_id = StoredValue::new(sub_context.trigger_id);
@@ -1378,32 +1534,16 @@ pub fn MenuSubTrigger<C: IntoView + 'static>(
 
     view! {
         <MenuAnchor as_child=true>
-            <Primitive
-                element=html::div
+            <MenuItemImpl
+                disabled=disabled
                 as_child=as_child
-                node_ref=node_ref
+                node_ref=composed_refs
                 attr:id=trigger_id.with_value(|id| id.clone())
                 attr:aria-haspopup="menu"
                 attr:aria-expanded=move || context.open.get()
                 attr:aria-controls=content_id.with_value(|id| id.clone())
                 attr:data-state=move || get_open_state(context.open.get())
-                attr:data-disabled=move || disabled.get().then_some("").unwrap_or_default()
-                on:click=move |event: MouseEvent| {
-                    if let Some(cb) = onclick.as_ref() {
-                        cb.run(event.clone());
-                    }
-                    if disabled.get() || event.default_prevented() {
-                        return;
-                    }
-                    if let Some(target) = event.current_target() {
-                        let element: web_sys::HtmlElement = target.unchecked_into();
-                        let _ = element.focus();
-                    }
-                    if !context.open.get() {
-                        context.on_open_change.run(true);
-                    }
-                }
-                on:pointermove=move |event: PointerEvent| {
+                on_pointer_move=Callback::new(move |event: PointerEvent| {
                     if let Some(cb) = onpointermove.as_ref() {
                         cb.run(event.clone());
                     }
@@ -1414,101 +1554,63 @@ pub fn MenuSubTrigger<C: IntoView + 'static>(
                     if event.default_prevented() {
                         return;
                     }
-                    if !disabled.get() && !context.open.get() {
+                    // Keep a pending timer instead of restarting it, so a pointer that keeps moving
+                    // still opens the submenu and no callback is abandoned per move.
+                    if !disabled.get() && !context.open.get() && open_timer_ref.get_untracked().is_none() {
                         content_context.on_pointer_grace_intent_change.run(None);
-                        if let Some(timer) = open_timer_ref.get() {
-                            window().clear_timeout_with_handle(timer);
-                        }
-                        let set_open: Closure<dyn Fn()> = Closure::new(move || {
-                            context.on_open_change.run(true);
-                            open_timer_ref.set(None);
-                        });
-                        let timer = window()
-                            .set_timeout_with_callback_and_timeout_and_arguments_0(
-                                set_open.as_ref().unchecked_ref(),
-                                100,
+                        let open_delay = hover_intent.with_value(|hover_intent| hover_intent.open_delay());
+                        open_timer_ref.set(
+                            set_timeout_with_handle(
+                                move || {
+                                    context.on_open_change.run(true);
+                                    open_timer_ref.set(None);
+                                },
+                                open_delay,
                             )
-                            .expect("Failed to set timeout");
-                        open_timer_ref.set(Some(timer));
-                        set_open.forget();
+                            .ok(),
+                        );
                     }
-                }
-                on:pointerleave=move |event: PointerEvent| {
+                })
+                on_pointer_leave=Callback::new(move |event: PointerEvent| {
                     if let Some(cb) = onpointerleave.as_ref() {
                         cb.run(event.clone());
                     }
                     if event.pointer_type() != "mouse" {
                         return;
                     }
-                    if let Some(timer) = open_timer_ref.get() {
-                        window().clear_timeout_with_handle(timer);
+                    if let Some(timer) = open_timer_ref.get_untracked() {
+                        timer.clear();
                         open_timer_ref.set(None);
                     }
-                    if let Some(content) = context.content_ref.get() {
-                        let content_rect = content.get_bounding_client_rect();
-                        let side = content
-                            .dataset()
-                            .get("side")
-                            .unwrap_or_else(|| "right".to_string());
-                        let right_side = side == "right";
-                        let bleed = if right_side { -5.0 } else { 5.0 };
-                        let area = vec![
-                            Point {
-                                x: event.client_x() as f64 + bleed,
-                                y: event.client_y() as f64,
-                            },
-                            Point {
-                                x: if right_side {
-                                    content_rect.left()
-                                } else {
-                                    content_rect.right()
-                                },
-                                y: content_rect.top(),
-                            },
-                            Point {
-                                x: if right_side {
-                                    content_rect.right()
-                                } else {
-                                    content_rect.left()
-                                },
-                                y: content_rect.top(),
-                            },
-                            Point {
-                                x: if right_side {
-                                    content_rect.right()
-                                } else {
-                                    content_rect.left()
-                                },
-                                y: content_rect.bottom(),
-                            },
-                            Point {
-                                x: if right_side {
-                                    content_rect.left()
-                                } else {
-                                    content_rect.right()
-                                },
-                                y: content_rect.bottom(),
-                            },
-                        ];
+                    let grace_intent = context.content_ref.get().and_then(|content| {
+                        let side = match content.dataset().get("side").as_deref() {
+                            Some("left") => Side::Left,
+                            _ => Side::Right,
+                        };
+                        let exit = Point {
+                            x: event.client_x() as f64,
+                            y: event.client_y() as f64,
+                        };
+                        let content_rect = Rect::from(&content.get_bounding_client_rect());
+                        hover_intent
+                            .with_value(|hover_intent| hover_intent.grace_area(exit, side, content_rect))
+                            .map(|area| GraceIntent { area, side })
+                    });
+                    if let Some(grace_intent) = grace_intent {
                         content_context
                             .on_pointer_grace_intent_change
-                            .run(
-                                Some(GraceIntent {
-                                    area,
-                                    side: if right_side { Side::Right } else { Side::Left },
-                                }),
-                            );
-                        let clear_intent: Closure<dyn Fn()> = Closure::new(move || {
-                            content_context.on_pointer_grace_intent_change.run(None);
-                        });
-                        let timer = window()
-                            .set_timeout_with_callback_and_timeout_and_arguments_0(
-                                clear_intent.as_ref().unchecked_ref(),
-                                300,
+                            .run(Some(grace_intent));
+                        if let Some(timer) = content_context.pointer_grace_timer.get_untracked() {
+                            timer.clear();
+                        }
+                        let grace_timeout = hover_intent.with_value(|hover_intent| hover_intent.grace_timeout());
+                        content_context.pointer_grace_timer.set(
+                            set_timeout_with_handle(
+                                move || content_context.on_pointer_grace_intent_change.run(None),
+                                grace_timeout,
                             )
-                            .expect("Failed to set timeout");
-                        content_context.pointer_grace_timer.set(timer as u64);
-                        clear_intent.forget();
+                            .ok(),
+                        );
                     } else {
                         content_context.on_trigger_leave.run(event.clone());
                         if event.default_prevented() {
@@ -1516,30 +1618,45 @@ pub fn MenuSubTrigger<C: IntoView + 'static>(
                         }
                         content_context.on_pointer_grace_intent_change.run(None);
                     }
+                })
+                on:click=move |event: MouseEvent| {
+                    if let Some(cb) = onclick.as_ref() {
+                        cb.run(event.clone());
+                    }
+                    if disabled.get() || event.default_prevented() {
+                        return;
+                    }
+                    if let Some(target) = event.current_target() {
+                        let element: web_sys::HtmlElement = target.unchecked_into();
+                        let _ = element.focus();
+                    }
+                    if !context.open.get() {
+                        context.on_open_change.run(true);
+                    }
                 }
                 on:keydown=move |event: KeyboardEvent| {
                     if let Some(cb) = onkeydown.as_ref() {
                         cb.run(event.clone());
                     }
                     let is_typing_ahead = !content_context.search.get().is_empty();
-                    if disabled.get() || (is_typing_ahead && event.key() == " ") {
+                    if disabled.get() || (is_typing_ahead && event.key() == SPACE) {
                         return;
                     }
-                    let open_keys = match root_context.dir.get() {
-                        Direction::Ltr => vec!["ArrowRight"],
-                        Direction::Rtl => vec!["ArrowLeft"],
-                    };
+                    let open_keys = keys_for_direction(SUB_OPEN_KEYS, root_context.dir.get());
                     if open_keys.contains(&event.key().as_str()) {
                         context.on_open_change.run(true);
+                        // The trigger may hold focus if opened via pointer interaction,
+                        // so we ensure content is given focus again when switching to keyboard.
                         if let Some(content) = context.content_ref.get() {
                             let _ = content.focus();
                         }
+                        // Prevent window from scrolling.
                         event.prevent_default();
                     }
                 }
             >
                 {children.with_value(|children| children())}
-            </Primitive>
+            </MenuItemImpl>
         </MenuAnchor>
     }
 }
@@ -1550,18 +1667,94 @@ pub fn MenuSubContent(
     #[prop(optional)] node_ref: AnyNodeRef,
 ) -> impl IntoView {
     let menu_ctx = expect_context::<MenuContextValue>();
+    let root_context = expect_context::<MenuRootContextValue>();
+    let sub_context = expect_context::<MenuSubContextValue>();
     let children = StoredValue::new(children);
     let is_open = Memo::new(move |_| menu_ctx.open.get());
+    // Open beside the trigger, on the side the grace area of `MenuSubTrigger` expects.
+    let placement = MenuContentPlacement {
+        side: MaybeProp::derive(move || {
+            Some(match root_context.dir.get() {
+                Direction::Ltr => PopperSide::Right,
+                Direction::Rtl => PopperSide::Left,
+            })
+        }),
+        align: MaybeProp::from(Align::Start),
+        ..Default::default()
+    };
     view! {
         <Presence present=is_open>
-            <MenuContentImpl
-                trap_focus=false
-                disable_outside_pointer_events=false
-                disable_outside_scroll=false
-                node_ref=node_ref
-            >
-                {children.with_value(|children| children())}
-            </MenuContentImpl>
+            <Collection::Provider item_data_type=ITEM_DATA_PHANTOM>
+                <MenuContentImpl
+                    placement=placement
+                    trap_focus=false
+                    disable_outside_pointer_events=false
+                    disable_outside_scroll=false
+                    on_open_auto_focus=Callback::new(move |event: Event| {
+                        // When opening a submenu, focus content for keyboard users only.
+                        // Focusing the content lets `on_entry_focus` move focus to the first item.
+                        if root_context.is_using_keyboard.get_untracked() {
+                            if let Some(content) = menu_ctx.content_ref.get_untracked() {
+                                let _ = content.focus();
+                            }
+                        }
+                        event.prevent_default();
+                    })
+                    // The menu might close because of focusing another menu item in the parent menu.
+                    // We don't want it to refocus the trigger in that case, so we handle trigger focus ourselves.
+                    on_close_auto_focus=Callback::new(move |event: Event| event.prevent_default())
+                    on_escape_key_down=Callback::new(move |event: KeyboardEvent| {
+                        root_context.on_close.run(());
+                        // Ensure pressing escape in a submenu doesn't escape full screen mode.
+                        event.prevent_default();
+                    })
+                    on_key_down=Callback::new(move |event: KeyboardEvent| {
+                        // Key events bubble from nested submenus, so only handle keys pressed in this menu.
+                        let is_key_down_inside = event
+                            .target()
+                            .map(|target| target.unchecked_into::<web_sys::Element>())
+                            .and_then(|target| target.closest("[data-radix-menu-content]").ok().flatten())
+                            == event
+                                .current_target()
+                                .and_then(|current_target| current_target.dyn_into::<web_sys::Element>().ok());
+                        let close_keys = keys_for_direction(SUB_CLOSE_KEYS, root_context.dir.get_untracked());
+                        if is_key_down_inside && close_keys.contains(&event.key().as_str()) {
+                            menu_ctx.on_open_change.run(false);
+                            // We focus manually because we prevented it in `on_close_auto_focus`.
+                            if let Some(trigger) = sub_context
+                                .trigger
+                                .get_untracked()
+                                .and_then(|trigger| trigger.get_untracked())
+                            {
+                                let _ = trigger.focus();
+                            }
+                            // Prevent window from scrolling.
+                            event.prevent_default();
+                        }
+                    })
+                    on_focus_outside=Callback::new(move |event: FocusOutsideEvent| {
+                        // We prevent closing when the trigger is focused to avoid triggering a re-open animation on pointer interaction.
+                        let trigger = sub_context
+                            .trigger
+                            .get_untracked()
+                            .and_then(|trigger| trigger.get_untracked());
+                        let is_trigger = trigger.is_some_and(|trigger| {
+                            event.original_event().target().as_ref()
+                                == Some(trigger.unchecked_ref::<web_sys::EventTarget>())
+                        });
+                        if is_trigger {
+                            // Otherwise the dismissable layer still dismisses the submenu.
+                            event.prevent_default();
+                        } else {
+                            menu_ctx.on_open_change.run(false);
+                        }
+                    })
+                    on_dismiss=Callback::new(move |_| menu_ctx.on_open_change.run(false))
+                    node_ref=node_ref
+                >
+                    {children.with_value(|children| children())}
+                </MenuContentImpl>
+            </Collection::Provider>
         </Presence>
     }
 }
@@ -1573,6 +1766,18 @@ fn get_open_state(open: bool) -> String {
     }
 }
 
+/// Dispatches a cancelable, non-bubbling event on `target` and returns whether it was prevented.
+fn dispatch_cancelable_event(target: &web_sys::EventTarget, name: &str) -> bool {
+    let init = CustomEventInit::new();
+    init.set_cancelable(true);
+    let event = CustomEvent::new_with_event_init_dict(name, &init)
+        .expect("Event should be instantiated.");
+    target
+        .dispatch_event(&event)
+        .expect("Event should be dispatched.");
+    event.default_prevented()
+}
+
 fn focus_first(candidates: Vec<web_sys::HtmlElement>) {
     let previously_focused_element = document().active_element();
     for candidate in candidates {
@@ -1590,7 +1795,9 @@ fn focus_first(candidates: Vec<web_sys::HtmlElement>) {
 
 /// Wraps an array around itself at a given start index.
 fn wrap_array<T: Clone>(array: &mut [T], start_index: usize) -> &[T] {
-    array.rotate_right(start_index);
+    if !array.is_empty() {
+        array.rotate_left(start_index % array.len());
+    }
     array
 }
 
@@ -1609,7 +1816,7 @@ fn wrap_array<T: Clone>(array: &mut [T], start_index: usize) -> &[T] {
 /// current match from the values because otherwise it would be the first to match always
 /// and focus would never move. This is as opposed to the regular case, where we
 /// don't want focus to move if the current match still matches.
-fn get_next_match(
+pub fn get_next_match(
     values: Vec<String>,
     search: String,
     current_match: Option<String>,
@@ -1647,60 +1854,13 @@ fn get_next_match(
     }
 }
 
-#[derive(Clone, Debug)]
-struct Point {
-    x: f64,
-    y: f64,
-}
-
-type Polygon = Vec<Point>;
-
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-enum Side {
-    Left,
-    Right,
-}
-
-#[derive(Clone, Debug)]
-struct GraceIntent {
-    area: Polygon,
-    side: Side,
-}
-
-/// Determine if a point is inside of a polygon.
-fn is_point_in_polygon(point: Point, polygon: Polygon) -> bool {
-    let Point { x, y } = point;
-    let mut inside = false;
-
-    let mut i = 0;
-    let mut j = polygon.len() - 1;
-    while i < polygon.len() {
-        let xi = polygon[i].x;
-        let yi = polygon[i].y;
-        let xj = polygon[j].x;
-        let yj = polygon[j].y;
-
-        let intersect = ((yi > y) != (yj > y)) && (x < (xj - xi) * (y - yi) / (yj - yi) + xi);
-        if intersect {
-            inside = !inside;
-        }
-
-        j = i;
-        i += 1;
-    }
-
-    inside
-}
-
-fn is_pointer_in_grace_area(event: &PointerEvent, area: Option<Polygon>) -> bool {
-    if let Some(area) = area {
-        let cursor_pos = Point {
+fn pointer_sample(event: &PointerEvent) -> PointerSample {
+    PointerSample {
+        point: Point {
             x: event.client_x() as f64,
             y: event.client_y() as f64,
-        };
-        is_point_in_polygon(cursor_pos, area)
-    } else {
-        false
+        },
+        time_stamp: event.time_stamp(),
     }
 }
 
@@ -1720,7 +1880,7 @@ pub mod primitive {
     pub use Menu as Root;
     pub use MenuAnchor as Anchor;
     pub use MenuArrow as Arrow;
-    // pub use MenuCheckboxItem as CheckboxItem;
+    pub use MenuCheckboxItem as CheckboxItem;
     pub use MenuContent as Content;
     pub use MenuGroup as Group;
     pub use MenuItem as Item;
@@ -1728,9 +1888,10 @@ pub mod primitive {
     pub use MenuLabel as Label;
     pub use MenuPortal as Portal;
     pub use MenuRadioGroup as RadioGroup;
-    // pub use MenuRadioItem as RadioItem;
+    pub use MenuRadioItem as RadioItem;
     pub use MenuSeparator as Separator;
     pub use MenuSub as Sub;
     pub use MenuSubContent as SubContent;
     pub use MenuSubTrigger as SubTrigger;
+    pub use MenuVirtualAnchor as VirtualAnchor;
 }
\ No newline at end of file
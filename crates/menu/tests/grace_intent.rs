@@ -0,0 +1,137 @@
+use js_sys::wasm_bindgen::JsCast;
+use leptos::{mount::mount_to, prelude::*};
+use radix_leptos_menu::*;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+async fn animation_frame() {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        web_sys::window()
+            .unwrap()
+            .request_animation_frame(&resolve)
+            .unwrap();
+    });
+    JsFuture::from(promise).await.unwrap();
+}
+
+fn setup_test() -> web_sys::HtmlElement {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let test_container = document.create_element("div").unwrap();
+    document
+        .body()
+        .unwrap()
+        .append_child(&test_container)
+        .unwrap();
+    test_container.unchecked_into()
+}
+
+fn dispatch_pointer_event(target: &web_sys::Element, event_type: &str, x: f64, y: f64) {
+    let init = web_sys::PointerEventInit::new();
+    init.set_bubbles(event_type != "pointerleave");
+    init.set_pointer_type("mouse");
+    init.set_client_x(x as i32);
+    init.set_client_y(y as i32);
+    let event = web_sys::PointerEvent::new_with_event_init_dict(event_type, &init).unwrap();
+    target.dispatch_event(&event).unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn test_sub_trigger_leave_keeps_submenu_open_towards_content() {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let test_container = setup_test();
+
+    let dispose = mount_to(test_container.clone(), || {
+        view! {
+            <Menu open=true>
+                <MenuAnchor>
+                    <button>"Trigger"</button>
+                </MenuAnchor>
+                <MenuContent>
+                    <MenuSub default_open=true>
+                        <MenuSubTrigger>"More"</MenuSubTrigger>
+                        <MenuSubContent>
+                            {(0..10)
+                                .map(|index| view! { <MenuItem>{format!("Sub item {index}")}</MenuItem> })
+                                .collect_view()}
+                        </MenuSubContent>
+                    </MenuSub>
+                    <MenuItem>"Below"</MenuItem>
+                </MenuContent>
+            </Menu>
+        }
+    });
+    // Let Floating UI place the submenu.
+    animation_frame().await;
+    animation_frame().await;
+
+    let sub_trigger = test_container
+        .query_selector("[aria-haspopup=menu]")
+        .unwrap()
+        .unwrap();
+    let sub_content = document
+        .get_element_by_id(&sub_trigger.get_attribute("aria-controls").unwrap())
+        .unwrap();
+    let items = test_container
+        .query_selector_all("[role=menuitem]")
+        .unwrap();
+    let below = (0..items.length())
+        .filter_map(|index| items.get(index))
+        .map(|item| item.unchecked_into::<web_sys::Element>())
+        .find(|item| item.text_content().as_deref() == Some("Below"))
+        .unwrap();
+
+    // The submenu opens beside its trigger, where the grace area expects it.
+    let trigger_rect = sub_trigger.get_bounding_client_rect();
+    let content_rect = sub_content.get_bounding_client_rect();
+    assert_eq!(
+        sub_content.get_attribute("data-side").as_deref(),
+        Some("right")
+    );
+    assert!(content_rect.left() >= trigger_rect.right() - 1.0);
+    assert!(content_rect.top() <= trigger_rect.top() + 1.0);
+
+    // Leave the trigger at its right edge, then cut diagonally across the item below it.
+    let center_y = trigger_rect.top() + trigger_rect.height() / 2.0;
+    dispatch_pointer_event(
+        &sub_trigger,
+        "pointermove",
+        trigger_rect.right() - 3.0,
+        center_y,
+    );
+    dispatch_pointer_event(
+        &sub_trigger,
+        "pointerleave",
+        trigger_rect.right() - 1.0,
+        center_y,
+    );
+    let below_rect = below.get_bounding_client_rect();
+    dispatch_pointer_event(
+        &below,
+        "pointermove",
+        trigger_rect.right() - 2.0,
+        below_rect.top() + below_rect.height() / 2.0,
+    );
+    animation_frame().await;
+
+    assert_ne!(document.active_element().as_ref(), Some(&below));
+    assert_eq!(
+        sub_trigger.get_attribute("data-state").as_deref(),
+        Some("open")
+    );
+
+    // Moving away from the submenu hands hover back to the item.
+    dispatch_pointer_event(
+        &below,
+        "pointermove",
+        below_rect.left() + 1.0,
+        below_rect.top() + below_rect.height() / 2.0,
+    );
+    animation_frame().await;
+
+    assert_eq!(document.active_element().as_ref(), Some(&below));
+
+    drop(dispose);
+    test_container.remove();
+}
@@ -0,0 +1,165 @@
+use std::time::Duration;
+
+use radix_leptos_menu::*;
+
+fn point(x: f64, y: f64) -> Point {
+    Point { x, y }
+}
+
+fn sample(x: f64, y: f64, time_stamp: f64) -> PointerSample {
+    PointerSample {
+        point: point(x, y),
+        time_stamp,
+    }
+}
+
+const CONTENT: Rect = Rect {
+    left: 100.0,
+    top: 0.0,
+    right: 200.0,
+    bottom: 100.0,
+};
+
+#[test]
+fn get_hull_drops_collinear_and_duplicate_points() {
+    let hull = get_hull(&[
+        point(0.0, 0.0),
+        point(5.0, 0.0),
+        point(10.0, 0.0),
+        point(10.0, 10.0),
+        point(10.0, 10.0),
+        point(0.0, 10.0),
+        point(0.0, 5.0),
+        point(5.0, 5.0),
+        point(0.0, 0.0),
+    ]);
+
+    assert_eq!(
+        hull,
+        vec![
+            point(0.0, 0.0),
+            point(10.0, 0.0),
+            point(10.0, 10.0),
+            point(0.0, 10.0),
+        ]
+    );
+}
+
+#[test]
+fn get_hull_handles_degenerate_input() {
+    assert_eq!(get_hull(&[]), vec![]);
+    assert_eq!(
+        get_hull(&[point(1.0, 1.0), point(1.0, 1.0)]),
+        vec![point(1.0, 1.0)]
+    );
+    assert_eq!(
+        get_hull(&[point(10.0, 10.0), point(5.0, 5.0), point(0.0, 0.0)]),
+        vec![point(0.0, 0.0), point(10.0, 10.0)]
+    );
+}
+
+#[test]
+fn is_point_in_polygon_checks_the_inside() {
+    let square = [
+        point(0.0, 0.0),
+        point(10.0, 0.0),
+        point(10.0, 10.0),
+        point(0.0, 10.0),
+    ];
+
+    assert!(is_point_in_polygon(point(5.0, 5.0), &square));
+    assert!(!is_point_in_polygon(point(-1.0, 5.0), &square));
+    assert!(!is_point_in_polygon(point(11.0, 5.0), &square));
+    assert!(!is_point_in_polygon(point(5.0, 10.5), &square));
+    assert!(!is_point_in_polygon(point(5.0, 5.0), &[]));
+}
+
+#[test]
+fn is_point_in_polygon_claims_shared_edges_once() {
+    // Two squares sharing the edge at `x = 10`: a point on it belongs to exactly one of them.
+    let left = [
+        point(0.0, 0.0),
+        point(10.0, 0.0),
+        point(10.0, 10.0),
+        point(0.0, 10.0),
+    ];
+    let right = [
+        point(10.0, 0.0),
+        point(20.0, 0.0),
+        point(20.0, 10.0),
+        point(10.0, 10.0),
+    ];
+    let on_edge = point(10.0, 5.0);
+
+    assert_ne!(
+        is_point_in_polygon(on_edge, &left),
+        is_point_in_polygon(on_edge, &right)
+    );
+}
+
+#[test]
+fn grace_area_keeps_the_submenu_while_moving_towards_it() {
+    let grace_area = GraceArea::default();
+    let intent = GraceIntent {
+        area: grace_area
+            .grace_area(point(90.0, 50.0), Side::Right, CONTENT)
+            .unwrap(),
+        side: Side::Right,
+    };
+
+    let previous = Some(sample(92.0, 50.0, 0.0));
+    assert!(grace_area.is_intending(&intent, previous, sample(95.0, 52.0, 16.0)));
+    // Moving back towards the trigger.
+    assert!(!grace_area.is_intending(&intent, previous, sample(90.0, 50.0, 16.0)));
+    // Leaving the area.
+    assert!(!grace_area.is_intending(&intent, previous, sample(95.0, 150.0, 16.0)));
+}
+
+#[test]
+fn safe_triangle_expires_when_the_pointer_rests() {
+    let safe_triangle = SafeTriangle::default();
+    let intent = GraceIntent {
+        area: safe_triangle
+            .grace_area(point(90.0, 50.0), Side::Right, CONTENT)
+            .unwrap(),
+        side: Side::Right,
+    };
+
+    let previous = Some(sample(92.0, 50.0, 0.0));
+    assert!(safe_triangle.is_intending(&intent, previous, sample(95.0, 50.0, 16.0)));
+    // Same distance, but after a pause: slower than `min_velocity`.
+    assert!(!safe_triangle.is_intending(&intent, previous, sample(95.0, 50.0, 500.0)));
+    // The first sample has nothing to measure against.
+    assert!(safe_triangle.is_intending(&intent, None, sample(95.0, 50.0, 500.0)));
+}
+
+#[test]
+fn safe_triangle_mirrors_for_left_submenus() {
+    let safe_triangle = SafeTriangle::default();
+    let content = Rect {
+        left: 0.0,
+        top: 0.0,
+        right: 100.0,
+        bottom: 100.0,
+    };
+    let intent = GraceIntent {
+        area: safe_triangle
+            .grace_area(point(110.0, 50.0), Side::Left, content)
+            .unwrap(),
+        side: Side::Left,
+    };
+
+    let previous = Some(sample(108.0, 50.0, 0.0));
+    assert!(safe_triangle.is_intending(&intent, previous, sample(105.0, 50.0, 16.0)));
+    assert!(!safe_triangle.is_intending(&intent, previous, sample(109.0, 50.0, 16.0)));
+}
+
+#[test]
+fn hover_delay_has_no_grace() {
+    let hover_delay = HoverDelay::default();
+
+    assert_eq!(hover_delay.grace_timeout(), Duration::ZERO);
+    assert!(hover_delay
+        .grace_area(point(90.0, 50.0), Side::Right, CONTENT)
+        .is_none());
+}
@@ -0,0 +1,193 @@
+use js_sys::wasm_bindgen::{JsCast, JsValue};
+use leptos::{mount::mount_to, prelude::*};
+use radix_leptos_menu::*;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Wraps `addEventListener`/`removeEventListener` so the listeners currently registered on
+/// `document` can be counted.
+const TRACK_DOCUMENT_LISTENERS: &str = r#"
+(() => {
+    if (window.__documentListenerCount) {
+        return;
+    }
+    const entries = [];
+    const capture = (options) =>
+        typeof options === "boolean" ? options : Boolean(options && options.capture);
+    const find = (target, type, listener, options) =>
+        entries.findIndex(
+            (entry) =>
+                entry.target === target &&
+                entry.type === type &&
+                entry.listener === listener &&
+                entry.capture === capture(options),
+        );
+    const add = EventTarget.prototype.addEventListener;
+    const remove = EventTarget.prototype.removeEventListener;
+    EventTarget.prototype.addEventListener = function (type, listener, options) {
+        if (find(this, type, listener, options) === -1) {
+            entries.push({ target: this, type, listener, capture: capture(options) });
+        }
+        return add.call(this, type, listener, options);
+    };
+    EventTarget.prototype.removeEventListener = function (type, listener, options) {
+        const index = find(this, type, listener, options);
+        if (index !== -1) {
+            entries.splice(index, 1);
+        }
+        return remove.call(this, type, listener, options);
+    };
+    window.__documentListenerCount = () =>
+        entries.filter((entry) => entry.target === document).length;
+})();
+"#;
+
+/// Wraps `setTimeout`/`clearTimeout` to count timers cleared before they fired. Their callbacks are
+/// abandoned, and leak if they were passed as one-shot closures.
+const TRACK_ABANDONED_TIMERS: &str = r#"
+(() => {
+    if (window.__abandonedTimerCount) {
+        return;
+    }
+    const pending = new Set();
+    let abandoned = 0;
+    const set = window.setTimeout;
+    const clear = window.clearTimeout;
+    window.setTimeout = function (callback, ...args) {
+        const handle = set.call(
+            window,
+            function (...callbackArgs) {
+                pending.delete(handle);
+                return typeof callback === "function" ? callback.apply(this, callbackArgs) : undefined;
+            },
+            ...args,
+        );
+        pending.add(handle);
+        return handle;
+    };
+    window.clearTimeout = function (handle) {
+        if (pending.delete(handle)) {
+            abandoned += 1;
+        }
+        return clear.call(window, handle);
+    };
+    window.__abandonedTimerCount = () => abandoned;
+})();
+"#;
+
+async fn tick() {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        web_sys::window()
+            .unwrap()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, 0)
+            .unwrap();
+    });
+    JsFuture::from(promise).await.unwrap();
+}
+
+fn call_counter(name: &str) -> u32 {
+    let count: js_sys::Function =
+        js_sys::Reflect::get(&web_sys::window().unwrap(), &JsValue::from_str(name))
+            .unwrap()
+            .unchecked_into();
+    count.call0(&JsValue::NULL).unwrap().as_f64().unwrap() as u32
+}
+
+fn document_listener_count() -> u32 {
+    call_counter("__documentListenerCount")
+}
+
+fn abandoned_timer_count() -> u32 {
+    call_counter("__abandonedTimerCount")
+}
+
+fn setup_test() -> web_sys::HtmlElement {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let test_container = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&test_container).unwrap();
+    test_container.unchecked_into()
+}
+
+async fn mount_and_unmount_menu() {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let test_container = setup_test();
+
+    let dispose = mount_to(test_container.clone(), || {
+        view! {
+            <Menu open=true>
+                <MenuAnchor>
+                    <button>"Trigger"</button>
+                </MenuAnchor>
+                <MenuContent>
+                    <MenuItem>"Item"</MenuItem>
+                    <MenuSub default_open=true>
+                        <MenuSubTrigger>"More"</MenuSubTrigger>
+                        <MenuSubContent>
+                            <MenuItem>"Sub item"</MenuItem>
+                        </MenuSubContent>
+                    </MenuSub>
+                    <MenuSub>
+                        <MenuSubTrigger>"Closed"</MenuSubTrigger>
+                        <MenuSubContent>
+                            <MenuItem>"Closed sub item"</MenuItem>
+                        </MenuSubContent>
+                    </MenuSub>
+                </MenuContent>
+            </Menu>
+        }
+    });
+    tick().await;
+
+    // Switch to keyboard modality through the shared input modality listener, which must be removed
+    // again once the last menu unmounts.
+    let init = web_sys::KeyboardEventInit::new();
+    init.set_key("ArrowDown");
+    let key_down = web_sys::KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+    document.dispatch_event(&key_down).unwrap();
+
+    // Moving the mouse over a closed sub trigger keeps a single open timer pending rather than
+    // restarting it on every move.
+    let abandoned_timers = abandoned_timer_count();
+    if let Some(sub_trigger) = test_container
+        .query_selector("[aria-haspopup=menu][data-state=closed]")
+        .unwrap()
+    {
+        for x in 0..5 {
+            let init = web_sys::PointerEventInit::new();
+            init.set_bubbles(true);
+            init.set_pointer_type("mouse");
+            init.set_client_x(x);
+            let pointer_move =
+                web_sys::PointerEvent::new_with_event_init_dict("pointermove", &init).unwrap();
+            sub_trigger.dispatch_event(&pointer_move).unwrap();
+        }
+    }
+    assert_eq!(abandoned_timer_count(), abandoned_timers);
+
+    // Selecting an item registers a listener for the select event.
+    if let Some(item) = test_container.query_selector("[role=menuitem]").unwrap() {
+        item.unchecked_into::<web_sys::HtmlElement>().click();
+    }
+    tick().await;
+
+    drop(dispose);
+    tick().await;
+    test_container.remove();
+}
+
+#[wasm_bindgen_test]
+async fn test_listener_count_stays_bounded() {
+    js_sys::eval(TRACK_DOCUMENT_LISTENERS).unwrap();
+    js_sys::eval(TRACK_ABANDONED_TIMERS).unwrap();
+
+    // The first mount may register listeners that live for the whole app, e.g. delegated events.
+    mount_and_unmount_menu().await;
+    let baseline = document_listener_count();
+
+    for _ in 0..10 {
+        mount_and_unmount_menu().await;
+        assert_eq!(document_listener_count(), baseline);
+    }
+}
@@ -0,0 +1,9 @@
+//! Leptos port of [Radix Menubar](https://www.radix-ui.com/primitives/docs/components/menubar).
+//!
+//! A visually persistent menu common in desktop applications that provides quick access to a consistent set of commands.
+//!
+//! See [`@radix-ui/react-menubar`](https://www.npmjs.com/package/@radix-ui/react-menubar) for the original package.
+
+mod menubar;
+
+pub use menubar::*;
@@ -0,0 +1,576 @@
+use std::marker::PhantomData;
+
+use leptos::{ev, html, prelude::*};
+use leptos::wasm_bindgen::JsCast;
+use leptos_node_ref::AnyNodeRef;
+use radix_leptos_collection::{primitive as Collection, use_collection};
+use radix_leptos_compose_refs::use_composed_refs;
+use radix_leptos_context::create_context;
+use radix_leptos_direction::use_direction;
+use radix_leptos_id::use_id;
+use radix_leptos_menu::{primitive as MenuPrimitive, HorizontalNavigation};
+use radix_leptos_popper::Align;
+use radix_leptos_primitive::{compose_callbacks, Primitive};
+use radix_leptos_roving_focus::{Orientation, RovingFocusGroup, RovingFocusGroupItem};
+use radix_leptos_use_controllable_state::{use_controllable_state, UseControllableStateParams};
+pub use radix_leptos_checkbox::CheckedState;
+pub use radix_leptos_direction::Direction;
+pub use radix_leptos_menu::{
+    GraceArea, HoverDelay, HoverIntent, HoverIntentProvider, HoverIntentStrategy, SafeTriangle,
+};
+
+/* -------------------------------------------------------------------------------------------------
+ * Menubar
+ * -----------------------------------------------------------------------------------------------*/
+
+const MENUBAR_NAME: &str = "Menubar";
+
+#[derive(Clone, Debug)]
+struct ItemData {
+    value: String,
+    disabled: bool,
+}
+
+const ITEM_DATA_PHANTOM: PhantomData<ItemData> = PhantomData;
+
+#[derive(Clone, Copy)]
+struct MenubarContextValue {
+    /// The value of the open menu, empty while all menus are closed.
+    value: Signal<String>,
+    dir: Signal<Direction>,
+    r#loop: Signal<bool>,
+    on_menu_open: Callback<String>,
+    on_menu_close: Callback<()>,
+    on_menu_toggle: Callback<String>,
+}
+
+create_context!(
+    context_type: MenubarContextValue,
+    provider: MenubarProvider,
+    hook: use_menubar_context,
+    root: MENUBAR_NAME
+);
+
+#[component]
+#[allow(non_snake_case)]
+pub fn Menubar(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] value: MaybeProp<String>,
+    #[prop(optional, into)] default_value: MaybeProp<String>,
+    #[prop(optional, into)] on_value_change: Option<Callback<String>>,
+    #[prop(optional, into)] dir: MaybeProp<Direction>,
+    /// Whether keyboard navigation between triggers should loop around. Defaults to `true`.
+    #[prop(optional, into)]
+    r#loop: MaybeProp<bool>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let direction = use_direction(dir);
+    let r#loop = Signal::derive(move || r#loop.get().unwrap_or(true));
+    let (value, set_value) = use_controllable_state(UseControllableStateParams {
+        prop: value,
+        default_prop: default_value,
+        on_change: on_value_change,
+    });
+
+    // We need to manage tab stop id manually as `RovingFocusGroup` updates the stop
+    // based on focus, and in some situations our triggers won't ever be given focus
+    // (e.g. click to open and then outside to close).
+    let current_tab_stop_id = RwSignal::new(None::<String>);
+
+    let context_value = MenubarContextValue {
+        value,
+        dir: direction,
+        r#loop,
+        on_menu_open: Callback::new(move |value: String| {
+            current_tab_stop_id.set(Some(value.clone()));
+            set_value.run(value);
+        }),
+        on_menu_close: Callback::new(move |_| set_value.run(String::new())),
+        on_menu_toggle: Callback::new(move |next_value: String| {
+            let is_open = value.get_untracked() == next_value;
+            current_tab_stop_id.set(Some(next_value.clone()));
+            set_value.run(if is_open { String::new() } else { next_value });
+        }),
+    };
+
+    view! {
+        <MenubarProvider value=context_value>
+            <Collection::Provider item_data_type=ITEM_DATA_PHANTOM>
+                <MenubarKeyboardShortcuts current_tab_stop_id=current_tab_stop_id />
+                <Collection::Slot item_data_type=ITEM_DATA_PHANTOM>
+                    <RovingFocusGroup
+                        as_child=true
+                        orientation=Orientation::Horizontal
+                        r#loop=r#loop
+                        dir=direction
+                        current_tab_stop_id=current_tab_stop_id
+                        on_current_tab_stop_id_change=Callback::new(move |value| current_tab_stop_id.set(value))
+                    >
+                        <Primitive
+                            element=html::div
+                            as_child=as_child
+                            node_ref=node_ref
+                            attr:role="menubar"
+                            attr:dir=move || direction.get().to_string()
+                        >
+                            {children.with_value(|children| children())}
+                        </Primitive>
+                    </RovingFocusGroup>
+                </Collection::Slot>
+            </Collection::Provider>
+        </MenubarProvider>
+    }
+}
+
+/// Moves focus to the menubar when `F10` is pressed, or `Alt` is pressed and released on its own.
+#[component]
+#[allow(non_snake_case)]
+fn MenubarKeyboardShortcuts(current_tab_stop_id: RwSignal<Option<String>>) -> impl IntoView {
+    let context = use_menubar_context(MENUBAR_NAME);
+    let get_items = use_collection::<ItemData>();
+    let is_alt_pressed_alone = StoredValue::new(false);
+
+    let focus_menubar = move || {
+        if !context.value.get_untracked().is_empty() {
+            return;
+        }
+        let items = get_items()
+            .into_iter()
+            .filter(|item| !item.data.disabled)
+            .collect::<Vec<_>>();
+        let current_tab_stop_id = current_tab_stop_id.get_untracked();
+        let item = items
+            .iter()
+            .find(|item| Some(&item.data.value) == current_tab_stop_id.as_ref())
+            .or(items.first());
+        if let Some(trigger) = item.and_then(|item| item.r#ref.get_untracked()) {
+            let _ = trigger.unchecked_into::<web_sys::HtmlElement>().focus();
+        }
+    };
+
+    let key_down = window_event_listener(ev::keydown, move |event| {
+        is_alt_pressed_alone.set_value(event.key() == "Alt" && !event.repeat());
+        if event.key() == "F10" {
+            focus_menubar();
+            event.prevent_default();
+        }
+    });
+    let key_up = window_event_listener(ev::keyup, move |event| {
+        if event.key() == "Alt" && is_alt_pressed_alone.get_value() {
+            focus_menubar();
+            event.prevent_default();
+        }
+        is_alt_pressed_alone.set_value(false);
+    });
+
+    on_cleanup(move || {
+        key_down.remove();
+        key_up.remove();
+    });
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenubarMenu
+ * -----------------------------------------------------------------------------------------------*/
+
+const MENUBAR_MENU_NAME: &str = "MenubarMenu";
+
+#[derive(Clone, Copy)]
+struct MenubarMenuContextValue {
+    value: Signal<String>,
+    trigger_id: Signal<String>,
+    trigger_ref: AnyNodeRef,
+    content_id: Signal<String>,
+}
+
+create_context!(
+    context_type: MenubarMenuContextValue,
+    provider: MenubarMenuProvider,
+    hook: use_menubar_menu_context,
+    root: MENUBAR_MENU_NAME
+);
+
+#[component]
+#[allow(non_snake_case)]
+pub fn MenubarMenu(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// Identifies the menu within the menubar. Defaults to a generated id.
+    #[prop(optional, into)]
+    value: MaybeProp<String>,
+    #[prop(optional, into)] hover_intent: Option<HoverIntentStrategy>,
+) -> impl IntoView {
+    let context = use_menubar_context(MENUBAR_MENU_NAME);
+    let get_items = use_collection::<ItemData>();
+    let auto_value = use_id();
+    let value = Signal::derive(move || value.get().unwrap_or_else(|| auto_value.get()));
+    let open = Signal::derive(move || {
+        let current = context.value.get();
+        !current.is_empty() && current == value.get()
+    });
+
+    let handle_horizontal_navigation = Callback::new(move |navigation: HorizontalNavigation| {
+        let mut values = get_items()
+            .into_iter()
+            .filter(|item| !item.data.disabled)
+            .map(|item| item.data.value)
+            .collect::<Vec<_>>();
+        if navigation == HorizontalNavigation::Previous {
+            values.reverse();
+        }
+        let current = value.get_untracked();
+        let Some(index) = values.iter().position(|value| *value == current) else {
+            return;
+        };
+        let next_value = values
+            .get(index + 1)
+            .or_else(|| context.r#loop.get_untracked().then(|| values.first()).flatten())
+            .filter(|next_value| **next_value != current);
+        if let Some(next_value) = next_value {
+            context.on_menu_open.run(next_value.clone());
+        }
+    });
+
+    let context_value = MenubarMenuContextValue {
+        value,
+        trigger_id: use_id().into(),
+        trigger_ref: AnyNodeRef::new(),
+        content_id: use_id().into(),
+    };
+
+    view! {
+        <MenubarMenuProvider value=context_value>
+            <MenuPrimitive::Root
+                children=children
+                open=open
+                on_open_change=Callback::new(move |open: bool| {
+                    // Menu only calls `on_open_change` when dismissing.
+                    if !open {
+                        context.on_menu_close.run(());
+                    }
+                })
+                modal=false
+                dir=context.dir
+                hover_intent=hover_intent
+                on_horizontal_navigation=handle_horizontal_navigation
+            />
+        </MenubarMenuProvider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenubarTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+const MENUBAR_TRIGGER_NAME: &str = "MenubarTrigger";
+
+#[component]
+#[allow(non_snake_case)]
+pub fn MenubarTrigger(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] disabled: MaybeProp<bool>,
+    #[prop(optional, into)] on_pointer_down: Option<Callback<ev::PointerEvent>>,
+    #[prop(optional, into)] on_pointer_enter: Option<Callback<ev::PointerEvent>>,
+    #[prop(optional, into)] on_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(optional, into)] on_focus: Option<Callback<ev::FocusEvent>>,
+    #[prop(optional, into)] on_blur: Option<Callback<ev::FocusEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_menubar_context(MENUBAR_TRIGGER_NAME);
+    let menu_context = use_menubar_menu_context(MENUBAR_TRIGGER_NAME);
+    let disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let open = Signal::derive(move || context.value.get() == menu_context.value.get());
+    let is_focused = RwSignal::new(false);
+
+    let item_data = Signal::derive(move || ItemData {
+        value: menu_context.value.get(),
+        disabled: disabled.get(),
+    });
+
+    view! {
+        <Collection::ItemSlot item_data_type=ITEM_DATA_PHANTOM item_data=item_data>
+            <RovingFocusGroupItem
+                as_child=true
+                focusable=Signal::derive(move || !disabled.get())
+                tab_stop_id=menu_context.value
+            >
+                <MenuPrimitive::Anchor as_child=true>
+                    <Primitive
+                        element=html::button
+                        as_child=as_child
+                        node_ref=use_composed_refs((node_ref, menu_context.trigger_ref))
+                        attr:id=menu_context.trigger_id
+                        attr:r#type="button"
+                        attr:role="menuitem"
+                        attr:aria-haspopup="menu"
+                        attr:aria-expanded=move || open.get()
+                        attr:aria-controls=move || open.get().then(|| menu_context.content_id.get())
+                        attr:data-highlighted=move || is_focused.get().then_some("")
+                        attr:data-state=move || if open.get() { "open" } else { "closed" }
+                        attr:data-disabled=move || disabled.get().then_some("")
+                        attr:disabled=move || disabled.get()
+                        on:pointerdown=compose_callbacks(
+                            on_pointer_down,
+                            Some(Callback::new(move |event: ev::PointerEvent| {
+                                // Only call handler if it's the left button (mousedown gets triggered by all mouse buttons)
+                                // but not when the control key is pressed (avoiding MacOS right click).
+                                if !disabled.get_untracked() && event.button() == 0 && !event.ctrl_key() {
+                                    context.on_menu_toggle.run(menu_context.value.get_untracked());
+                                    // Prevent trigger focusing when opening.
+                                    // This allows the content to be given focus without competition.
+                                    if !open.get_untracked() {
+                                        event.prevent_default();
+                                    }
+                                }
+                            })),
+                            None,
+                        )
+                        on:pointerenter=compose_callbacks(
+                            on_pointer_enter,
+                            Some(Callback::new(move |_: ev::PointerEvent| {
+                                let is_menubar_open = !context.value.get_untracked().is_empty();
+                                if is_menubar_open && !open.get_untracked() && !disabled.get_untracked() {
+                                    context.on_menu_open.run(menu_context.value.get_untracked());
+                                    if let Some(trigger) = menu_context.trigger_ref.get_untracked() {
+                                        let _ = trigger.unchecked_into::<web_sys::HtmlElement>().focus();
+                                    }
+                                }
+                            })),
+                            None,
+                        )
+                        on:keydown=compose_callbacks(
+                            on_key_down,
+                            Some(Callback::new(move |event: ev::KeyboardEvent| {
+                                if disabled.get_untracked() {
+                                    return;
+                                }
+                                let key = event.key();
+                                if ["Enter", " "].contains(&key.as_str()) {
+                                    context.on_menu_toggle.run(menu_context.value.get_untracked());
+                                }
+                                if key == "ArrowDown" {
+                                    context.on_menu_open.run(menu_context.value.get_untracked());
+                                }
+                                // Prevent keydown from scrolling window / first focused item to execute
+                                // that keydown (inadvertently closing the menu).
+                                if ["Enter", " ", "ArrowDown"].contains(&key.as_str()) {
+                                    event.prevent_default();
+                                }
+                            })),
+                            None,
+                        )
+                        on:focus=compose_callbacks(
+                            on_focus,
+                            Some(Callback::new(move |_| is_focused.set(true))),
+                            None,
+                        )
+                        on:blur=compose_callbacks(
+                            on_blur,
+                            Some(Callback::new(move |_| is_focused.set(false))),
+                            None,
+                        )
+                    >
+                        {children.with_value(|children| children())}
+                    </Primitive>
+                </MenuPrimitive::Anchor>
+            </RovingFocusGroupItem>
+        </Collection::ItemSlot>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenubarPortal
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::Portal as MenubarPortal;
+
+/* -------------------------------------------------------------------------------------------------
+ * MenubarContent
+ * -----------------------------------------------------------------------------------------------*/
+
+const MENUBAR_CONTENT_NAME: &str = "MenubarContent";
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn MenubarContent(
+    children: ChildrenFn, // NOTE: No passthrough needed
+    #[prop(optional)] node_ref: AnyNodeRef,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    let context = use_menubar_context(MENUBAR_CONTENT_NAME);
+    let menu_context = use_menubar_menu_context(MENUBAR_CONTENT_NAME);
+    let get_items = use_collection::<ItemData>();
+
+    let has_interacted_outside = RwSignal::new(false);
+
+    let on_close_auto_focus = move |event: ev::Event| {
+        let is_menubar_open = !context.value.get_untracked().is_empty();
+        if !is_menubar_open && !has_interacted_outside.get_untracked() {
+            if let Some(trigger) = menu_context.trigger_ref.get_untracked() {
+                let _ = trigger.unchecked_into::<web_sys::HtmlElement>().focus();
+            }
+        }
+        has_interacted_outside.set(false);
+        // Always prevent auto focus because we either focus manually or want user agent focus.
+        event.prevent_default();
+    };
+
+    let on_interact_outside = move |event: ev::Event| {
+        let target = event
+            .unchecked_ref::<web_sys::CustomEvent>()
+            .detail()
+            .unchecked_into::<web_sys::Event>()
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+        let is_menubar_trigger = get_items().iter().any(|item| {
+            item.r#ref
+                .get_untracked()
+                .is_some_and(|trigger| trigger.contains(target.as_ref()))
+        });
+        // Triggers switch and toggle menus themselves, so interacting with one doesn't dismiss.
+        if is_menubar_trigger {
+            event.prevent_default();
+        } else {
+            has_interacted_outside.set(true);
+        }
+    };
+
+    view! {
+        <MenuPrimitive::Content
+            node_ref=node_ref
+            align=Align::Start
+            attr:aria-labelledby=menu_context.trigger_id
+            {..}
+            id=menu_context.content_id
+            data-radix-menubar-content=""
+            on:closeautofocus=on_close_auto_focus
+            on:interactoutside=on_interact_outside
+            style:--radix-menubar-content-transform-origin="var(--radix-popper-transform-origin)"
+            style:--radix-menubar-content-available-width="var(--radix-popper-available-width)"
+            style:--radix-menubar-content-available-height="var(--radix-popper-available-height)"
+            style:--radix-menubar-trigger-width="var(--radix-popper-anchor-width)"
+            style:--radix-menubar-trigger-height="var(--radix-popper-anchor-height)"
+        >
+            {children.with_value(|children| children())}
+        </MenuPrimitive::Content>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * MenubarGroup
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::Group as MenubarGroup;
+
+/* -------------------------------------------------------------------------------------------------
+ * MenubarLabel
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::Label as MenubarLabel;
+
+/* -------------------------------------------------------------------------------------------------
+ * MenubarItem
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::Item as MenubarItem;
+
+/* -------------------------------------------------------------------------------------------------
+ * MenubarCheckboxItem
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::CheckboxItem as MenubarCheckboxItem;
+
+/* -------------------------------------------------------------------------------------------------
+ * MenubarRadioGroup
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::RadioGroup as MenubarRadioGroup;
+
+/* -------------------------------------------------------------------------------------------------
+ * MenubarRadioItem
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::RadioItem as MenubarRadioItem;
+
+/* -------------------------------------------------------------------------------------------------
+ * MenubarItemIndicator
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::ItemIndicator as MenubarItemIndicator;
+
+/* -------------------------------------------------------------------------------------------------
+ * MenubarSeparator
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::Separator as MenubarSeparator;
+
+/* -------------------------------------------------------------------------------------------------
+ * MenubarArrow
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::Arrow as MenubarArrow;
+
+/* -------------------------------------------------------------------------------------------------
+ * MenubarSub
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::Sub as MenubarSub;
+
+/* -------------------------------------------------------------------------------------------------
+ * MenubarSubTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+pub use MenuPrimitive::SubTrigger as MenubarSubTrigger;
+
+/* -------------------------------------------------------------------------------------------------
+ * MenubarSubContent
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+#[allow(non_snake_case)]
+pub fn MenubarSubContent(
+    children: ChildrenFn, // NOTE: No passthrough needed
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+) -> impl IntoView {
+    view! {
+        <MenuPrimitive::SubContent
+            children=children
+            node_ref=node_ref
+            {..}
+            data-radix-menubar-content=""
+            style:--radix-menubar-content-transform-origin="var(--radix-popper-transform-origin)"
+            style:--radix-menubar-content-available-width="var(--radix-popper-available-width)"
+            style:--radix-menubar-content-available-height="var(--radix-popper-available-height)"
+            style:--radix-menubar-trigger-width="var(--radix-popper-anchor-width)"
+            style:--radix-menubar-trigger-height="var(--radix-popper-anchor-height)"
+        />
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub mod primitive {
+    pub use super::*;
+    pub use Menubar as Root;
+    pub use MenubarArrow as Arrow;
+    pub use MenubarCheckboxItem as CheckboxItem;
+    pub use MenubarContent as Content;
+    pub use MenubarGroup as Group;
+    pub use MenubarItem as Item;
+    pub use MenubarItemIndicator as ItemIndicator;
+    pub use MenubarLabel as Label;
+    pub use MenubarMenu as Menu;
+    pub use MenubarPortal as Portal;
+    pub use MenubarRadioGroup as RadioGroup;
+    pub use MenubarRadioItem as RadioItem;
+    pub use MenubarSeparator as Separator;
+    pub use MenubarSub as Sub;
+    pub use MenubarSubContent as SubContent;
+    pub use MenubarSubTrigger as SubTrigger;
+    pub use MenubarTrigger as Trigger;
+}
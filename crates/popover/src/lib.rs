@@ -0,0 +1,9 @@
+//! Leptos port of [Radix Popover](https://www.radix-ui.com/primitives/docs/components/popover).
+//!
+//! Displays rich content in a portal, triggered by a button.
+//!
+//! See [`@radix-ui/react-popover`](https://www.npmjs.com/package/@radix-ui/react-popover) for the original package.
+
+mod popover;
+
+pub use popover::*;
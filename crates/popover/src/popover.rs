@@ -0,0 +1,611 @@
+use leptos::{context::Provider, ev, html, prelude::*};
+use leptos::wasm_bindgen::JsCast;
+use leptos_node_ref::AnyNodeRef;
+use leptos_remove_scroll::RemoveScroll;
+use leptos_typed_fallback_show::TypedFallbackShow;
+use radix_leptos_compose_refs::use_composed_refs;
+use radix_leptos_context::create_context;
+use radix_leptos_dismissable_layer::{
+    DismissableLayer, FocusOutsideEvent, InteractOutsideEvent, PointerDownOutsideEvent,
+};
+use radix_leptos_focus_scope::FocusScope;
+use radix_leptos_id::use_id;
+use radix_leptos_popper::{Popper, PopperAnchor, PopperArrow, PopperContent};
+use radix_leptos_portal::Portal as PortalPrimitive;
+use radix_leptos_presence::Presence;
+use radix_leptos_primitive::{compose_callbacks, Primitive};
+use radix_leptos_use_controllable_state::{use_controllable_state, UseControllableStateParams};
+pub use radix_leptos_popper::{Align, Padding, Side};
+
+/* -------------------------------------------------------------------------------------------------
+ * Popover
+ * -----------------------------------------------------------------------------------------------*/
+
+const POPOVER_NAME: &str = "Popover";
+
+#[derive(Clone, Copy)]
+struct PopoverContextValue {
+    trigger_ref: AnyNodeRef,
+    content_id: Signal<String>,
+    open: Signal<bool>,
+    on_open_change: Callback<bool>,
+    on_open_toggle: Callback<()>,
+    has_custom_anchor: RwSignal<bool>,
+    modal: Signal<bool>,
+}
+
+create_context!(
+    context_type: PopoverContextValue,
+    provider: PopoverProvider,
+    hook: use_popover_context,
+    root: POPOVER_NAME
+);
+
+#[component]
+#[allow(non_snake_case)]
+pub fn Popover(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] open: MaybeProp<bool>,
+    #[prop(optional, into)] default_open: MaybeProp<bool>,
+    #[prop(optional, into)] on_open_change: Option<Callback<bool>>,
+    /// When `true`, focus is trapped, outside pointer events are blocked and scrolling is locked
+    /// while open. Defaults to `false`.
+    #[prop(optional, into)]
+    modal: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+
+    let (open, set_open) = use_controllable_state(UseControllableStateParams {
+        prop: open,
+        default_prop: default_open,
+        on_change: on_open_change,
+    });
+
+    let context_value = PopoverContextValue {
+        trigger_ref: AnyNodeRef::new(),
+        content_id: use_id().into(),
+        open,
+        on_open_change: set_open,
+        on_open_toggle: Callback::new(move |_| set_open.run(!open.get_untracked())),
+        has_custom_anchor: RwSignal::new(false),
+        modal: Signal::derive(move || modal.get().unwrap_or(false)),
+    };
+
+    view! {
+        <PopoverProvider value=context_value>
+            <Popper>{children.with_value(|children| children())}</Popper>
+        </PopoverProvider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PopoverAnchor
+ * -----------------------------------------------------------------------------------------------*/
+
+const POPOVER_ANCHOR_NAME: &str = "PopoverAnchor";
+
+/// Positions the content against this element instead of the trigger.
+#[component]
+#[allow(non_snake_case)]
+pub fn PopoverAnchor(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_popover_context(POPOVER_ANCHOR_NAME);
+
+    context.has_custom_anchor.set(true);
+    on_cleanup(move || {
+        context.has_custom_anchor.try_set(false);
+    });
+
+    view! {
+        <PopperAnchor as_child=as_child node_ref=node_ref>
+            {children.with_value(|children| children())}
+        </PopperAnchor>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PopoverTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+const POPOVER_TRIGGER_NAME: &str = "PopoverTrigger";
+
+#[component]
+#[allow(non_snake_case)]
+pub fn PopoverTrigger(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_popover_context(POPOVER_TRIGGER_NAME);
+    let composed_refs = use_composed_refs((node_ref, context.trigger_ref));
+
+    let trigger = move || {
+        view! {
+            <Primitive
+                element=html::button
+                as_child=as_child
+                node_ref=composed_refs
+                attr:r#type="button"
+                attr:aria-haspopup="dialog"
+                attr:aria-expanded=move || context.open.get().to_string()
+                attr:aria-controls=context.content_id
+                attr:data-state=move || get_state(context.open.get())
+                on:click=compose_callbacks(
+                    on_click,
+                    Some(Callback::new(move |_: ev::MouseEvent| context.on_open_toggle.run(()))),
+                    None,
+                )
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        }
+    };
+
+    // The trigger only anchors the content when there is no `PopoverAnchor`.
+    view! {
+        <TypedFallbackShow when=move || context.has_custom_anchor.get() fallback=move || {
+            view! { <PopperAnchor as_child=true>{trigger()}</PopperAnchor> }
+        }>
+            {trigger()}
+        </TypedFallbackShow>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PopoverPortal
+ * -----------------------------------------------------------------------------------------------*/
+
+const POPOVER_PORTAL_NAME: &str = "PopoverPortal";
+
+#[derive(Clone, Copy)]
+struct PopoverPortalContextValue {
+    force_mount: MaybeProp<bool>,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn PopoverPortal(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] container: AnyNodeRef,
+    /// Keep the portal mounted while closed, e.g. to control animations from outside.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_popover_context(POPOVER_PORTAL_NAME);
+    let container = StoredValue::new(container);
+
+    view! {
+        <Provider value=PopoverPortalContextValue { force_mount }>
+            <Presence present=Signal::derive(move || {
+                force_mount.get().unwrap_or(false) || context.open.get()
+            })>
+                <PortalPrimitive as_child=true mount=container.with_value(|container| container.get())>
+                    {children.with_value(|children| children())}
+                </PortalPrimitive>
+            </Presence>
+        </Provider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PopoverContent
+ * -----------------------------------------------------------------------------------------------*/
+
+const POPOVER_CONTENT_NAME: &str = "PopoverContent";
+
+/// Positioning props forwarded to `PopperContent`.
+#[derive(Clone, Copy, Default)]
+struct PopoverContentPlacement {
+    side: MaybeProp<Side>,
+    side_offset: MaybeProp<f64>,
+    align: MaybeProp<Align>,
+    align_offset: MaybeProp<f64>,
+    avoid_collisions: MaybeProp<bool>,
+    collision_padding: MaybeProp<Padding>,
+    hide_when_detached: MaybeProp<bool>,
+}
+
+/// Event handlers forwarded to `FocusScope` and `DismissableLayer`.
+#[derive(Clone, Copy, Default)]
+struct PopoverContentHandlers {
+    on_open_auto_focus: Option<Callback<ev::Event>>,
+    on_close_auto_focus: Option<Callback<ev::Event>>,
+    on_escape_key_down: Option<Callback<ev::KeyboardEvent>>,
+    on_pointer_down_outside: Option<Callback<PointerDownOutsideEvent>>,
+    on_focus_outside: Option<Callback<FocusOutsideEvent>>,
+    on_interact_outside: Option<Callback<InteractOutsideEvent>>,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn PopoverContent(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// Keep the content mounted while closed, e.g. to control animations from outside.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+    /// The preferred side of the anchor to render against. Defaults to `Bottom`.
+    #[prop(optional, into)]
+    side: MaybeProp<Side>,
+    #[prop(optional, into)] side_offset: MaybeProp<f64>,
+    /// The preferred alignment against the anchor. Defaults to `Center`.
+    #[prop(optional, into)]
+    align: MaybeProp<Align>,
+    #[prop(optional, into)] align_offset: MaybeProp<f64>,
+    #[prop(optional, into)] avoid_collisions: MaybeProp<bool>,
+    #[prop(optional, into)] collision_padding: MaybeProp<Padding>,
+    #[prop(optional, into)] hide_when_detached: MaybeProp<bool>,
+    /// Event handler called when focus moves into the content on open. Can be prevented.
+    #[prop(optional, into)]
+    on_open_auto_focus: Option<Callback<ev::Event>>,
+    /// Event handler called when focus moves back to the trigger on close. Can be prevented.
+    #[prop(optional, into)]
+    on_close_auto_focus: Option<Callback<ev::Event>>,
+    #[prop(optional, into)] on_escape_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(optional, into)] on_pointer_down_outside: Option<Callback<PointerDownOutsideEvent>>,
+    #[prop(optional, into)] on_focus_outside: Option<Callback<FocusOutsideEvent>>,
+    #[prop(optional, into)] on_interact_outside: Option<Callback<InteractOutsideEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_popover_context(POPOVER_CONTENT_NAME);
+    let portal_context = use_context::<PopoverPortalContextValue>();
+    let force_mount = Signal::derive(move || {
+        force_mount
+            .get()
+            .or_else(|| portal_context.and_then(|portal_context| portal_context.force_mount.get()))
+            .unwrap_or(false)
+    });
+    let placement = PopoverContentPlacement {
+        side,
+        side_offset,
+        align,
+        align_offset,
+        avoid_collisions,
+        collision_padding,
+        hide_when_detached,
+    };
+    let handlers = PopoverContentHandlers {
+        on_open_auto_focus,
+        on_close_auto_focus,
+        on_escape_key_down,
+        on_pointer_down_outside,
+        on_focus_outside,
+        on_interact_outside,
+    };
+
+    view! {
+        <Presence present=Signal::derive(move || force_mount.get() || context.open.get())>
+            <TypedFallbackShow
+                when=move || context.modal.get()
+                fallback=move || {
+                    view! {
+                        <PopoverContentNonModal
+                            placement=placement
+                            handlers=handlers
+                            as_child=as_child
+                            node_ref=node_ref
+                        >
+                            {children.with_value(|children| children())}
+                        </PopoverContentNonModal>
+                    }
+                }
+            >
+                <PopoverContentModal
+                    placement=placement
+                    handlers=handlers
+                    as_child=as_child
+                    node_ref=node_ref
+                >
+                    {children.with_value(|children| children())}
+                </PopoverContentModal>
+            </TypedFallbackShow>
+        </Presence>
+    }
+}
+
+/* ---------------------------------------------------------------------------------------------- */
+
+#[component]
+#[allow(non_snake_case)]
+fn PopoverContentModal<C: IntoView + 'static>(
+    placement: PopoverContentPlacement,
+    handlers: PopoverContentHandlers,
+    as_child: MaybeProp<bool>,
+    node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_popover_context(POPOVER_CONTENT_NAME);
+    let is_right_click_outside = StoredValue::new(false);
+
+    let handlers = PopoverContentHandlers {
+        on_close_auto_focus: Some(Callback::new(compose_callbacks(
+            handlers.on_close_auto_focus,
+            Some(Callback::new(move |event: ev::Event| {
+                event.prevent_default();
+                if !is_right_click_outside.get_value() {
+                    focus_trigger(context.trigger_ref);
+                }
+            })),
+            None,
+        ))),
+        on_pointer_down_outside: Some(Callback::new(compose_callbacks(
+            handlers.on_pointer_down_outside,
+            Some(Callback::new(move |event: PointerDownOutsideEvent| {
+                let original_event = event.original_event();
+                let ctrl_left_click = original_event.button() == 0 && original_event.ctrl_key();
+                let is_right_click = original_event.button() == 2 || ctrl_left_click;
+                is_right_click_outside.set_value(is_right_click);
+                // If the event is a right-click, we shouldn't close because
+                // it is effectively as if we right-clicked the `Overlay`.
+                if is_right_click {
+                    event.prevent_default();
+                }
+            })),
+            Some(false),
+        ))),
+        // When focus is trapped, a `focusout` event may still happen.
+        // We make sure we don't trigger our `on_dismiss` in such case.
+        on_focus_outside: Some(Callback::new(compose_callbacks(
+            handlers.on_focus_outside,
+            Some(Callback::new(move |event: FocusOutsideEvent| {
+                event.prevent_default();
+            })),
+            Some(false),
+        ))),
+        ..handlers
+    };
+
+    view! {
+        <RemoveScroll allow_pinch_zoom=true forward_props=false>
+            <PopoverContentImpl
+                // We make sure we're not trapping once it's been closed (closed != unmounted when animating out).
+                trap_focus=context.open
+                disable_outside_pointer_events=true
+                placement=placement
+                handlers=handlers
+                as_child=as_child
+                node_ref=node_ref
+            >
+                {children.with_value(|children| children())}
+            </PopoverContentImpl>
+        </RemoveScroll>
+    }
+}
+
+#[component]
+#[allow(non_snake_case)]
+fn PopoverContentNonModal<C: IntoView + 'static>(
+    placement: PopoverContentPlacement,
+    handlers: PopoverContentHandlers,
+    as_child: MaybeProp<bool>,
+    node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_popover_context(POPOVER_CONTENT_NAME);
+    let has_interacted_outside = StoredValue::new(false);
+    let has_pointer_down_outside = StoredValue::new(false);
+
+    let handlers = PopoverContentHandlers {
+        on_close_auto_focus: Some(Callback::new(compose_callbacks(
+            handlers.on_close_auto_focus,
+            Some(Callback::new(move |event: ev::Event| {
+                if !has_interacted_outside.get_value() {
+                    focus_trigger(context.trigger_ref);
+                }
+                // Always prevent auto focus because we either focus manually or want user agent focus.
+                event.prevent_default();
+
+                has_interacted_outside.set_value(false);
+                has_pointer_down_outside.set_value(false);
+            })),
+            None,
+        ))),
+        on_interact_outside: Some(Callback::new(compose_callbacks(
+            handlers.on_interact_outside,
+            Some(Callback::new(move |event: InteractOutsideEvent| {
+                has_interacted_outside.set_value(true);
+                if matches!(event, InteractOutsideEvent::PointerDownOutside(_)) {
+                    has_pointer_down_outside.set_value(true);
+                }
+
+                // Prevent dismissing when clicking the trigger.
+                // As the trigger is already setup to close, without doing so would
+                // cause it to close and immediately open.
+                let target = event
+                    .original_event()
+                    .target()
+                    .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+                let target_is_trigger = context
+                    .trigger_ref
+                    .get_untracked()
+                    .is_some_and(|trigger| trigger.contains(target.as_ref()));
+                if target_is_trigger {
+                    event.prevent_default();
+                }
+
+                // On Safari if the trigger is inside a container with tabIndex={0}, when clicked
+                // we will get the pointer down outside event on the trigger, but then a subsequent
+                // focus outside event on the container, we ignore any focus outside event when we've
+                // already had a pointer down outside event.
+                if matches!(event, InteractOutsideEvent::FocusOutside(_))
+                    && has_pointer_down_outside.get_value()
+                {
+                    event.prevent_default();
+                }
+            })),
+            Some(false),
+        ))),
+        ..handlers
+    };
+
+    view! {
+        <PopoverContentImpl
+            trap_focus=false
+            disable_outside_pointer_events=false
+            placement=placement
+            handlers=handlers
+            as_child=as_child
+            node_ref=node_ref
+        >
+            {children.with_value(|children| children())}
+        </PopoverContentImpl>
+    }
+}
+
+/* ---------------------------------------------------------------------------------------------- */
+
+#[component]
+#[allow(non_snake_case)]
+fn PopoverContentImpl<C: IntoView + 'static>(
+    #[prop(into)] trap_focus: MaybeProp<bool>,
+    #[prop(into)] disable_outside_pointer_events: MaybeProp<bool>,
+    placement: PopoverContentPlacement,
+    handlers: PopoverContentHandlers,
+    as_child: MaybeProp<bool>,
+    node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_popover_context(POPOVER_CONTENT_NAME);
+
+    view! {
+        <FocusScope
+            as_child=true
+            r#loop=true
+            trapped=trap_focus
+            on_mount_auto_focus=handlers.on_open_auto_focus
+            on_unmount_auto_focus=handlers.on_close_auto_focus
+        >
+            <DismissableLayer
+                as_child=true
+                disable_outside_pointer_events=disable_outside_pointer_events
+                on_escape_key_down=handlers.on_escape_key_down
+                on_pointer_down_outside=handlers.on_pointer_down_outside
+                on_focus_outside=handlers.on_focus_outside
+                on_interact_outside=handlers.on_interact_outside
+                on_dismiss=Callback::new(move |_| context.on_open_change.run(false))
+            >
+                <PopperContent
+                    side=placement.side
+                    side_offset=placement.side_offset
+                    align=placement.align
+                    align_offset=placement.align_offset
+                    avoid_collisions=placement.avoid_collisions
+                    collision_padding=placement.collision_padding
+                    hide_when_detached=placement.hide_when_detached
+                    as_child=as_child
+                    node_ref=node_ref
+                    attr:role="dialog"
+                    attr:id=context.content_id
+                    attr:data-state=move || get_state(context.open.get())
+                    style:--radix-popover-content-transform-origin="var(--radix-popper-transform-origin)"
+                    style:--radix-popover-content-available-width="var(--radix-popper-available-width)"
+                    style:--radix-popover-content-available-height="var(--radix-popper-available-height)"
+                    style:--radix-popover-trigger-width="var(--radix-popper-anchor-width)"
+                    style:--radix-popover-trigger-height="var(--radix-popper-anchor-height)"
+                >
+                    {children.with_value(|children| children())}
+                </PopperContent>
+            </DismissableLayer>
+        </FocusScope>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PopoverClose
+ * -----------------------------------------------------------------------------------------------*/
+
+const POPOVER_CLOSE_NAME: &str = "PopoverClose";
+
+#[component]
+#[allow(non_snake_case)]
+pub fn PopoverClose(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_popover_context(POPOVER_CLOSE_NAME);
+
+    view! {
+        <Primitive
+            element=html::button
+            as_child=as_child
+            node_ref=node_ref
+            attr:r#type="button"
+            on:click=compose_callbacks(
+                on_click,
+                Some(Callback::new(move |_: ev::MouseEvent| context.on_open_change.run(false))),
+                None,
+            )
+        >
+            {children.with_value(|children| children())}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PopoverArrow
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+#[allow(non_snake_case)]
+pub fn PopoverArrow(
+    #[prop(optional, into)] width: MaybeProp<f64>,
+    #[prop(optional, into)] height: MaybeProp<f64>,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    view! {
+        <PopperArrow width=width height=height as_child=as_child node_ref=node_ref>
+            {children.with_value(|children| children.as_ref().map(|children| children()))}
+        </PopperArrow>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Helpers
+ * -----------------------------------------------------------------------------------------------*/
+
+fn get_state(open: bool) -> &'static str {
+    if open {
+        "open"
+    } else {
+        "closed"
+    }
+}
+
+fn focus_trigger(trigger_ref: AnyNodeRef) {
+    if let Some(trigger) = trigger_ref.get_untracked() {
+        let _ = trigger.unchecked_into::<web_sys::HtmlElement>().focus();
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub mod primitive {
+    pub use super::*;
+    pub use Popover as Root;
+    pub use PopoverAnchor as Anchor;
+    pub use PopoverArrow as Arrow;
+    pub use PopoverClose as Close;
+    pub use PopoverContent as Content;
+    pub use PopoverPortal as Portal;
+    pub use PopoverTrigger as Trigger;
+}
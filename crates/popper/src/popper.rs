@@ -3,7 +3,7 @@ use floating_ui_leptos::{use_floating, Alignment, ApplyState, Arrow, ArrowData,
 use leptos::{html, prelude::*};
 use leptos::context::Provider;
 use leptos::logging::{debug_warn, log};
-use leptos_node_ref::AnyNodeRef;
+use leptos_node_ref::{prelude::any_node_ref, AnyNodeRef};
 use radix_leptos_arrow::Arrow as ArrowPrimitive;
 use radix_leptos_compose_refs::use_composed_refs;
 use radix_leptos_primitive::{Primitive};
@@ -85,6 +85,54 @@ pub fn PopperAnchor<C: IntoView + 'static>(
     view! { <Primitive children=children element=html::div as_child=as_child node_ref=composed_refs /> }
 }
 
+/// A rectangle in viewport coordinates.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct VirtualRect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl VirtualRect {
+    /// A zero-size rectangle at a point, e.g. the pointer position.
+    pub fn from_point(x: f64, y: f64) -> Self {
+        Self {
+            x,
+            y,
+            width: 0.0,
+            height: 0.0,
+        }
+    }
+}
+
+/// Anchors the content to `rect` instead of an element.
+///
+/// Floating UI measures real nodes, so this renders an empty, fixed-position `span` covering `rect`.
+///
+/// A `transform`, `perspective` or `filter` on an ancestor makes that ancestor the containing block
+/// of fixed-position descendants, which offsets the `span` from `rect`. Render the virtual anchor
+/// outside of such ancestors.
+#[component]
+#[allow(non_snake_case)]
+pub fn PopperVirtualAnchor(#[prop(into)] rect: Signal<VirtualRect>) -> impl IntoView {
+    let context: PopperContextValue = expect_context();
+
+    view! {
+        <span
+            aria-hidden="true"
+            style:position="fixed"
+            style:pointer-events="none"
+            style:visibility="hidden"
+            style:left=move || format!("{}px", rect.get().x)
+            style:top=move || format!("{}px", rect.get().y)
+            style:width=move || format!("{}px", rect.get().width)
+            style:height=move || format!("{}px", rect.get().height)
+        />
+    }
+    .add_any_attr(any_node_ref(context.anchor_ref))
+}
+
 #[derive(Clone)]
 struct PopperContentContextValue {
     pub placed_side: Signal<Side>,
@@ -1,7 +1,6 @@
 use leptos::{ev, ev::{animationcancel, animationend, animationstart}, html, logging, prelude::*};
 use leptos::wasm_bindgen::JsCast;
 use leptos_use::use_event_listener;
-use web_sys::HtmlDivElement;
 use leptos_node_ref::prelude::*;
 use leptos_typed_fallback_show::TypedFallbackShow;
 
@@ -44,40 +43,39 @@ impl PresenceState {
     }
 }
 
-fn get_animation_name_recursive(element: &web_sys::Element) -> Option<String> {
-    if let Some(name) = get_animation_name(element) {
-        return Some(name);
-    }
+/// Returns the computed `animation-name` and `display` of `element`.
+fn get_styles(element: &web_sys::Element) -> (String, String) {
+    let styles = window().get_computed_style(element).ok().flatten();
+    let get_property = |name: &str| {
+        styles
+            .as_ref()
+            .and_then(|styles| styles.get_property_value(name).ok())
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| "none".to_string())
+    };
 
-    let children = element.children();
-    for i in 0..children.length() {
-        if let Some(child) = children.item(i) {
-            if let Some(name) = get_animation_name_recursive(&child) {
-                return Some(name);
-            }
-        }
-    }
-    None
+    (get_property("animation-name"), get_property("display"))
 }
 
-fn get_animation_name(element: &web_sys::Element) -> Option<String> {
-    window()
-        .get_computed_style(element)
-        .ok()
-        .flatten()
-        .and_then(|s| s.get_property_value("animation-name").ok())
-        .and_then(|name| {
-            let name = name.trim();
-            if name.is_empty() || name == "none" {
-                None
-            } else {
-                Some(name.to_string())
-            }
-        })
+#[derive(Clone, Copy)]
+struct PresenceContextValue {
+    is_present: Signal<bool>,
+}
+
+/// Whether the closest `Presence` is rendering its children. This stays `true` while an exit
+/// animation plays after `present` turned `false`, so content can stay visible until it ends.
+///
+/// Returns `true` outside of a `Presence`.
+pub fn use_is_present() -> Signal<bool> {
+    use_context::<PresenceContextValue>()
+        .map(|context| context.is_present)
+        .unwrap_or_else(|| Signal::derive(|| true))
 }
 
 /// A component that handles mounting/unmounting of children with animation support.
-/// Tracks animation state to ensure proper cleanup after exit animations complete.
+/// When `present` turns `false` while the child starts a new animation, the child stays mounted
+/// until that animation ends.
 #[component]
 pub fn Presence<C: IntoView + 'static>(
     #[prop(into)]
@@ -85,90 +83,101 @@ pub fn Presence<C: IntoView + 'static>(
     children: TypedChildrenFn<C>,
 ) -> impl IntoView {
     let node_ref = NodeRef::<html::Div>::new();
-    // let is_present = create_presence(present, node_ref);
     let children = StoredValue::new(children.into_inner());
 
-    let (prev_present, set_prev_present) = signal(present.get_untracked());
     let (state, set_state) = signal(if present.get_untracked() {
         PresenceState::Mounted
     } else {
         PresenceState::Unmounted
     });
-    let (active_animations, set_active_animations) = signal(0);
-    let (is_unmounting, set_is_unmounting) = signal(false);
-    let (exit_animation_started, set_exit_animation_started) = signal(false);
+    let prev_present = StoredValue::new(present.get_untracked());
+    let prev_animation_name = StoredValue::new("none".to_string());
 
     let send = move |event: PresenceEvent| {
-        if let Some(new_state) = state.get_untracked().transition(event) {
+        if let Some(new_state) = state.try_get_untracked().and_then(|state| state.transition(event)) {
             set_state.set(new_state);
         }
     };
 
+    let is_present = Signal::derive(move || present.get() || state.get() != PresenceState::Unmounted);
+    provide_context(PresenceContextValue { is_present });
+
     Effect::new(move |_| {
-        let Some(el) = node_ref.get().and_then(|n: HtmlDivElement| n.dyn_into::<web_sys::HtmlElement>().ok()) else {
-            return;
-        };
+        let is_mounted = state.get() == PresenceState::Mounted;
+        let current_animation_name = node_ref
+            .get_untracked()
+            .map(|node| get_styles(&node).0)
+            .unwrap_or_else(|| "none".to_string());
+        prev_animation_name.set_value(if is_mounted { current_animation_name } else { "none".to_string() });
+    });
 
-        let was_present = prev_present.get();
+    Effect::new(move |_| {
         let now_present = present.get();
-
-        if was_present == now_present {
+        if prev_present.get_value() == now_present {
             return;
         }
+        prev_present.set_value(now_present);
 
         if now_present {
-            set_is_unmounting.set(false);
-            set_exit_animation_started.set(false);
-            let _ = el.set_attribute("data-state", "enter");
             send(PresenceEvent::Mount);
-        } else {
-            set_is_unmounting.set(true);
-            set_exit_animation_started.set(false);
+            return;
+        }
+        let Some(node) = node_ref.get_untracked() else {
+            send(PresenceEvent::Unmount);
+            return;
+        };
 
-            let _ = el.set_attribute("data-state", "exit");
-            let _ = el.offset_height();
+        // The child applies its closed styles in its own effects, so read them on the next frame.
+        request_animation_frame(move || {
+            if present.try_get_untracked() != Some(false) {
+                return;
+            }
 
-            let has_animation = get_animation_name_recursive(&el).is_some();
+            let (current_animation_name, display) = get_styles(&node);
+            let is_animating = prev_animation_name
+                .try_with_value(|prev_animation_name| *prev_animation_name != current_animation_name)
+                .unwrap_or(false);
 
-            if has_animation {
-                send(PresenceEvent::AnimationOut);
-            } else {
+            if current_animation_name == "none" || display == "none" || !is_animating {
                 send(PresenceEvent::Unmount);
+            } else {
+                send(PresenceEvent::AnimationOut);
             }
-        }
-
-        set_prev_present.set(now_present);
+        });
     });
 
-    Effect::new(move |_| {
-        let handle_start = move |ev: ev::AnimationEvent| {
-            if is_unmounting.get_untracked() && ev.animation_name().contains("exit") {
-                set_exit_animation_started.set(true);
-            }
-
-            let current = active_animations.get_untracked();
-            set_active_animations.set(current + 1);
-        };
-
-        let handle_end = move |ev: ev::AnimationEvent| {
-            let current = active_animations.get_untracked();
-            let new_count = (current - 1).max(0);
-            set_active_animations.set(new_count);
+    let is_node_event = move |event: &ev::AnimationEvent| {
+        node_ref.get_untracked().is_some_and(|node| {
+            event.target().as_ref() == Some(node.unchecked_ref::<web_sys::EventTarget>())
+        })
+    };
 
-            if new_count == 0 &&
-                is_unmounting.get_untracked() &&
-                exit_animation_started.get_untracked() {
-                send(PresenceEvent::AnimationEnd);
+    let handle_start = move |event: ev::AnimationEvent| {
+        // Only enter animations are recorded, so an exit animation is always new when checked.
+        if is_node_event(&event) && present.get_untracked() {
+            if let Some(node) = node_ref.get_untracked() {
+                prev_animation_name.set_value(get_styles(&node).0);
             }
+        }
+    };
+
+    let handle_end = move |event: ev::AnimationEvent| {
+        let Some(node) = node_ref.get_untracked() else {
+            return;
         };
+        // Animations of descendants bubble up, so only the child's own current animation counts.
+        let is_current_animation = get_styles(&node).0.contains(&event.animation_name());
+        if is_node_event(&event) && is_current_animation && !present.get_untracked() {
+            send(PresenceEvent::AnimationEnd);
+        }
+    };
 
-        _ = use_event_listener(node_ref, animationstart, handle_start);
-        _ = use_event_listener(node_ref, animationend, handle_end.clone());
-        _ = use_event_listener(node_ref, animationcancel, handle_end);
-    });
+    _ = use_event_listener(node_ref, animationstart, handle_start);
+    _ = use_event_listener(node_ref, animationend, handle_end);
+    _ = use_event_listener(node_ref, animationcancel, handle_end);
 
     view! {
-        <TypedFallbackShow when=move || present.get() fallback=|| ()>
+        <TypedFallbackShow when=move || is_present.get() fallback=|| ()>
             {children.with_value(|children| children()).add_any_attr(any_node_ref(node_ref))}
         </TypedFallbackShow>
     }
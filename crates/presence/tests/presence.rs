@@ -0,0 +1,152 @@
+use js_sys::wasm_bindgen::JsCast;
+use leptos::{mount::mount_to, prelude::*};
+use radix_leptos_presence::*;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const EXIT_ANIMATION_STYLES: &str = r#"
+@keyframes presence-test-exit {
+    from { opacity: 1; }
+    to { opacity: 0; }
+}
+.presence-test-animated[data-state="closed"] {
+    animation: presence-test-exit 200ms;
+}
+"#;
+
+async fn animation_frame() {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        web_sys::window()
+            .unwrap()
+            .request_animation_frame(&resolve)
+            .unwrap();
+    });
+    JsFuture::from(promise).await.unwrap();
+}
+
+async fn sleep(ms: i32) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        web_sys::window()
+            .unwrap()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms)
+            .unwrap();
+    });
+    JsFuture::from(promise).await.unwrap();
+}
+
+/// Lets the effects run and the deferred style check of `Presence` happen.
+async fn settle() {
+    animation_frame().await;
+    animation_frame().await;
+}
+
+fn setup_test() -> web_sys::HtmlElement {
+    let document = web_sys::window().unwrap().document().unwrap();
+
+    if document.get_element_by_id("presence-test-styles").is_none() {
+        let style = document.create_element("style").unwrap();
+        style.set_id("presence-test-styles");
+        style.set_text_content(Some(EXIT_ANIMATION_STYLES));
+        document.body().unwrap().append_child(&style).unwrap();
+    }
+
+    let test_container = document.create_element("div").unwrap();
+    document
+        .body()
+        .unwrap()
+        .append_child(&test_container)
+        .unwrap();
+    test_container.unchecked_into()
+}
+
+fn content_view(present: RwSignal<bool>, class: &'static str) -> impl IntoView {
+    view! {
+        <Presence present=present>
+            <div
+                class=class
+                data-testid="content"
+                data-state=move || if present.get() { "open" } else { "closed" }
+            />
+        </Presence>
+    }
+}
+
+fn content(test_container: &web_sys::HtmlElement) -> Option<web_sys::Element> {
+    test_container
+        .query_selector("[data-testid=content]")
+        .unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn test_unmounts_without_animation() {
+    let test_container = setup_test();
+    let present = RwSignal::new(true);
+    let dispose = mount_to(test_container.clone(), move || content_view(present, ""));
+    settle().await;
+    assert!(content(&test_container).is_some());
+
+    present.set(false);
+    settle().await;
+
+    assert!(content(&test_container).is_none());
+
+    drop(dispose);
+    test_container.remove();
+}
+
+#[wasm_bindgen_test]
+async fn test_unmounts_after_exit_animation_ends() {
+    let test_container = setup_test();
+    let present = RwSignal::new(true);
+    let dispose = mount_to(test_container.clone(), move || {
+        content_view(present, "presence-test-animated")
+    });
+    settle().await;
+
+    present.set(false);
+    settle().await;
+
+    // The exit animation is still running.
+    let node = content(&test_container).expect("Content should stay mounted while animating out.");
+    assert_eq!(node.get_attribute("data-state").as_deref(), Some("closed"));
+
+    // Wait for `animationend`.
+    sleep(400).await;
+
+    assert!(content(&test_container).is_none());
+
+    drop(dispose);
+    test_container.remove();
+}
+
+#[wasm_bindgen_test]
+async fn test_reopens_during_exit_animation() {
+    let test_container = setup_test();
+    let present = RwSignal::new(true);
+    let dispose = mount_to(test_container.clone(), move || {
+        content_view(present, "presence-test-animated")
+    });
+    settle().await;
+    let node = content(&test_container).unwrap();
+
+    present.set(false);
+    settle().await;
+    assert!(content(&test_container).is_some());
+
+    present.set(true);
+    settle().await;
+
+    // The same node is kept rather than unmounted and mounted again.
+    assert_eq!(content(&test_container), Some(node.clone()));
+    assert_eq!(node.get_attribute("data-state").as_deref(), Some("open"));
+
+    // The cancelled exit animation doesn't unmount it later on.
+    sleep(400).await;
+
+    assert_eq!(content(&test_container), Some(node));
+
+    drop(dispose);
+    test_container.remove();
+}
@@ -31,6 +31,9 @@ mod utils;
 mod sidecar;
 mod components;
 
+pub use types::{Axis, TouchAction};
+pub use utils::{handle_scroll, TouchTracker};
+
 use leptos::{prelude::*, ev::{TouchEvent, WheelEvent}, wasm_bindgen::JsCast, ev};
 use leptos::ev::on;
 use web_sys::{window, HtmlElement};
@@ -0,0 +1,9 @@
+//! Leptos port of [Radix Roving Focus](https://www.radix-ui.com/primitives).
+//!
+//! This is an internal utility, not intended for public usage.
+//!
+//! See [`@radix-ui/react-roving-focus`](https://www.npmjs.com/package/@radix-ui/react-roving-focus) for the original package.
+
+mod roving_focus;
+
+pub use roving_focus::*;
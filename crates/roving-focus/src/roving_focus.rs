@@ -0,0 +1,475 @@
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use leptos::{
+    context::Provider,
+    ev::{Event, FocusEvent, KeyboardEvent, MouseEvent},
+    html,
+    prelude::*,
+};
+use leptos_node_ref::prelude::*;
+use radix_leptos_collection::{primitive as Collection, use_collection};
+use radix_leptos_direction::{use_direction, Direction};
+use radix_leptos_id::use_id;
+use radix_leptos_primitive::{compose_callbacks, Primitive};
+use radix_leptos_use_controllable_state::{
+    use_controllable_optional_state, UseControllableOptionalStateParams,
+};
+use web_sys::{wasm_bindgen::JsCast, CustomEvent, CustomEventInit, FocusOptions};
+
+const ENTRY_FOCUS: &str = "rovingFocusGroup.onEntryFocus";
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+impl Display for Orientation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Orientation::Horizontal => "horizontal",
+                Orientation::Vertical => "vertical",
+            }
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ItemData {
+    id: String,
+    focusable: bool,
+    active: bool,
+}
+
+const ITEM_DATA_PHANTOM: PhantomData<ItemData> = PhantomData;
+
+#[derive(Clone, Copy)]
+struct RovingContextValue {
+    orientation: Signal<Option<Orientation>>,
+    dir: Signal<Direction>,
+    r#loop: Signal<bool>,
+    current_tab_stop_id: Signal<Option<String>>,
+    on_item_focus: Callback<String>,
+    on_item_shift_tab: Callback<()>,
+    focusable_items_count: RwSignal<usize>,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * RovingFocusGroup
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+#[allow(non_snake_case)]
+pub fn RovingFocusGroup<C: IntoView + 'static>(
+    /// The orientation of the group. Mainly so arrow navigation is done accordingly (left & right vs. up & down).
+    #[prop(into, optional)]
+    orientation: MaybeProp<Orientation>,
+    /// The direction of navigation between items.
+    #[prop(into, optional)]
+    dir: MaybeProp<Direction>,
+    /// Whether keyboard navigation should loop around. Defaults to `false`.
+    #[prop(into, optional)]
+    r#loop: MaybeProp<bool>,
+    #[prop(into, optional)] current_tab_stop_id: MaybeProp<Option<String>>,
+    #[prop(into, optional)] default_current_tab_stop_id: MaybeProp<String>,
+    #[prop(into, optional)] on_current_tab_stop_id_change: Option<Callback<Option<String>>>,
+    #[prop(into, optional)] on_entry_focus: Option<Callback<Event>>,
+    #[prop(into, optional)] prevent_scroll_on_entry_focus: MaybeProp<bool>,
+    #[prop(into, optional)] on_mouse_down: Option<Callback<MouseEvent>>,
+    #[prop(into, optional)] on_focus: Option<Callback<FocusEvent>>,
+    #[prop(into, optional)] on_blur: Option<Callback<FocusEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(optional)] node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+
+    view! {
+        <Collection::Provider item_data_type=ITEM_DATA_PHANTOM>
+            <RovingFocusGroupImpl
+                orientation=orientation
+                dir=dir
+                r#loop=r#loop
+                current_tab_stop_id=current_tab_stop_id
+                default_current_tab_stop_id=default_current_tab_stop_id
+                on_current_tab_stop_id_change=on_current_tab_stop_id_change
+                on_entry_focus=on_entry_focus
+                prevent_scroll_on_entry_focus=prevent_scroll_on_entry_focus
+                on_mouse_down=on_mouse_down
+                on_focus=on_focus
+                on_blur=on_blur
+                as_child=as_child
+                node_ref=node_ref
+            >
+                {children.with_value(|children| children())}
+            </RovingFocusGroupImpl>
+        </Collection::Provider>
+    }
+}
+
+#[component]
+#[allow(non_snake_case)]
+fn RovingFocusGroupImpl<C: IntoView + 'static>(
+    #[prop(into, optional)] orientation: MaybeProp<Orientation>,
+    #[prop(into, optional)] dir: MaybeProp<Direction>,
+    #[prop(into, optional)] r#loop: MaybeProp<bool>,
+    #[prop(into, optional)] current_tab_stop_id: MaybeProp<Option<String>>,
+    #[prop(into, optional)] default_current_tab_stop_id: MaybeProp<String>,
+    #[prop(into, optional)] on_current_tab_stop_id_change: Option<Callback<Option<String>>>,
+    #[prop(into, optional)] on_entry_focus: Option<Callback<Event>>,
+    #[prop(into, optional)] prevent_scroll_on_entry_focus: MaybeProp<bool>,
+    #[prop(into, optional)] on_mouse_down: Option<Callback<MouseEvent>>,
+    #[prop(into, optional)] on_focus: Option<Callback<FocusEvent>>,
+    #[prop(into, optional)] on_blur: Option<Callback<FocusEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(optional)] node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let orientation = Signal::derive(move || orientation.get());
+    let direction = use_direction(dir);
+    let r#loop = Signal::derive(move || r#loop.get().unwrap_or(false));
+    let prevent_scroll_on_entry_focus =
+        Signal::derive(move || prevent_scroll_on_entry_focus.get().unwrap_or(false));
+
+    let (current_tab_stop_id, set_current_tab_stop_id) =
+        use_controllable_optional_state(UseControllableOptionalStateParams {
+            prop: current_tab_stop_id,
+            default_prop: MaybeProp::derive(move || Some(default_current_tab_stop_id.get())),
+            on_change: on_current_tab_stop_id_change,
+        });
+    let (is_tabbing_back_out, set_is_tabbing_back_out) = signal(false);
+    let is_click_focus = StoredValue::new(false);
+    let focusable_items_count = RwSignal::new(0usize);
+    let get_items = use_collection::<ItemData>();
+
+    let context_value = RovingContextValue {
+        orientation,
+        dir: direction,
+        r#loop,
+        current_tab_stop_id,
+        on_item_focus: Callback::new(move |tab_stop_id| {
+            set_current_tab_stop_id.run(Some(tab_stop_id));
+        }),
+        on_item_shift_tab: Callback::new(move |_| {
+            set_is_tabbing_back_out.set(true);
+        }),
+        focusable_items_count,
+    };
+
+    view! {
+        <Provider value=context_value>
+            <Collection::Slot item_data_type=ITEM_DATA_PHANTOM>
+                <Primitive
+                    element=html::div
+                    as_child=as_child
+                    node_ref=node_ref
+                    attr:tabindex=move || {
+                        match is_tabbing_back_out.get() || focusable_items_count.get() == 0 {
+                            true => -1,
+                            false => 0,
+                        }
+                    }
+                    attr:data-orientation=move || orientation.get().map(|orientation| orientation.to_string())
+                    style:outline="none"
+                    on:mousedown=compose_callbacks(
+                        on_mouse_down,
+                        Some(Callback::new(move |_| {
+                            is_click_focus.set_value(true);
+                        })),
+                        None,
+                    )
+                    on:focus=compose_callbacks(
+                        on_focus,
+                        Some(Callback::new(move |event: FocusEvent| {
+                            // We normally wouldn't need this check, because we already check
+                            // that the focus is on the current target and not bubbling to it.
+                            // We do this because Safari doesn't focus buttons when clicked, and
+                            // instead, the wrapper will get focused and not through a bubbling event.
+                            let is_keyboard_focus = !is_click_focus.get_value();
+
+                            if event.target() == event.current_target()
+                                && is_keyboard_focus
+                                && !is_tabbing_back_out.get_untracked()
+                            {
+                                let init = CustomEventInit::new();
+                                init.set_bubbles(false);
+                                init.set_cancelable(true);
+                                let entry_focus_event =
+                                    CustomEvent::new_with_event_init_dict(ENTRY_FOCUS, &init)
+                                        .expect("Entry focus event should be instantiated.");
+                                if let Some(on_entry_focus) = on_entry_focus {
+                                    on_entry_focus.run(entry_focus_event.clone().into());
+                                }
+
+                                if !entry_focus_event.default_prevented() {
+                                    let items = get_items()
+                                        .into_iter()
+                                        .filter(|item| item.data.focusable)
+                                        .collect::<Vec<_>>();
+                                    let current_tab_stop_id = current_tab_stop_id.get_untracked();
+                                    let active_item = items.iter().find(|item| item.data.active);
+                                    let current_item = items.iter().find(|item| {
+                                        current_tab_stop_id
+                                            .as_ref()
+                                            .is_some_and(|id| item.data.id == *id)
+                                    });
+                                    let candidate_nodes = active_item
+                                        .into_iter()
+                                        .chain(current_item)
+                                        .chain(items.iter())
+                                        .filter_map(|item| item.r#ref.get_untracked())
+                                        .map(|node| node.unchecked_into::<web_sys::HtmlElement>())
+                                        .collect::<Vec<_>>();
+                                    focus_first(candidate_nodes, prevent_scroll_on_entry_focus.get_untracked());
+                                }
+                            }
+
+                            is_click_focus.set_value(false);
+                        })),
+                        None,
+                    )
+                    on:blur=compose_callbacks(
+                        on_blur,
+                        Some(Callback::new(move |_| {
+                            set_is_tabbing_back_out.set(false);
+                        })),
+                        None,
+                    )
+                >
+                    {children.with_value(|children| children())}
+                </Primitive>
+            </Collection::Slot>
+        </Provider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * RovingFocusGroupItem
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+#[allow(non_snake_case)]
+pub fn RovingFocusGroupItem<C: IntoView + 'static>(
+    /// Whether the item can receive focus. Defaults to `true`.
+    #[prop(into, optional)]
+    focusable: MaybeProp<bool>,
+    /// Whether the item should be focused when the group is entered. Defaults to `false`.
+    #[prop(into, optional)]
+    active: MaybeProp<bool>,
+    #[prop(into, optional)] tab_stop_id: MaybeProp<String>,
+    #[prop(into, optional)] on_mouse_down: Option<Callback<MouseEvent>>,
+    #[prop(into, optional)] on_focus: Option<Callback<FocusEvent>>,
+    #[prop(into, optional)] on_key_down: Option<Callback<KeyboardEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(optional)] node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let focusable = Signal::derive(move || focusable.get().unwrap_or(true));
+    let active = Signal::derive(move || active.get().unwrap_or(false));
+
+    let auto_id = use_id();
+    let id = Signal::derive(move || tab_stop_id.get().unwrap_or_else(|| auto_id.get()));
+    let context = expect_context::<RovingContextValue>();
+    let is_current_tab_stop =
+        Signal::derive(move || context.current_tab_stop_id.get() == Some(id.get()));
+    let get_items = use_collection::<ItemData>();
+
+    Effect::new(move |_| {
+        if focusable.get() {
+            context.focusable_items_count.update(|count| *count += 1);
+            on_cleanup(move || {
+                context.focusable_items_count.try_update(|count| *count -= 1);
+            });
+        }
+    });
+
+    let item_data = Signal::derive(move || ItemData {
+        id: id.get(),
+        focusable: focusable.get(),
+        active: active.get(),
+    });
+
+    view! {
+        <Collection::ItemSlot item_data_type=ITEM_DATA_PHANTOM item_data=item_data>
+            <Primitive
+                element=html::span
+                as_child=as_child
+                node_ref=node_ref
+                attr:tabindex=move || if is_current_tab_stop.get() { 0 } else { -1 }
+                attr:data-orientation=move || context.orientation.get().map(|orientation| orientation.to_string())
+                on:mousedown=compose_callbacks(
+                    on_mouse_down,
+                    Some(Callback::new(move |event: MouseEvent| {
+                        // We prevent focusing non-focusable items on `mousedown`.
+                        // Even though the item has `tabindex=-1`, that only means take it out of the tab order.
+                        if !focusable.get_untracked() {
+                            event.prevent_default();
+                        } else {
+                            // Safari doesn't focus a button when clicked so we run our logic on mousedown also.
+                            context.on_item_focus.run(id.get_untracked());
+                        }
+                    })),
+                    None,
+                )
+                on:focus=compose_callbacks(
+                    on_focus,
+                    Some(Callback::new(move |_| {
+                        context.on_item_focus.run(id.get_untracked());
+                    })),
+                    None,
+                )
+                on:keydown=compose_callbacks(
+                    on_key_down,
+                    Some(Callback::new(move |event: KeyboardEvent| {
+                        if event.key() == "Tab" && event.shift_key() {
+                            context.on_item_shift_tab.run(());
+                            return;
+                        }
+
+                        if event.target() != event.current_target() {
+                            return;
+                        }
+
+                        let Some(focus_intent) = get_focus_intent(
+                            &event,
+                            context.orientation.get_untracked(),
+                            context.dir.get_untracked(),
+                        ) else {
+                            return;
+                        };
+
+                        if event.meta_key() || event.ctrl_key() || event.alt_key() || event.shift_key() {
+                            return;
+                        }
+                        event.prevent_default();
+
+                        let mut candidate_nodes = get_items()
+                            .into_iter()
+                            .filter(|item| item.data.focusable)
+                            .filter_map(|item| item.r#ref.get_untracked())
+                            .map(|node| node.unchecked_into::<web_sys::HtmlElement>())
+                            .collect::<Vec<_>>();
+
+                        match focus_intent {
+                            FocusIntent::First => {}
+                            FocusIntent::Last => candidate_nodes.reverse(),
+                            FocusIntent::Prev | FocusIntent::Next => {
+                                if focus_intent == FocusIntent::Prev {
+                                    candidate_nodes.reverse();
+                                }
+                                let current_target = event
+                                    .current_target()
+                                    .map(|current_target| current_target.unchecked_into::<web_sys::HtmlElement>());
+                                let start_index = candidate_nodes
+                                    .iter()
+                                    .position(|node| Some(node) == current_target.as_ref())
+                                    .map(|index| index + 1)
+                                    .unwrap_or(0);
+                                candidate_nodes = match context.r#loop.get_untracked() {
+                                    true => wrap_array(&mut candidate_nodes, start_index).to_vec(),
+                                    false => candidate_nodes.split_off(start_index.min(candidate_nodes.len())),
+                                };
+                            }
+                        }
+
+                        // Imperative focus during keydown is risky, so defer it until the event has been handled.
+                        set_timeout(move || focus_first(candidate_nodes, false), Duration::ZERO);
+                    })),
+                    None,
+                )
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        </Collection::ItemSlot>
+    }
+}
+
+/* -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum FocusIntent {
+    First,
+    Last,
+    Prev,
+    Next,
+}
+
+fn map_key_to_focus_intent(key: &str) -> Option<FocusIntent> {
+    match key {
+        "ArrowLeft" | "ArrowUp" => Some(FocusIntent::Prev),
+        "ArrowRight" | "ArrowDown" => Some(FocusIntent::Next),
+        "PageUp" | "Home" => Some(FocusIntent::First),
+        "PageDown" | "End" => Some(FocusIntent::Last),
+        _ => None,
+    }
+}
+
+fn get_direction_aware_key(key: String, dir: Direction) -> String {
+    if dir != Direction::Rtl {
+        return key;
+    }
+    match key.as_str() {
+        "ArrowLeft" => "ArrowRight".into(),
+        "ArrowRight" => "ArrowLeft".into(),
+        _ => key,
+    }
+}
+
+fn get_focus_intent(
+    event: &KeyboardEvent,
+    orientation: Option<Orientation>,
+    dir: Direction,
+) -> Option<FocusIntent> {
+    let key = get_direction_aware_key(event.key(), dir);
+    if orientation == Some(Orientation::Vertical) && ["ArrowLeft", "ArrowRight"].contains(&key.as_str()) {
+        return None;
+    }
+    if orientation == Some(Orientation::Horizontal) && ["ArrowUp", "ArrowDown"].contains(&key.as_str()) {
+        return None;
+    }
+    map_key_to_focus_intent(&key)
+}
+
+fn focus_first(candidates: Vec<web_sys::HtmlElement>, prevent_scroll: bool) {
+    let previously_focused_element = document().active_element();
+    let options = FocusOptions::new();
+    options.set_prevent_scroll(prevent_scroll);
+    for candidate in candidates {
+        // If focus is already where we want to go, we don't want to keep going through the candidates.
+        if previously_focused_element.as_ref() == candidate.dyn_ref::<web_sys::Element>() {
+            return;
+        }
+
+        candidate
+            .focus_with_options(&options)
+            .expect("Element should be focused.");
+        if document().active_element() != previously_focused_element {
+            return;
+        }
+    }
+}
+
+/// Wraps an array around itself at a given start index.
+fn wrap_array<T>(array: &mut [T], start_index: usize) -> &[T] {
+    if !array.is_empty() {
+        array.rotate_left(start_index % array.len());
+    }
+    array
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub mod primitive {
+    pub use super::*;
+    pub use RovingFocusGroup as Root;
+    pub use RovingFocusGroupItem as Item;
+}
@@ -0,0 +1,9 @@
+//! Leptos port of [Radix Select](https://www.radix-ui.com/primitives/docs/components/select).
+//!
+//! Displays a list of options for the user to pick from, triggered by a button.
+//!
+//! See [`@radix-ui/react-select`](https://www.npmjs.com/package/@radix-ui/react-select) for the original package.
+
+mod select;
+
+pub use select::*;
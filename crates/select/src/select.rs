@@ -0,0 +1,1632 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use leptos::{context::Provider, ev, html, prelude::*};
+use leptos::wasm_bindgen::JsCast;
+use leptos_node_ref::AnyNodeRef;
+use leptos_remove_scroll::RemoveScroll;
+use radix_leptos_collection::{primitive as Collection, use_collection, CollectionItemValue};
+use radix_leptos_compose_refs::use_composed_refs;
+use radix_leptos_context::create_context;
+use radix_leptos_direction::use_direction;
+use radix_leptos_dismissable_layer::{DismissableLayer, FocusOutsideEvent};
+use radix_leptos_focus_scope::FocusScope;
+use radix_leptos_id::use_id;
+use radix_leptos_popper::{Popper, PopperAnchor, PopperArrow, PopperContent};
+use radix_leptos_portal::Portal as PortalPrimitive;
+use radix_leptos_presence::Presence;
+use radix_leptos_primitive::{compose_callbacks, Primitive, VoidPrimitive};
+use radix_leptos_use_controllable_state::{use_controllable_state, UseControllableStateParams};
+use radix_leptos_use_previous::use_previous;
+use radix_leptos_use_size::use_size;
+use web_sys::FocusOptions;
+pub use radix_leptos_direction::Direction;
+pub use radix_leptos_popper::{Align, Padding, Side};
+
+const OPEN_KEYS: &[&str] = &[" ", "Enter", "ArrowUp", "ArrowDown"];
+const SELECTION_KEYS: &[&str] = &[" ", "Enter"];
+
+/// Distance kept between the content and the edges of the viewport, in pixels.
+const CONTENT_MARGIN: f64 = 10.0;
+
+/// How far the pointer has to travel after pressing the trigger before releasing it selects an item.
+const POINTER_UP_THRESHOLD: f64 = 10.0;
+
+/* -------------------------------------------------------------------------------------------------
+ * Select
+ * -----------------------------------------------------------------------------------------------*/
+
+const SELECT_NAME: &str = "Select";
+
+#[derive(Clone, Debug)]
+struct ItemData {
+    value: String,
+    disabled: bool,
+    text_value: String,
+}
+
+const ITEM_DATA_PHANTOM: PhantomData<ItemData> = PhantomData;
+
+/// An `<option>` rendered by the hidden native select, registered by `SelectItemText`.
+#[derive(Clone, Debug, PartialEq)]
+struct NativeOption {
+    value: String,
+    text: String,
+    disabled: bool,
+    /// The same item may be mounted twice while the content switches between open and closed.
+    registrations: usize,
+}
+
+#[derive(Clone, Copy)]
+struct SelectContextValue {
+    trigger_ref: AnyNodeRef,
+    value_node_ref: AnyNodeRef,
+    content_id: Signal<String>,
+    /// The selected value, empty while the placeholder is shown.
+    value: Signal<String>,
+    on_value_change: Callback<String>,
+    open: Signal<bool>,
+    required: Signal<bool>,
+    on_open_change: Callback<bool>,
+    dir: Signal<Direction>,
+    trigger_pointer_down_pos: StoredValue<Option<(f64, f64)>>,
+    disabled: Signal<bool>,
+    native_options: RwSignal<Vec<NativeOption>>,
+}
+
+create_context!(
+    context_type: SelectContextValue,
+    provider: SelectProvider,
+    hook: use_select_context,
+    root: SELECT_NAME
+);
+
+#[component]
+#[allow(non_snake_case)]
+pub fn Select(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] value: MaybeProp<String>,
+    #[prop(optional, into)] default_value: MaybeProp<String>,
+    #[prop(optional, into)] on_value_change: Option<Callback<String>>,
+    #[prop(optional, into)] open: MaybeProp<bool>,
+    #[prop(optional, into)] default_open: MaybeProp<bool>,
+    #[prop(optional, into)] on_open_change: Option<Callback<bool>>,
+    #[prop(optional, into)] dir: MaybeProp<Direction>,
+    /// HTML `name` attribute of the hidden native select.
+    #[prop(optional, into)]
+    name: MaybeProp<String>,
+    #[prop(optional, into)] auto_complete: MaybeProp<String>,
+    #[prop(optional, into)] disabled: MaybeProp<bool>,
+    #[prop(optional, into)] required: MaybeProp<bool>,
+    /// Id of the form the select belongs to, when it isn't rendered inside one.
+    #[prop(optional, into)]
+    form: MaybeProp<String>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let required = Signal::derive(move || required.get().unwrap_or(false));
+
+    let (value, set_value) = use_controllable_state(UseControllableStateParams {
+        prop: value,
+        default_prop: default_value,
+        on_change: on_value_change,
+    });
+    let (open, set_open) = use_controllable_state(UseControllableStateParams {
+        prop: open,
+        default_prop: default_open,
+        on_change: on_open_change,
+    });
+
+    let trigger_ref = AnyNodeRef::new();
+    let native_options = RwSignal::new(Vec::<NativeOption>::new());
+
+    // The native select is only needed when the value is submitted with a form.
+    let is_form_control = Signal::derive(move || {
+        form.get().is_some()
+            || trigger_ref
+                .get()
+                .and_then(|trigger| trigger.closest("form").ok().flatten())
+                .is_some()
+    });
+
+    let initial_value = value.get_untracked();
+
+    let context_value = SelectContextValue {
+        trigger_ref,
+        value_node_ref: AnyNodeRef::new(),
+        content_id: use_id().into(),
+        value,
+        on_value_change: set_value,
+        open,
+        required,
+        on_open_change: set_open,
+        dir: use_direction(dir),
+        trigger_pointer_down_pos: StoredValue::new(None),
+        disabled,
+        native_options,
+    };
+
+    view! {
+        <SelectProvider value=context_value>
+            <Collection::Provider item_data_type=ITEM_DATA_PHANTOM>
+                <Popper>
+                    {children.with_value(|children| children())}
+                </Popper>
+            </Collection::Provider>
+            <Show when=move || is_form_control.get()>
+                <BubbleSelect
+                    control_ref=trigger_ref
+                    value=value
+                    options=native_options
+                    name=name
+                    auto_complete=auto_complete
+                    required=required
+                    disabled=disabled
+                    form=form
+                    // Enable form autofill.
+                    on_change=set_value
+                    on_reset=Callback::new({
+                        let initial_value = initial_value.clone();
+                        move |_| set_value.run(initial_value.clone())
+                    })
+                />
+            </Show>
+        </SelectProvider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+const SELECT_TRIGGER_NAME: &str = "SelectTrigger";
+
+#[component]
+#[allow(non_snake_case)]
+pub fn SelectTrigger(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] disabled: MaybeProp<bool>,
+    #[prop(optional, into)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(optional, into)] on_pointer_down: Option<Callback<ev::PointerEvent>>,
+    #[prop(optional, into)] on_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_select_context(SELECT_TRIGGER_NAME);
+    let is_disabled = Signal::derive(move || context.disabled.get() || disabled.get().unwrap_or(false));
+    let get_items = use_collection::<ItemData>();
+    let pointer_type = StoredValue::new(String::from("touch"));
+    let search = TypeaheadSearch::new();
+
+    let handle_typeahead_search = move |key: &str| {
+        let search = search.push(key);
+        let items = get_items()
+            .into_iter()
+            .filter(|item| !item.data.disabled)
+            .collect::<Vec<_>>();
+        let current_value = context.value.get_untracked();
+        if let Some(next_item) = find_next_item(&items, &search, Some(&current_value)) {
+            context.on_value_change.run(next_item.data.value.clone());
+        }
+    };
+
+    let handle_open = move |pointer_position: Option<(f64, f64)>| {
+        if !is_disabled.get_untracked() {
+            context.on_open_change.run(true);
+            // Reset typeahead when we open.
+            search.reset();
+        }
+        if let Some(pointer_position) = pointer_position {
+            context.trigger_pointer_down_pos.set_value(Some(pointer_position));
+        }
+    };
+
+    view! {
+        <PopperAnchor as_child=true>
+            <Primitive
+                element=html::button
+                as_child=as_child
+                node_ref=use_composed_refs((node_ref, context.trigger_ref))
+                attr:r#type="button"
+                attr:role="combobox"
+                attr:aria-controls=context.content_id
+                attr:aria-expanded=move || context.open.get().to_string()
+                attr:aria-required=move || context.required.get().to_string()
+                attr:aria-autocomplete="none"
+                attr:dir=move || context.dir.get().to_string()
+                attr:data-state=move || if context.open.get() { "open" } else { "closed" }
+                attr:disabled=move || is_disabled.get()
+                attr:data-disabled=move || is_disabled.get().then_some("")
+                attr:data-placeholder=move || context.value.get().is_empty().then_some("")
+                on:click=compose_callbacks(
+                    on_click,
+                    Some(Callback::new(move |event: ev::MouseEvent| {
+                        // Whilst browsers generally have buttons receive focus when clicked, Safari
+                        // doesn't, so we focus it explicitly to make sure it can restore focus.
+                        if let Some(trigger) = event.current_target() {
+                            let _ = trigger.unchecked_into::<web_sys::HtmlElement>().focus();
+                        }
+                        // Open on click when using a touch or pen device.
+                        if pointer_type.get_value() != "mouse" {
+                            handle_open(Some((event.page_x() as f64, event.page_y() as f64)));
+                        }
+                    })),
+                    None,
+                )
+                on:pointerdown=compose_callbacks(
+                    on_pointer_down,
+                    Some(Callback::new(move |event: ev::PointerEvent| {
+                        pointer_type.set_value(event.pointer_type());
+
+                        // Prevent implicit pointer capture.
+                        if let Some(target) = event
+                            .target()
+                            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                        {
+                            if target.has_pointer_capture(event.pointer_id()) {
+                                let _ = target.release_pointer_capture(event.pointer_id());
+                            }
+                        }
+
+                        // Only call handler if it's the left button (mousedown gets triggered by all mouse buttons)
+                        // but not when the control key is pressed (avoiding MacOS right click); also not for touch
+                        // devices because that would open the menu on scroll. (pen devices behave as touch on iOS).
+                        if event.button() == 0 && !event.ctrl_key() && event.pointer_type() == "mouse" {
+                            handle_open(Some((event.page_x() as f64, event.page_y() as f64)));
+                            // Prevent trigger from stealing focus from the active item after opening.
+                            event.prevent_default();
+                        }
+                    })),
+                    None,
+                )
+                on:keydown=compose_callbacks(
+                    on_key_down,
+                    Some(Callback::new(move |event: ev::KeyboardEvent| {
+                        let key = event.key();
+                        let is_typing_ahead = search.is_typing();
+                        let is_modifier_key = event.ctrl_key() || event.alt_key() || event.meta_key();
+                        if !is_modifier_key && key.len() == 1 {
+                            handle_typeahead_search(&key);
+                        }
+                        if is_typing_ahead && key == " " {
+                            return;
+                        }
+                        if OPEN_KEYS.contains(&key.as_str()) {
+                            handle_open(None);
+                            event.prevent_default();
+                        }
+                    })),
+                    None,
+                )
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        </PopperAnchor>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectValue
+ * -----------------------------------------------------------------------------------------------*/
+
+const SELECT_VALUE_NAME: &str = "SelectValue";
+
+/// Renders the text of the selected item, or `placeholder` while there is no value.
+#[component]
+#[allow(non_snake_case)]
+pub fn SelectValue(
+    #[prop(optional, into)] placeholder: MaybeProp<String>,
+    /// Overrides the text of the selected item.
+    #[prop(optional)]
+    children: Option<ChildrenFn>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    let context = use_select_context(SELECT_VALUE_NAME);
+
+    // Items unmount and remount while the content opens and closes, so keep showing the last known
+    // text for the same value until its item registers again.
+    let selected_text = Memo::new(move |previous: Option<&(String, String)>| {
+        let value = context.value.get();
+        let text = context.native_options.with(|options| {
+            options
+                .iter()
+                .find(|option| option.value == value)
+                .map(|option| option.text.clone())
+        });
+        let text = text.unwrap_or_else(|| {
+            previous
+                .filter(|(previous_value, _)| *previous_value == value)
+                .map(|(_, text)| text.clone())
+                .unwrap_or_default()
+        });
+        (value, text)
+    });
+
+    view! {
+        <Primitive
+            element=html::span
+            as_child=as_child
+            node_ref=use_composed_refs((node_ref, context.value_node_ref))
+            // We don't want events from the portalled `SelectValue` children to bubble
+            // through the item they came from.
+            style:pointer-events="none"
+        >
+            {move || {
+                if context.value.get().is_empty() {
+                    placeholder.get().unwrap_or_default().into_any()
+                } else if let Some(children) = children.get_value() {
+                    children().into_any()
+                } else {
+                    selected_text.get().1.into_any()
+                }
+            }}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectIcon
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+#[allow(non_snake_case)]
+pub fn SelectIcon(
+    #[prop(optional)] children: Option<ChildrenFn>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    view! {
+        <Primitive element=html::span as_child=as_child node_ref=node_ref attr:aria-hidden="true">
+            {move || match children.get_value() {
+                Some(children) => children().into_any(),
+                None => "▼".into_any(),
+            }}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectPortal
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+#[allow(non_snake_case)]
+pub fn SelectPortal(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] container: AnyNodeRef,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let container = StoredValue::new(container);
+
+    view! {
+        <PortalPrimitive as_child=true mount=container.with_value(|container| container.get())>
+            {children.with_value(|children| children())}
+        </PortalPrimitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectContent
+ * -----------------------------------------------------------------------------------------------*/
+
+const SELECT_CONTENT_NAME: &str = "SelectContent";
+
+/// How `SelectContent` is positioned.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum SelectPosition {
+    /// Places the content over the trigger so the selected item lines up with `SelectValue`,
+    /// like a native select on macOS.
+    #[default]
+    ItemAligned,
+    /// Places the content next to the trigger using `PopperContent`.
+    Popper,
+}
+
+/// Positioning props forwarded to `PopperContent` in `SelectPosition::Popper` mode.
+#[derive(Clone, Copy, Default)]
+struct PopperPlacement {
+    side: MaybeProp<Side>,
+    side_offset: MaybeProp<f64>,
+    align: MaybeProp<Align>,
+    align_offset: MaybeProp<f64>,
+    avoid_collisions: MaybeProp<bool>,
+    collision_padding: MaybeProp<Padding>,
+}
+
+#[derive(Clone, Copy)]
+struct SelectContentContextValue {
+    content_ref: AnyNodeRef,
+    viewport_ref: AnyNodeRef,
+    position: SelectPosition,
+    is_positioned: RwSignal<bool>,
+    search: TypeaheadSearch,
+    /// How far the pointer moved since it pressed the trigger, while it is still down.
+    pointer_move_delta: StoredValue<Option<(f64, f64)>>,
+    on_item_leave: Callback<()>,
+}
+
+create_context!(
+    context_type: SelectContentContextValue,
+    provider: SelectContentProvider,
+    hook: use_select_content_context,
+    root: SELECT_CONTENT_NAME
+);
+
+#[component]
+#[allow(non_snake_case)]
+pub fn SelectContent(
+    children: ChildrenFn,
+    #[prop(optional, into)] position: SelectPosition,
+    /// The preferred side of the trigger in `Popper` mode. Defaults to `Bottom`.
+    #[prop(optional, into)]
+    side: MaybeProp<Side>,
+    #[prop(optional, into)] side_offset: MaybeProp<f64>,
+    /// The preferred alignment against the trigger in `Popper` mode. Defaults to `Start`.
+    #[prop(optional, into)]
+    align: MaybeProp<Align>,
+    #[prop(optional, into)] align_offset: MaybeProp<f64>,
+    #[prop(optional, into)] avoid_collisions: MaybeProp<bool>,
+    #[prop(optional, into)] collision_padding: MaybeProp<Padding>,
+    #[prop(optional, into)] on_escape_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    let context = use_select_context(SELECT_CONTENT_NAME);
+    let placement = PopperPlacement {
+        side,
+        side_offset,
+        align,
+        align_offset,
+        avoid_collisions,
+        collision_padding,
+    };
+
+    view! {
+        <Presence present=context.open>
+            <SelectContentImpl
+                position=position
+                placement=placement
+                on_escape_key_down=on_escape_key_down
+                node_ref=node_ref
+                as_child=as_child
+            >
+                {children.with_value(|children| children())}
+            </SelectContentImpl>
+        </Presence>
+        // Items still render while closed so the trigger can show the selected text, typeahead
+        // through them and fill the native select. They go into a detached element instead.
+        <Show when=move || !context.open.get()>
+            <PortalPrimitive mount=document().create_element("div").ok()>
+                <SelectContentProvider value=SelectContentContextValue {
+                    content_ref: AnyNodeRef::new(),
+                    viewport_ref: AnyNodeRef::new(),
+                    position,
+                    is_positioned: RwSignal::new(false),
+                    search: TypeaheadSearch::new(),
+                    pointer_move_delta: StoredValue::new(None),
+                    on_item_leave: Callback::new(|_| {}),
+                }>
+                    <div>{children.with_value(|children| children())}</div>
+                </SelectContentProvider>
+            </PortalPrimitive>
+        </Show>
+    }
+}
+
+#[component]
+#[allow(non_snake_case)]
+fn SelectContentImpl(
+    children: ChildrenFn,
+    position: SelectPosition,
+    placement: PopperPlacement,
+    on_escape_key_down: Option<Callback<ev::KeyboardEvent>>,
+    node_ref: AnyNodeRef,
+    as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    let context = use_select_context(SELECT_CONTENT_NAME);
+    let get_items = use_collection::<ItemData>();
+    let content_ref = AnyNodeRef::new();
+    let composed_refs = use_composed_refs((node_ref, content_ref));
+    let is_positioned = RwSignal::new(false);
+    let search = TypeaheadSearch::new();
+    let pointer_move_delta = StoredValue::new(None::<(f64, f64)>);
+
+    let focus_content = move || {
+        if let Some(content) = content_ref.get_untracked() {
+            let options = FocusOptions::new();
+            options.set_prevent_scroll(true);
+            let _ = content.unchecked_into::<web_sys::HtmlElement>().focus_with_options(&options);
+        }
+    };
+
+    let enabled_item_nodes = move || {
+        get_items()
+            .into_iter()
+            .filter(|item| !item.data.disabled)
+            .filter_map(|item| item.r#ref.get_untracked())
+            .map(|item| item.unchecked_into::<web_sys::HtmlElement>())
+            .collect::<Vec<_>>()
+    };
+
+    // Focus the selected item, or the first item, once the content has been positioned.
+    Effect::new(move |_| {
+        if !is_positioned.get() {
+            return;
+        }
+        let items = get_items();
+        let value = context.value.get_untracked();
+        let selected_item = items
+            .iter()
+            .find(|item| !item.data.disabled && item.data.value == value)
+            .or_else(|| items.iter().find(|item| !item.data.disabled))
+            .and_then(|item| item.r#ref.get_untracked())
+            .map(|item| item.unchecked_into::<web_sys::HtmlElement>());
+        let content = content_ref
+            .get_untracked()
+            .map(|content| content.unchecked_into::<web_sys::HtmlElement>());
+        focus_first(selected_item.into_iter().chain(content).collect());
+    });
+
+    // Releasing the pointer that opened the content selects the item under it, unless it barely
+    // moved (the trigger was just clicked), and closes the content if it was released outside.
+    if let Some((start_x, start_y)) = context.trigger_pointer_down_pos.get_value() {
+        pointer_move_delta.set_value(Some((0.0, 0.0)));
+
+        let pointer_move = window_event_listener(ev::pointermove, move |event| {
+            pointer_move_delta.set_value(Some((
+                (event.page_x() as f64 - start_x).abs(),
+                (event.page_y() as f64 - start_y).abs(),
+            )));
+        });
+        let pointer_up = window_event_listener(ev::pointerup, move |event| {
+            let has_moved = pointer_move_delta.get_value().is_some_and(|(x, y)| {
+                x > POINTER_UP_THRESHOLD || y > POINTER_UP_THRESHOLD
+            });
+            let target = event
+                .target()
+                .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+            let is_inside_content = content_ref
+                .get_untracked()
+                .is_some_and(|content| content.contains(target.as_ref()));
+            if has_moved && !is_inside_content {
+                context.on_open_change.run(false);
+            }
+            pointer_move_delta.set_value(None);
+            context.trigger_pointer_down_pos.set_value(None);
+        });
+
+        on_cleanup(move || {
+            pointer_move.remove();
+            pointer_up.remove();
+        });
+    }
+
+    let handle_typeahead_search = move |key: &str| {
+        let search = search.push(key);
+        let items = get_items()
+            .into_iter()
+            .filter(|item| !item.data.disabled)
+            .collect::<Vec<_>>();
+        let active_element = document().active_element();
+        let current_value = items
+            .iter()
+            .find(|item| item.r#ref.get_untracked() == active_element)
+            .map(|item| item.data.value.clone());
+        let next_item = find_next_item(&items, &search, current_value.as_deref())
+            .and_then(|item| item.r#ref.get_untracked());
+        if let Some(next_item) = next_item {
+            // Imperative focus during keydown is risky so we prevent React's batching updates
+            // to avoid potential bugs. See: https://github.com/facebook/react/issues/20332
+            set_timeout(
+                move || {
+                    let _ = next_item.unchecked_into::<web_sys::HtmlElement>().focus();
+                },
+                Duration::ZERO,
+            );
+        }
+    };
+
+    let content_context_value = SelectContentContextValue {
+        content_ref,
+        viewport_ref: AnyNodeRef::new(),
+        position,
+        is_positioned,
+        search,
+        pointer_move_delta,
+        on_item_leave: Callback::new(move |_| focus_content()),
+    };
+
+    let content = move || {
+        view! {
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=composed_refs
+                attr:role="listbox"
+                attr:id=context.content_id
+                attr:data-state=move || if context.open.get() { "open" } else { "closed" }
+                attr:dir=move || context.dir.get().to_string()
+                attr:tabindex="-1"
+                // Flex layout so we can place the scroll buttons properly.
+                style:display="flex"
+                style:flex-direction="column"
+                // Reset the outline by default as the content may be focused.
+                style:outline="none"
+                style:--radix-select-content-transform-origin="var(--radix-popper-transform-origin)"
+                style:--radix-select-content-available-width="var(--radix-popper-available-width)"
+                style:--radix-select-content-available-height="var(--radix-popper-available-height)"
+                style:--radix-select-trigger-width="var(--radix-popper-anchor-width)"
+                style:--radix-select-trigger-height="var(--radix-popper-anchor-height)"
+                on:contextmenu=move |event: ev::MouseEvent| event.prevent_default()
+                on:keydown=move |event: ev::KeyboardEvent| {
+                    let key = event.key();
+                    let is_modifier_key = event.ctrl_key() || event.alt_key() || event.meta_key();
+
+                    // Select should not be navigated using tab key so we prevent it.
+                    if key == "Tab" {
+                        event.prevent_default();
+                    }
+                    if !is_modifier_key && key.len() == 1 {
+                        handle_typeahead_search(&key);
+                    }
+
+                    if ["ArrowUp", "ArrowDown", "Home", "End"].contains(&key.as_str()) {
+                        let mut candidate_nodes = enabled_item_nodes();
+                        if ["ArrowUp", "End"].contains(&key.as_str()) {
+                            candidate_nodes.reverse();
+                        }
+                        if ["ArrowUp", "ArrowDown"].contains(&key.as_str()) {
+                            let current_element = event
+                                .target()
+                                .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok());
+                            let current_index = candidate_nodes
+                                .iter()
+                                .position(|node| Some(node) == current_element.as_ref());
+                            candidate_nodes = candidate_nodes
+                                .split_off(current_index.map(|index| index + 1).unwrap_or(0));
+                        }
+
+                        // Imperative focus during keydown is risky so we defer it.
+                        set_timeout(move || focus_first(candidate_nodes), Duration::ZERO);
+                        event.prevent_default();
+                    }
+                }
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        }
+    };
+
+    view! {
+        <SelectContentProvider value=content_context_value>
+            <RemoveScroll allow_pinch_zoom=true forward_props=false>
+                <FocusScope
+                    as_child=true
+                    // We make sure we're not trapping once it's been closed (closed != unmounted when animating out).
+                    trapped=context.open
+                    on_mount_auto_focus=Callback::new(move |event: ev::Event| {
+                        // We prevent open autofocus because we manually focus the selected item.
+                        event.prevent_default();
+                    })
+                    on_unmount_auto_focus=Callback::new(move |event: ev::Event| {
+                        if let Some(trigger) = context.trigger_ref.get_untracked() {
+                            let options = FocusOptions::new();
+                            options.set_prevent_scroll(true);
+                            let _ = trigger
+                                .unchecked_into::<web_sys::HtmlElement>()
+                                .focus_with_options(&options);
+                        }
+                        event.prevent_default();
+                    })
+                >
+                    <DismissableLayer
+                        as_child=true
+                        disable_outside_pointer_events=true
+                        on_escape_key_down=on_escape_key_down
+                        // When focus is trapped, a `focusout` event may still happen.
+                        // We make sure we don't trigger our `on_dismiss` in such case.
+                        on_focus_outside=Callback::new(move |event: FocusOutsideEvent| {
+                            event.prevent_default();
+                        })
+                        on_dismiss=Callback::new(move |_| context.on_open_change.run(false))
+                    >
+                        {match position {
+                            SelectPosition::ItemAligned => view! {
+                                <SelectItemAlignedPosition>{content()}</SelectItemAlignedPosition>
+                            }
+                            .into_any(),
+                            SelectPosition::Popper => view! {
+                                <SelectPopperPosition placement=placement>{content()}</SelectPopperPosition>
+                            }
+                            .into_any(),
+                        }}
+                    </DismissableLayer>
+                </FocusScope>
+            </RemoveScroll>
+        </SelectContentProvider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectItemAlignedPosition
+ * -----------------------------------------------------------------------------------------------*/
+
+const SELECT_ITEM_ALIGNED_POSITION_NAME: &str = "SelectItemAlignedPosition";
+
+#[component]
+#[allow(non_snake_case)]
+fn SelectItemAlignedPosition(children: Children) -> impl IntoView {
+    let context = use_select_context(SELECT_ITEM_ALIGNED_POSITION_NAME);
+    let content_context = use_select_content_context(SELECT_ITEM_ALIGNED_POSITION_NAME);
+    let get_items = use_collection::<ItemData>();
+    let content_wrapper_ref: NodeRef<html::Div> = NodeRef::new();
+    let content_z_index = RwSignal::new(None::<String>);
+
+    let position = move || {
+        let (
+            Some(trigger),
+            Some(value_node),
+            Some(content_wrapper),
+            Some(content),
+            Some(viewport),
+        ) = (
+            context.trigger_ref.get_untracked(),
+            context.value_node_ref.get_untracked(),
+            content_wrapper_ref.get_untracked(),
+            content_context.content_ref.get_untracked(),
+            content_context.viewport_ref.get_untracked(),
+        )
+        else {
+            return;
+        };
+        let content = content.unchecked_into::<web_sys::HtmlElement>();
+        let viewport = viewport.unchecked_into::<web_sys::HtmlElement>();
+
+        let items = get_items()
+            .into_iter()
+            .filter(|item| !item.data.disabled)
+            .collect::<Vec<_>>();
+        let value = context.value.get_untracked();
+        let Some(selected_index) = items
+            .iter()
+            .position(|item| item.data.value == value)
+            .or((!items.is_empty()).then_some(0))
+        else {
+            return;
+        };
+        let Some(selected_item) = items[selected_index]
+            .r#ref
+            .get_untracked()
+            .map(|item| item.unchecked_into::<web_sys::HtmlElement>())
+        else {
+            return;
+        };
+        // Align against the item's text, falling back to the item itself.
+        let selected_item_text = selected_item
+            .get_attribute("aria-labelledby")
+            .and_then(|id| document().get_element_by_id(&id))
+            .unwrap_or_else(|| selected_item.clone().into());
+
+        let window = window();
+        let window_width = window
+            .inner_width()
+            .ok()
+            .and_then(|width| width.as_f64())
+            .unwrap_or_default();
+        let window_height = window
+            .inner_height()
+            .ok()
+            .and_then(|height| height.as_f64())
+            .unwrap_or_default();
+        let wrapper_style = content_wrapper.style();
+
+        // -----------------------------------------------------------------------------------------
+        //  Horizontal positioning
+        // -----------------------------------------------------------------------------------------
+        let trigger_rect = trigger.get_bounding_client_rect();
+        let content_rect = content.get_bounding_client_rect();
+        let value_node_rect = value_node.get_bounding_client_rect();
+        let item_text_rect = selected_item_text.get_bounding_client_rect();
+
+        if context.dir.get_untracked() != Direction::Rtl {
+            let item_text_offset = item_text_rect.left() - content_rect.left();
+            let left = value_node_rect.left() - item_text_offset;
+            let left_delta = trigger_rect.left() - left;
+            let min_content_width = trigger_rect.width() + left_delta;
+            let content_width = min_content_width.max(content_rect.width());
+            let right_edge = window_width - CONTENT_MARGIN;
+            let clamped_left = left.min(right_edge - content_width).max(CONTENT_MARGIN);
+
+            let _ = wrapper_style.set_property("min-width", &format!("{min_content_width}px"));
+            let _ = wrapper_style.set_property("left", &format!("{clamped_left}px"));
+        } else {
+            let item_text_offset = content_rect.right() - item_text_rect.right();
+            let right = window_width - value_node_rect.right() - item_text_offset;
+            let right_delta = window_width - trigger_rect.right() - right;
+            let min_content_width = trigger_rect.width() + right_delta;
+            let content_width = min_content_width.max(content_rect.width());
+            let left_edge = window_width - CONTENT_MARGIN;
+            let clamped_right = right.min(left_edge - content_width).max(CONTENT_MARGIN);
+
+            let _ = wrapper_style.set_property("min-width", &format!("{min_content_width}px"));
+            let _ = wrapper_style.set_property("right", &format!("{clamped_right}px"));
+        }
+
+        // -----------------------------------------------------------------------------------------
+        // Vertical positioning
+        // -----------------------------------------------------------------------------------------
+        let available_height = window_height - CONTENT_MARGIN * 2.0;
+        let items_height = viewport.scroll_height() as f64;
+
+        let content_styles = computed_style(&content);
+        let content_border_top_width = content_styles("border-top-width");
+        let content_padding_top = content_styles("padding-top");
+        let content_border_bottom_width = content_styles("border-bottom-width");
+        let content_padding_bottom = content_styles("padding-bottom");
+        // We use the border-box so the content can't grow beyond the wrapper.
+        let full_content_height = content_border_top_width
+            + content_padding_top
+            + items_height
+            + content_padding_bottom
+            + content_border_bottom_width;
+        let min_content_height = (selected_item.offset_height() as f64 * 5.0).min(full_content_height);
+
+        let viewport_styles = computed_style(&viewport);
+        let viewport_padding_top = viewport_styles("padding-top");
+        let viewport_padding_bottom = viewport_styles("padding-bottom");
+
+        let top_edge_to_trigger_middle =
+            trigger_rect.top() + trigger_rect.height() / 2.0 - CONTENT_MARGIN;
+        let trigger_middle_to_bottom_edge = available_height - top_edge_to_trigger_middle;
+
+        let selected_item_half_height = selected_item.offset_height() as f64 / 2.0;
+        let item_offset_middle = selected_item.offset_top() as f64 + selected_item_half_height;
+        let content_top_to_item_middle =
+            content_border_top_width + content_padding_top + item_offset_middle;
+        let item_middle_to_content_bottom = full_content_height - content_top_to_item_middle;
+
+        let will_align_without_top_overflow = content_top_to_item_middle <= top_edge_to_trigger_middle;
+
+        if will_align_without_top_overflow {
+            let is_last_item = selected_index == items.len() - 1;
+            let _ = wrapper_style.set_property("bottom", "0px");
+            let viewport_offset_bottom = content.client_height() as f64
+                - viewport.offset_top() as f64
+                - viewport.offset_height() as f64;
+            let clamped_trigger_middle_to_bottom_edge = trigger_middle_to_bottom_edge.max(
+                selected_item_half_height
+                    // Viewport might have padding bottom, include it to avoid a scrollable viewport.
+                    + if is_last_item { viewport_padding_bottom } else { 0.0 }
+                    + viewport_offset_bottom
+                    + content_border_bottom_width,
+            );
+            let height = content_top_to_item_middle + clamped_trigger_middle_to_bottom_edge;
+            let _ = wrapper_style.set_property("height", &format!("{height}px"));
+        } else {
+            let is_first_item = selected_index == 0;
+            let _ = wrapper_style.set_property("top", "0px");
+            let clamped_top_edge_to_trigger_middle = top_edge_to_trigger_middle.max(
+                content_border_top_width
+                    + viewport.offset_top() as f64
+                    // Viewport might have padding top, include it to avoid a scrollable viewport.
+                    + if is_first_item { viewport_padding_top } else { 0.0 }
+                    + selected_item_half_height,
+            );
+            let height = clamped_top_edge_to_trigger_middle + item_middle_to_content_bottom;
+            let _ = wrapper_style.set_property("height", &format!("{height}px"));
+            viewport.set_scroll_top(
+                (content_top_to_item_middle - top_edge_to_trigger_middle
+                    + viewport.offset_top() as f64) as i32,
+            );
+        }
+
+        let _ = wrapper_style.set_property("margin", &format!("{CONTENT_MARGIN}px 0"));
+        let _ = wrapper_style.set_property("min-height", &format!("{min_content_height}px"));
+        let _ = wrapper_style.set_property("max-height", &format!("{available_height}px"));
+
+        content_context.is_positioned.set(true);
+    };
+
+    Effect::new(move |_| {
+        if let Some(content) = content_context.content_ref.get() {
+            let content = content.unchecked_into::<web_sys::HtmlElement>();
+            // The content fills the wrapper, which is what gets positioned.
+            let content_style = content.style();
+            let _ = content_style.set_property("box-sizing", "border-box");
+            let _ = content_style.set_property("max-height", "100%");
+            // Copy z-index from content to wrapper.
+            content_z_index.set(
+                window()
+                    .get_computed_style(&content)
+                    .ok()
+                    .flatten()
+                    .and_then(|styles| styles.get_property_value("z-index").ok()),
+            );
+            position();
+        }
+    });
+
+    view! {
+        <div
+            node_ref=content_wrapper_ref
+            style:display="flex"
+            style:flex-direction="column"
+            style:position="fixed"
+            style:z-index=move || content_z_index.get().unwrap_or_default()
+        >
+            {children()}
+        </div>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectPopperPosition
+ * -----------------------------------------------------------------------------------------------*/
+
+const SELECT_POPPER_POSITION_NAME: &str = "SelectPopperPosition";
+
+#[component]
+#[allow(non_snake_case)]
+fn SelectPopperPosition(children: TypedChildrenFn<impl IntoView + 'static>, placement: PopperPlacement) -> impl IntoView {
+    let content_context = use_select_content_context(SELECT_POPPER_POSITION_NAME);
+
+    view! {
+        <PopperContent
+            as_child=true
+            side=placement.side
+            side_offset=placement.side_offset
+            align=Signal::derive(move || Some(placement.align.get().unwrap_or(Align::Start)))
+            align_offset=placement.align_offset
+            avoid_collisions=placement.avoid_collisions
+            collision_padding=Signal::derive(move || {
+                Some(placement.collision_padding.get().unwrap_or(Padding::All(CONTENT_MARGIN)))
+            })
+            on_placed=Callback::new(move |_| content_context.is_positioned.set(true))
+            children=children
+        />
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectViewport
+ * -----------------------------------------------------------------------------------------------*/
+
+const SELECT_VIEWPORT_NAME: &str = "SelectViewport";
+
+#[component]
+#[allow(non_snake_case)]
+pub fn SelectViewport(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let content_context = use_select_content_context(SELECT_VIEWPORT_NAME);
+
+    view! {
+        <Collection::Slot item_data_type=ITEM_DATA_PHANTOM>
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=use_composed_refs((node_ref, content_context.viewport_ref))
+                attr:data-radix-select-viewport=""
+                attr:role="presentation"
+                // We use position: 'relative' here on the `viewport` so that when we call
+                // `selected_item.offset_top()` in calculations, the offset is relative to the viewport
+                // (independent of the scroll buttons).
+                style:position="relative"
+                style:flex="1"
+                style:overflow="auto"
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        </Collection::Slot>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectGroup
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone, Copy)]
+struct SelectGroupContextValue {
+    id: Signal<String>,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn SelectGroup(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let group_id: Signal<String> = use_id().into();
+
+    view! {
+        <Provider value=SelectGroupContextValue { id: group_id }>
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=node_ref
+                attr:role="group"
+                attr:aria-labelledby=group_id
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        </Provider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectLabel
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+#[allow(non_snake_case)]
+pub fn SelectLabel(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let group_context = use_context::<SelectGroupContextValue>();
+
+    view! {
+        <Primitive
+            element=html::div
+            as_child=as_child
+            node_ref=node_ref
+            attr:id=move || group_context.map(|group_context| group_context.id.get())
+        >
+            {children.with_value(|children| children())}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectItem
+ * -----------------------------------------------------------------------------------------------*/
+
+const SELECT_ITEM_NAME: &str = "SelectItem";
+
+#[derive(Clone, Copy)]
+struct SelectItemContextValue {
+    value: StoredValue<String>,
+    disabled: Signal<bool>,
+    text_id: Signal<String>,
+    is_selected: Signal<bool>,
+}
+
+create_context!(
+    context_type: SelectItemContextValue,
+    provider: SelectItemProvider,
+    hook: use_select_item_context,
+    root: SELECT_ITEM_NAME
+);
+
+#[component]
+#[allow(non_snake_case)]
+pub fn SelectItem(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// Must not be empty, the empty value is reserved for showing the placeholder.
+    value: String,
+    #[prop(optional, into)] disabled: MaybeProp<bool>,
+    /// Text used for typeahead. Defaults to the item's text content.
+    #[prop(optional, into)]
+    text_value: MaybeProp<String>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    assert!(
+        !value.is_empty(),
+        "A <SelectItem /> must have a value that is not an empty string. This is because the Select value can be set to an empty string to clear the selection and show the placeholder."
+    );
+
+    let children = StoredValue::new(children.into_inner());
+    let context = use_select_context(SELECT_ITEM_NAME);
+    let content_context = use_select_content_context(SELECT_ITEM_NAME);
+    let value = StoredValue::new(value);
+    let disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let is_selected = Signal::derive(move || value.with_value(|value| context.value.get() == *value));
+    let is_focused = RwSignal::new(false);
+    let text_id: Signal<String> = use_id().into();
+    let pointer_type = StoredValue::new(String::from("touch"));
+
+    let item_ref = AnyNodeRef::new();
+    let composed_refs = use_composed_refs((node_ref, item_ref));
+    let text_content = RwSignal::new(String::new());
+
+    Effect::new(move |_| {
+        if let Some(item) = item_ref.get() {
+            text_content.set(item.text_content().unwrap_or_default());
+        }
+    });
+
+    let item_data = Signal::derive(move || ItemData {
+        value: value.get_value(),
+        disabled: disabled.get(),
+        text_value: text_value.get().unwrap_or_else(|| text_content.get()),
+    });
+
+    let handle_select = move || {
+        if !disabled.get_untracked() {
+            context.on_value_change.run(value.get_value());
+            context.on_open_change.run(false);
+        }
+    };
+
+    let item_context_value = SelectItemContextValue {
+        value,
+        disabled,
+        text_id,
+        is_selected,
+    };
+
+    view! {
+        <SelectItemProvider value=item_context_value>
+            <Collection::ItemSlot item_data_type=ITEM_DATA_PHANTOM item_data=item_data>
+                <Primitive
+                    element=html::div
+                    as_child=as_child
+                    node_ref=composed_refs
+                    attr:role="option"
+                    attr:aria-labelledby=text_id
+                    attr:data-highlighted=move || is_focused.get().then_some("")
+                    // `isFocused` caveat fixes stuttering in VoiceOver.
+                    attr:aria-selected=move || (is_selected.get() && is_focused.get()).to_string()
+                    attr:data-state=move || if is_selected.get() { "checked" } else { "unchecked" }
+                    attr:aria-disabled=move || disabled.get().then_some("true")
+                    attr:data-disabled=move || disabled.get().then_some("")
+                    attr:tabindex=move || (!disabled.get()).then_some("-1")
+                    on:focus=move |_: ev::FocusEvent| is_focused.set(true)
+                    on:blur=move |_: ev::FocusEvent| is_focused.set(false)
+                    on:click=move |_: ev::MouseEvent| {
+                        // Open on click when using a touch or pen device.
+                        if pointer_type.get_value() != "mouse" {
+                            handle_select();
+                        }
+                    }
+                    on:pointerup=move |_: ev::PointerEvent| {
+                        // The pointer that opened the content is released without having moved
+                        // far enough, so this is the end of the click on the trigger.
+                        let is_trigger_click = content_context
+                            .pointer_move_delta
+                            .get_value()
+                            .is_some_and(|(x, y)| x <= POINTER_UP_THRESHOLD && y <= POINTER_UP_THRESHOLD);
+                        if pointer_type.get_value() == "mouse" && !is_trigger_click {
+                            handle_select();
+                        }
+                    }
+                    on:pointerdown=move |event: ev::PointerEvent| {
+                        pointer_type.set_value(event.pointer_type());
+                    }
+                    on:pointermove=move |event: ev::PointerEvent| {
+                        // Remember pointer type when sliding over to this item from another one.
+                        pointer_type.set_value(event.pointer_type());
+                        if disabled.get_untracked() {
+                            content_context.on_item_leave.run(());
+                        } else if pointer_type.get_value() == "mouse" {
+                            // Even though Safari doesn't support this option, it's acceptable
+                            // as it only means it might scroll a few pixels when using the pointer.
+                            if let Some(item) = event.current_target() {
+                                let options = FocusOptions::new();
+                                options.set_prevent_scroll(true);
+                                let _ = item
+                                    .unchecked_into::<web_sys::HtmlElement>()
+                                    .focus_with_options(&options);
+                            }
+                        }
+                    }
+                    on:pointerleave=move |event: ev::PointerEvent| {
+                        let is_active = event
+                            .current_target()
+                            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                            == document().active_element();
+                        if is_active {
+                            content_context.on_item_leave.run(());
+                        }
+                    }
+                    on:keydown=move |event: ev::KeyboardEvent| {
+                        let is_typing_ahead = content_context.search.is_typing();
+                        if is_typing_ahead && event.key() == " " {
+                            return;
+                        }
+                        if SELECTION_KEYS.contains(&event.key().as_str()) {
+                            handle_select();
+                        }
+                        // Prevent page scroll if using the space key to select an item.
+                        if event.key() == " " {
+                            event.prevent_default();
+                        }
+                    }
+                >
+                    {children.with_value(|children| children())}
+                </Primitive>
+            </Collection::ItemSlot>
+        </SelectItemProvider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectItemText
+ * -----------------------------------------------------------------------------------------------*/
+
+const SELECT_ITEM_TEXT_NAME: &str = "SelectItemText";
+
+#[component]
+#[allow(non_snake_case)]
+pub fn SelectItemText(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_select_context(SELECT_ITEM_TEXT_NAME);
+    let item_context = use_select_item_context(SELECT_ITEM_TEXT_NAME);
+    let text_ref = AnyNodeRef::new();
+    let composed_refs = use_composed_refs((node_ref, text_ref));
+
+    // Register the text with the native select and `SelectValue`. The effect reruns when the text
+    // or `disabled` change, but only the first run counts as a registration.
+    let is_registered = StoredValue::new(false);
+    Effect::new(move |_| {
+        let Some(text) = text_ref.get() else {
+            return;
+        };
+        let option = NativeOption {
+            value: item_context.value.get_value(),
+            text: text.text_content().unwrap_or_default(),
+            disabled: item_context.disabled.get(),
+            registrations: 1,
+        };
+        let is_new_registration = !is_registered.get_value();
+        is_registered.set_value(true);
+        context.native_options.update(|options| {
+            match options.iter_mut().find(|existing| existing.value == option.value) {
+                Some(existing) => {
+                    existing.text = option.text;
+                    existing.disabled = option.disabled;
+                    if is_new_registration {
+                        existing.registrations += 1;
+                    }
+                }
+                None => options.push(option),
+            }
+        });
+    });
+
+    on_cleanup(move || {
+        if !is_registered.get_value() {
+            return;
+        }
+        let value = item_context.value.get_value();
+        context.native_options.try_update(|options| {
+            if let Some(existing) = options.iter_mut().find(|existing| existing.value == value) {
+                existing.registrations -= 1;
+            }
+            options.retain(|option| option.registrations > 0);
+        });
+    });
+
+    view! {
+        <Primitive
+            element=html::span
+            as_child=as_child
+            node_ref=composed_refs
+            attr:id=item_context.text_id
+        >
+            {children.with_value(|children| children())}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectItemIndicator
+ * -----------------------------------------------------------------------------------------------*/
+
+const SELECT_ITEM_INDICATOR_NAME: &str = "SelectItemIndicator";
+
+#[component]
+#[allow(non_snake_case)]
+pub fn SelectItemIndicator(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let item_context = use_select_item_context(SELECT_ITEM_INDICATOR_NAME);
+
+    view! {
+        <Show when=move || item_context.is_selected.get()>
+            <Primitive element=html::span as_child=as_child node_ref=node_ref attr:aria-hidden="true">
+                {children.with_value(|children| children())}
+            </Primitive>
+        </Show>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectSeparator
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+#[allow(non_snake_case)]
+pub fn SelectSeparator(
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    view! {
+        <VoidPrimitive element=html::div as_child=as_child node_ref=node_ref attr:aria-hidden="true">
+            {children.with_value(|children| children.as_ref().map(|children| children()))}
+        </VoidPrimitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * SelectArrow
+ * -----------------------------------------------------------------------------------------------*/
+
+const SELECT_ARROW_NAME: &str = "SelectArrow";
+
+/// Only rendered when the content uses `SelectPosition::Popper`.
+#[component]
+#[allow(non_snake_case)]
+pub fn SelectArrow(
+    #[prop(optional, into)] width: MaybeProp<f64>,
+    #[prop(optional, into)] height: MaybeProp<f64>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let context = use_select_context(SELECT_ARROW_NAME);
+    let content_context = use_select_content_context(SELECT_ARROW_NAME);
+
+    view! {
+        <Show when=move || context.open.get() && content_context.position == SelectPosition::Popper>
+            <PopperArrow width=width height=height as_child=as_child node_ref=node_ref />
+        </Show>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * BubbleSelect (for form integration)
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn BubbleSelect(
+    #[prop(into)] control_ref: AnyNodeRef,
+    #[prop(into)] value: Signal<String>,
+    #[prop(into)] options: Signal<Vec<NativeOption>>,
+    #[prop(into)] name: MaybeProp<String>,
+    #[prop(into)] auto_complete: MaybeProp<String>,
+    #[prop(into)] required: Signal<bool>,
+    #[prop(into)] disabled: Signal<bool>,
+    #[prop(into)] form: MaybeProp<String>,
+    on_change: Callback<String>,
+    on_reset: Callback<()>,
+) -> impl IntoView {
+    let node_ref: NodeRef<html::Select> = NodeRef::new();
+    let prev_value = use_previous(value);
+    let control_size = use_size(control_ref);
+
+    // Whenever `value` changes, bubble a change event to the parent form
+    Effect::new(move |_| {
+        if let Some(select) = node_ref.get() {
+            let old = prev_value.get();
+            let new = value.get();
+            if old != new {
+                select.set_value(&new);
+
+                let init = web_sys::EventInit::new();
+                init.set_bubbles(true);
+                let event = web_sys::Event::new_with_event_init_dict("change", &init).unwrap();
+                let _ = select.dispatch_event(&event);
+            }
+        }
+    });
+
+    // Reset to the initial value when the owning form is reset.
+    let reset = window_event_listener(ev::reset, move |event| {
+        let form = node_ref.get_untracked().and_then(|select| select.form());
+        let is_own_form = form.is_some_and(|form| {
+            event.target().as_ref() == Some(form.unchecked_ref::<web_sys::EventTarget>())
+        });
+        if is_own_form {
+            on_reset.run(());
+        }
+    });
+    on_cleanup(move || reset.remove());
+
+    view! {
+        <select
+            node_ref=node_ref
+            aria-hidden="true"
+            tabindex="-1"
+            name=move || name.get()
+            autocomplete=move || auto_complete.get()
+            required=move || required.get()
+            disabled=move || disabled.get()
+            form=move || form.get()
+            on:change=move |event: ev::Event| {
+                let select = event_target::<web_sys::HtmlSelectElement>(&event);
+                on_change.run(select.value());
+            }
+            style:transform="translateX(-100%)"
+            style:width=move || {
+                control_size.get().map(|s| format!("{}px", s.width)).unwrap_or_default()
+            }
+            style:height=move || {
+                control_size.get().map(|s| format!("{}px", s.height)).unwrap_or_default()
+            }
+            style:position="absolute"
+            style:pointer-events="none"
+            style:opacity="0"
+            style:margin="0"
+        >
+            <Show when=move || value.get().is_empty()>
+                <option value="" />
+            </Show>
+            <For
+                each=move || options.get()
+                key=|option| option.value.clone()
+                let:option
+            >
+                <option
+                    value=option.value.clone()
+                    disabled=option.disabled
+                    selected=move || value.get() == option.value
+                >
+                    {option.text.clone()}
+                </option>
+            </For>
+        </select>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Typeahead
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Accumulates typeahead keys, resetting one second after the last key press.
+#[derive(Clone, Copy)]
+struct TypeaheadSearch {
+    search: RwSignal<String>,
+    timer: RwSignal<Option<TimeoutHandle>>,
+}
+
+impl TypeaheadSearch {
+    fn new() -> Self {
+        let typeahead = Self {
+            search: RwSignal::new(String::new()),
+            timer: RwSignal::new(None),
+        };
+        on_cleanup(move || typeahead.clear_timer());
+        typeahead
+    }
+
+    fn is_typing(self) -> bool {
+        !self.search.get_untracked().is_empty()
+    }
+
+    /// Appends `key` and returns the resulting search.
+    fn push(self, key: &str) -> String {
+        let search = self.search.get_untracked() + key;
+        self.search.set(search.clone());
+        self.clear_timer();
+        // Reset search 1 second after it was last updated.
+        self.timer.set(
+            set_timeout_with_handle(move || self.search.set(String::new()), Duration::from_millis(1000))
+                .ok(),
+        );
+        search
+    }
+
+    fn reset(self) {
+        self.search.set(String::new());
+        self.clear_timer();
+    }
+
+    fn clear_timer(self) {
+        if let Some(timer) = self.timer.try_get_untracked().flatten() {
+            timer.clear();
+        }
+    }
+}
+
+/// Finds the next item whose text starts with `search`, looking forward from the current item.
+///
+/// Repeatedly pressing the same character cycles through the items starting with it, and
+/// `None` is returned if the current item is still the best match.
+fn find_next_item<'a>(
+    items: &'a [CollectionItemValue<ItemData>],
+    search: &str,
+    current_value: Option<&str>,
+) -> Option<&'a CollectionItemValue<ItemData>> {
+    let mut chars = search.chars();
+    let first_char = chars.next()?;
+    let is_repeated = search.chars().count() > 1 && chars.all(|c| c == first_char);
+    let normalized_search = if is_repeated {
+        first_char.to_string()
+    } else {
+        search.to_string()
+    }
+    .to_lowercase();
+
+    let current_index = current_value
+        .and_then(|current_value| items.iter().position(|item| item.data.value == current_value));
+    let start_index = current_index.unwrap_or(0);
+    let exclude_current_item = normalized_search.chars().count() == 1;
+
+    let next_item = items[start_index..]
+        .iter()
+        .chain(&items[..start_index])
+        .filter(|item| !(exclude_current_item && Some(item.data.value.as_str()) == current_value))
+        .find(|item| item.data.text_value.to_lowercase().starts_with(&normalized_search));
+
+    next_item.filter(|item| Some(item.data.value.as_str()) != current_value)
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Helpers
+ * -----------------------------------------------------------------------------------------------*/
+
+fn focus_first(candidates: Vec<web_sys::HtmlElement>) {
+    let previously_focused_element = document().active_element();
+    for candidate in candidates {
+        // If focus is already where we want to go, we don't want to keep going through the candidates.
+        if previously_focused_element.as_ref() == candidate.dyn_ref::<web_sys::Element>() {
+            return;
+        }
+
+        let options = FocusOptions::new();
+        options.set_prevent_scroll(true);
+        let _ = candidate.focus_with_options(&options);
+        if document().active_element() != previously_focused_element {
+            return;
+        }
+    }
+}
+
+/// Returns a reader for pixel values of `element`'s computed style.
+fn computed_style(element: &web_sys::Element) -> impl Fn(&str) -> f64 {
+    let styles = window().get_computed_style(element).ok().flatten();
+    move |property| {
+        styles
+            .as_ref()
+            .and_then(|styles| styles.get_property_value(property).ok())
+            .and_then(|value| value.trim_end_matches("px").parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub mod primitive {
+    pub use super::*;
+    pub use Select as Root;
+    pub use SelectArrow as Arrow;
+    pub use SelectContent as Content;
+    pub use SelectGroup as Group;
+    pub use SelectIcon as Icon;
+    pub use SelectItem as Item;
+    pub use SelectItemIndicator as ItemIndicator;
+    pub use SelectItemText as ItemText;
+    pub use SelectLabel as Label;
+    pub use SelectPortal as Portal;
+    pub use SelectSeparator as Separator;
+    pub use SelectTrigger as Trigger;
+    pub use SelectValue as Value;
+    pub use SelectViewport as Viewport;
+}
@@ -0,0 +1,9 @@
+//! Leptos port of [Radix Tabs](https://www.radix-ui.com/primitives/docs/components/tabs).
+//!
+//! A set of layered sections of content, known as tab panels, that are displayed one at a time.
+//!
+//! See [`@radix-ui/react-tabs`](https://www.npmjs.com/package/@radix-ui/react-tabs) for the original package.
+
+mod tabs;
+
+pub use tabs::*;
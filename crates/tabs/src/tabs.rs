@@ -0,0 +1,357 @@
+use leptos::{ev, html, prelude::*};
+use leptos_node_ref::AnyNodeRef;
+use radix_leptos_context::create_context;
+use radix_leptos_direction::use_direction;
+pub use radix_leptos_direction::Direction;
+use radix_leptos_id::use_id;
+use radix_leptos_presence::{use_is_present, Presence};
+use radix_leptos_primitive::{compose_callbacks, Primitive};
+pub use radix_leptos_roving_focus::Orientation;
+use radix_leptos_roving_focus::{RovingFocusGroup, RovingFocusGroupItem};
+use radix_leptos_use_controllable_state::{use_controllable_state, UseControllableStateParams};
+
+/* -------------------------------------------------------------------------------------------------
+ * Tabs
+ * -----------------------------------------------------------------------------------------------*/
+
+const TABS_NAME: &str = "Tabs";
+
+/// Whether a tab is activated as soon as its trigger receives focus, or only once the trigger is
+/// clicked or activated with `Enter` or `Space`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ActivationMode {
+    #[default]
+    Automatic,
+    Manual,
+}
+
+#[derive(Clone, Copy)]
+struct TabsContextValue {
+    base_id: Signal<String>,
+    value: Signal<String>,
+    on_value_change: Callback<String>,
+    orientation: Signal<Orientation>,
+    direction: Signal<Direction>,
+    activation_mode: Signal<ActivationMode>,
+}
+
+create_context!(
+    context_type: TabsContextValue,
+    provider: TabsProvider,
+    hook: use_tabs_context,
+    root: TABS_NAME
+);
+
+/// A set of layered sections of content, known as tab panels, that are displayed one at a time.
+#[component]
+#[allow(non_snake_case)]
+pub fn Tabs(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// The value of the active tab.
+    #[prop(optional, into)]
+    value: MaybeProp<String>,
+    #[prop(optional, into)] default_value: MaybeProp<String>,
+    #[prop(optional, into)] on_value_change: Option<Callback<String>>,
+    /// The axis arrow key navigation between triggers follows. Defaults to `Horizontal`.
+    #[prop(optional, into)]
+    orientation: MaybeProp<Orientation>,
+    /// The reading direction, used for horizontal arrow key navigation. Inherits from the closest
+    /// `DirectionProvider` when unset.
+    #[prop(optional, into)]
+    dir: MaybeProp<Direction>,
+    /// Defaults to `Automatic`.
+    #[prop(optional, into)]
+    activation_mode: MaybeProp<ActivationMode>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let direction = use_direction(dir);
+    let orientation = Signal::derive(move || orientation.get().unwrap_or(Orientation::Horizontal));
+
+    let (value, set_value) = use_controllable_state(UseControllableStateParams {
+        prop: value,
+        default_prop: default_value,
+        on_change: on_value_change,
+    });
+
+    let context_value = TabsContextValue {
+        base_id: use_id().into(),
+        value,
+        on_value_change: set_value,
+        orientation,
+        direction,
+        activation_mode: Signal::derive(move || activation_mode.get().unwrap_or_default()),
+    };
+
+    view! {
+        <TabsProvider value=context_value>
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=node_ref
+                attr:dir=move || direction.get().to_string()
+                attr:data-orientation=move || orientation.get().to_string()
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        </TabsProvider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * TabsList
+ * -----------------------------------------------------------------------------------------------*/
+
+const TAB_LIST_NAME: &str = "TabsList";
+
+/// Contains the triggers. Arrow keys move focus between them, following the orientation and
+/// reading direction of the tabs.
+#[component]
+#[allow(non_snake_case)]
+pub fn TabsList(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// Whether keyboard navigation loops from the last trigger to the first and back. Defaults to
+    /// `true`.
+    #[prop(optional, into)]
+    r#loop: MaybeProp<bool>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_tabs_context(TAB_LIST_NAME);
+
+    view! {
+        <RovingFocusGroup
+            as_child=true
+            orientation=context.orientation
+            dir=context.direction
+            r#loop=Signal::derive(move || r#loop.get().unwrap_or(true))
+        >
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=node_ref
+                attr:role="tablist"
+                attr:aria-orientation=move || context.orientation.get().to_string()
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        </RovingFocusGroup>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * TabsTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+const TRIGGER_NAME: &str = "TabsTrigger";
+
+/// Activates the tab panel with the same `value`.
+#[component]
+#[allow(non_snake_case)]
+pub fn TabsTrigger(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(into)] value: String,
+    /// When `true`, the tab can't be activated and is skipped by keyboard navigation.
+    #[prop(optional, into)]
+    disabled: MaybeProp<bool>,
+    #[prop(optional, into)] on_mouse_down: Option<Callback<ev::MouseEvent>>,
+    #[prop(optional, into)] on_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(optional, into)] on_focus: Option<Callback<ev::FocusEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_tabs_context(TRIGGER_NAME);
+    let disabled = Signal::derive(move || disabled.get().unwrap_or(false));
+    let value = StoredValue::new(value);
+
+    let trigger_id = Signal::derive(move || {
+        value.with_value(|value| make_trigger_id(&context.base_id.get(), value))
+    });
+    let content_id = Signal::derive(move || {
+        value.with_value(|value| make_content_id(&context.base_id.get(), value))
+    });
+    let is_selected = Signal::derive(move || {
+        value.with_value(|value| context.value.with(|current| current == value))
+    });
+
+    view! {
+        <RovingFocusGroupItem
+            as_child=true
+            focusable=Signal::derive(move || !disabled.get())
+            active=is_selected
+        >
+            <Primitive
+                element=html::button
+                as_child=as_child
+                node_ref=node_ref
+                attr:r#type="button"
+                attr:role="tab"
+                attr:id=trigger_id
+                attr:aria-selected=move || is_selected.get().to_string()
+                attr:aria-controls=content_id
+                attr:data-state=move || get_state(is_selected.get())
+                attr:data-orientation=move || context.orientation.get().to_string()
+                attr:data-disabled=move || disabled.get().then_some("")
+                attr:disabled=move || disabled.get()
+                on:mousedown=compose_callbacks(
+                    on_mouse_down,
+                    Some(Callback::new(move |event: ev::MouseEvent| {
+                        // Only activate on the left button, but not when the control key is pressed (avoiding
+                        // MacOS right click).
+                        if !disabled.get_untracked() && event.button() == 0 && !event.ctrl_key() {
+                            context.on_value_change.run(value.get_value());
+                        } else {
+                            // Prevent focus to avoid accidental activation.
+                            event.prevent_default();
+                        }
+                    })),
+                    None,
+                )
+                on:keydown=compose_callbacks(
+                    on_key_down,
+                    Some(Callback::new(move |event: ev::KeyboardEvent| {
+                        if [" ", "Enter"].contains(&event.key().as_str()) {
+                            context.on_value_change.run(value.get_value());
+                        }
+                    })),
+                    None,
+                )
+                on:focus=compose_callbacks(
+                    on_focus,
+                    Some(Callback::new(move |_: ev::FocusEvent| {
+                        // Automatic activation follows focus, including focus moved by the arrow keys.
+                        let is_automatic_activation =
+                            context.activation_mode.get_untracked() == ActivationMode::Automatic;
+                        if !is_selected.get_untracked() && !disabled.get_untracked() && is_automatic_activation {
+                            context.on_value_change.run(value.get_value());
+                        }
+                    })),
+                    None,
+                )
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        </RovingFocusGroupItem>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * TabsContent
+ * -----------------------------------------------------------------------------------------------*/
+
+const CONTENT_NAME: &str = "TabsContent";
+
+/// The tab panel shown while the trigger with the same `value` is active.
+#[component]
+#[allow(non_snake_case)]
+pub fn TabsContent(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(into)] value: String,
+    /// Keep the content mounted while inactive, e.g. to control animations from outside.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_tabs_context(CONTENT_NAME);
+    let value = StoredValue::new(value);
+    let is_selected = Signal::derive(move || {
+        value.with_value(|value| context.value.with(|current| current == value))
+    });
+    let present = Signal::derive(move || force_mount.get().unwrap_or(false) || is_selected.get());
+
+    view! {
+        <Presence present=present>
+            <TabsContentImpl
+                value=value
+                is_selected=is_selected
+                as_child=as_child
+                node_ref=node_ref
+            >
+                {children.with_value(|children| children())}
+            </TabsContentImpl>
+        </Presence>
+    }
+}
+
+#[component]
+#[allow(non_snake_case)]
+fn TabsContentImpl<C: IntoView + 'static>(
+    value: StoredValue<String>,
+    is_selected: Signal<bool>,
+    as_child: MaybeProp<bool>,
+    node_ref: AnyNodeRef,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_tabs_context(CONTENT_NAME);
+    let is_present = use_is_present();
+    let trigger_id = Signal::derive(move || {
+        value.with_value(|value| make_trigger_id(&context.base_id.get(), value))
+    });
+    let content_id = Signal::derive(move || {
+        value.with_value(|value| make_content_id(&context.base_id.get(), value))
+    });
+
+    // Content that is active on mount shouldn't play its enter animation.
+    let is_mount_animation_prevented = RwSignal::new(is_selected.get_untracked());
+    Effect::new(move |_| {
+        request_animation_frame(move || is_mount_animation_prevented.set(false));
+    });
+
+    view! {
+        <Primitive
+            element=html::div
+            as_child=as_child
+            node_ref=node_ref
+            attr:id=content_id
+            attr:role="tabpanel"
+            attr:aria-labelledby=trigger_id
+            attr:tabindex="0"
+            attr:hidden=move || !is_present.get()
+            attr:data-state=move || get_state(is_selected.get())
+            attr:data-orientation=move || context.orientation.get().to_string()
+            style:animation-duration=move || {
+                if is_mount_animation_prevented.get() { "0s" } else { "" }.to_string()
+            }
+        >
+            {children.with_value(|children| children())}
+        </Primitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Helpers
+ * -----------------------------------------------------------------------------------------------*/
+
+fn get_state(selected: bool) -> &'static str {
+    if selected {
+        "active"
+    } else {
+        "inactive"
+    }
+}
+
+fn make_trigger_id(base_id: &str, value: &str) -> String {
+    format!("{base_id}-trigger-{value}")
+}
+
+fn make_content_id(base_id: &str, value: &str) -> String {
+    format!("{base_id}-content-{value}")
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub mod primitive {
+    pub use super::*;
+    pub use Tabs as Root;
+    pub use TabsContent as Content;
+    pub use TabsList as List;
+    pub use TabsTrigger as Trigger;
+}
@@ -0,0 +1,9 @@
+//! Leptos port of [Radix Tooltip](https://www.radix-ui.com/primitives/docs/components/tooltip).
+//!
+//! A popup that displays information related to an element when the element receives keyboard focus or the mouse hovers over it.
+//!
+//! See [`@radix-ui/react-tooltip`](https://www.npmjs.com/package/@radix-ui/react-tooltip) for the original package.
+
+mod tooltip;
+
+pub use tooltip::*;
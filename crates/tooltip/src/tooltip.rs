@@ -0,0 +1,962 @@
+use std::time::Duration;
+
+use leptos::wasm_bindgen::{closure::Closure, JsCast};
+use leptos::{context::Provider, ev, html, prelude::*};
+use leptos_node_ref::AnyNodeRef;
+use leptos_typed_fallback_show::TypedFallbackShow;
+use radix_leptos_compose_refs::use_composed_refs;
+use radix_leptos_context::create_context;
+use radix_leptos_dismissable_layer::{
+    DismissableLayer, FocusOutsideEvent, PointerDownOutsideEvent,
+};
+use radix_leptos_id::use_id;
+pub use radix_leptos_popper::{Align, Padding, Side};
+use radix_leptos_popper::{Popper, PopperAnchor, PopperArrow, PopperContent};
+use radix_leptos_portal::Portal as PortalPrimitive;
+use radix_leptos_presence::Presence;
+use radix_leptos_primitive::{compose_callbacks, Primitive};
+use radix_leptos_use_controllable_state::{use_controllable_state, UseControllableStateParams};
+use send_wrapper::SendWrapper;
+use web_sys::AddEventListenerOptions;
+
+const DEFAULT_DELAY_DURATION: f64 = 700.0;
+const DEFAULT_SKIP_DELAY_DURATION: f64 = 300.0;
+const TOOLTIP_OPEN: &str = "tooltip.open";
+
+/* -------------------------------------------------------------------------------------------------
+ * TooltipProvider
+ * -----------------------------------------------------------------------------------------------*/
+
+const PROVIDER_NAME: &str = "TooltipProvider";
+
+#[derive(Clone, Copy)]
+struct TooltipProviderContextValue {
+    /// Whether the next tooltip waits for `delay_duration` before opening.
+    is_open_delayed: RwSignal<bool>,
+    delay_duration: Signal<f64>,
+    on_open: Callback<()>,
+    on_close: Callback<()>,
+    is_pointer_in_transit: StoredValue<bool>,
+    disable_hoverable_content: Signal<bool>,
+}
+
+create_context!(
+    context_type: TooltipProviderContextValue,
+    provider: TooltipProviderContextProvider,
+    hook: use_tooltip_provider_context,
+    root: PROVIDER_NAME
+);
+
+/// Shares open delays between tooltips, so moving from one trigger to an adjacent one opens its
+/// tooltip instantly.
+#[component]
+#[allow(non_snake_case)]
+pub fn TooltipProvider(
+    children: TypedChildren<impl IntoView + 'static>,
+    /// Milliseconds from when the pointer enters a trigger until the tooltip opens. Defaults to `700`.
+    #[prop(optional, into)]
+    delay_duration: MaybeProp<f64>,
+    /// Milliseconds after a tooltip closes during which another one opens without a delay.
+    /// Defaults to `300`.
+    #[prop(optional, into)]
+    skip_delay_duration: MaybeProp<f64>,
+    /// Close tooltips as soon as the pointer leaves their trigger, instead of letting it move
+    /// onto the content. Defaults to `false`.
+    #[prop(optional, into)]
+    disable_hoverable_content: MaybeProp<bool>,
+) -> impl IntoView {
+    let is_open_delayed = RwSignal::new(true);
+    let skip_delay_timer = StoredValue::new(None::<TimeoutHandle>);
+
+    let clear_skip_delay_timer = move || {
+        if let Some(timer) = skip_delay_timer.try_get_value().flatten() {
+            timer.clear();
+        }
+    };
+    on_cleanup(clear_skip_delay_timer);
+
+    let context_value = TooltipProviderContextValue {
+        is_open_delayed,
+        delay_duration: Signal::derive(move || {
+            delay_duration.get().unwrap_or(DEFAULT_DELAY_DURATION)
+        }),
+        on_open: Callback::new(move |_| {
+            clear_skip_delay_timer();
+            is_open_delayed.set(false);
+        }),
+        on_close: Callback::new(move |_| {
+            clear_skip_delay_timer();
+            let skip_delay_duration = skip_delay_duration
+                .get_untracked()
+                .unwrap_or(DEFAULT_SKIP_DELAY_DURATION);
+            skip_delay_timer.set_value(
+                set_timeout_with_handle(
+                    move || is_open_delayed.set(true),
+                    Duration::from_millis(skip_delay_duration as u64),
+                )
+                .ok(),
+            );
+        }),
+        is_pointer_in_transit: StoredValue::new(false),
+        disable_hoverable_content: Signal::derive(move || {
+            disable_hoverable_content.get().unwrap_or(false)
+        }),
+    };
+
+    view! {
+        <TooltipProviderContextProvider value=context_value>
+            {children.into_inner()()}
+        </TooltipProviderContextProvider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Tooltip
+ * -----------------------------------------------------------------------------------------------*/
+
+const TOOLTIP_NAME: &str = "Tooltip";
+
+#[derive(Clone, Copy)]
+struct TooltipContextValue {
+    content_id: Signal<String>,
+    open: Signal<bool>,
+    /// `closed`, `delayed-open` or `instant-open`.
+    state_attribute: Signal<&'static str>,
+    trigger_ref: AnyNodeRef,
+    on_trigger_enter: Callback<()>,
+    on_trigger_leave: Callback<ev::PointerEvent>,
+    on_open: Callback<()>,
+    on_close: Callback<()>,
+    disable_hoverable_content: Signal<bool>,
+    /// Set by hoverable content to start its grace area when the pointer leaves the trigger.
+    trigger_leave_handler: StoredValue<Option<Callback<ev::PointerEvent>>>,
+}
+
+create_context!(
+    context_type: TooltipContextValue,
+    provider: TooltipContextProvider,
+    hook: use_tooltip_context,
+    root: TOOLTIP_NAME
+);
+
+#[component]
+#[allow(non_snake_case)]
+pub fn Tooltip(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] open: MaybeProp<bool>,
+    #[prop(optional, into)] default_open: MaybeProp<bool>,
+    #[prop(optional, into)] on_open_change: Option<Callback<bool>>,
+    /// Overrides the provider's `delay_duration` for this tooltip.
+    #[prop(optional, into)]
+    delay_duration: MaybeProp<f64>,
+    /// Overrides the provider's `disable_hoverable_content` for this tooltip.
+    #[prop(optional, into)]
+    disable_hoverable_content: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let provider_context = use_tooltip_provider_context(TOOLTIP_NAME);
+    let delay_duration = Signal::derive(move || {
+        delay_duration
+            .get()
+            .unwrap_or_else(|| provider_context.delay_duration.get())
+    });
+    let disable_hoverable_content = Signal::derive(move || {
+        disable_hoverable_content
+            .get()
+            .unwrap_or_else(|| provider_context.disable_hoverable_content.get())
+    });
+    let open_timer = StoredValue::new(None::<TimeoutHandle>);
+    let was_open_delayed = RwSignal::new(false);
+
+    let (open, set_open_state) = use_controllable_state(UseControllableStateParams {
+        prop: open,
+        default_prop: default_open,
+        on_change: on_open_change,
+    });
+    // Notify the provider and other tooltips before the state changes, so this tooltip's own
+    // content isn't mounted yet when the open event goes out.
+    let set_open = Callback::new(move |next_open: bool| {
+        if next_open == open.get_untracked() {
+            return;
+        }
+        if next_open {
+            provider_context.on_open.run(());
+            // Let any other open tooltip know it should close.
+            if let Ok(event) = web_sys::CustomEvent::new(TOOLTIP_OPEN) {
+                let _ = window().dispatch_event(&event);
+            }
+        } else {
+            provider_context.on_close.run(());
+        }
+        set_open_state.run(next_open);
+    });
+
+    let state_attribute = Signal::derive(move || {
+        if open.get() {
+            if was_open_delayed.get() {
+                "delayed-open"
+            } else {
+                "instant-open"
+            }
+        } else {
+            "closed"
+        }
+    });
+
+    let clear_open_timer = move || {
+        if let Some(timer) = open_timer.try_get_value().flatten() {
+            timer.clear();
+        }
+        open_timer.try_set_value(None);
+    };
+    on_cleanup(clear_open_timer);
+
+    let handle_open = move || {
+        clear_open_timer();
+        was_open_delayed.set(false);
+        set_open.run(true);
+    };
+
+    let handle_close = move || {
+        clear_open_timer();
+        set_open.run(false);
+    };
+
+    let handle_delayed_open = move || {
+        clear_open_timer();
+        open_timer.set_value(
+            set_timeout_with_handle(
+                move || {
+                    was_open_delayed.set(true);
+                    set_open.run(true);
+                    open_timer.set_value(None);
+                },
+                Duration::from_millis(delay_duration.get_untracked() as u64),
+            )
+            .ok(),
+        );
+    };
+
+    let trigger_leave_handler = StoredValue::new(None::<Callback<ev::PointerEvent>>);
+
+    let context_value = TooltipContextValue {
+        content_id: use_id().into(),
+        open,
+        state_attribute,
+        trigger_ref: AnyNodeRef::new(),
+        on_trigger_enter: Callback::new(move |_| {
+            if provider_context.is_open_delayed.get_untracked() {
+                handle_delayed_open();
+            } else {
+                handle_open();
+            }
+        }),
+        on_trigger_leave: Callback::new(move |event: ev::PointerEvent| {
+            if disable_hoverable_content.get_untracked() {
+                handle_close();
+            } else {
+                // Clear the timer in case the pointer leaves the trigger before the tooltip is opened.
+                clear_open_timer();
+                if let Some(handler) = trigger_leave_handler.get_value() {
+                    handler.run(event);
+                }
+            }
+        }),
+        on_open: Callback::new(move |_| handle_open()),
+        on_close: Callback::new(move |_| handle_close()),
+        disable_hoverable_content,
+        trigger_leave_handler,
+    };
+
+    view! {
+        <TooltipContextProvider value=context_value>
+            <Popper>{children.with_value(|children| children())}</Popper>
+        </TooltipContextProvider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * TooltipTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+const TRIGGER_NAME: &str = "TooltipTrigger";
+
+#[component]
+#[allow(non_snake_case)]
+pub fn TooltipTrigger(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] on_pointer_move: Option<Callback<ev::PointerEvent>>,
+    #[prop(optional, into)] on_pointer_leave: Option<Callback<ev::PointerEvent>>,
+    #[prop(optional, into)] on_pointer_down: Option<Callback<ev::PointerEvent>>,
+    #[prop(optional, into)] on_focus: Option<Callback<ev::FocusEvent>>,
+    #[prop(optional, into)] on_blur: Option<Callback<ev::FocusEvent>>,
+    #[prop(optional, into)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_tooltip_context(TRIGGER_NAME);
+    let provider_context = use_tooltip_provider_context(TRIGGER_NAME);
+    let composed_refs = use_composed_refs((node_ref, context.trigger_ref));
+    let is_pointer_down = StoredValue::new(false);
+    let has_pointer_move_opened = StoredValue::new(false);
+
+    // Only added while the pointer is down, so idle triggers don't listen on the document.
+    let handle_pointer_up =
+        StoredValue::new_local(Closure::<dyn Fn(web_sys::Event)>::new(move |_| {
+            is_pointer_down.set_value(false);
+        }));
+    on_cleanup(move || {
+        handle_pointer_up.try_with_value(|handle_pointer_up| {
+            let _ = document().remove_event_listener_with_callback(
+                "pointerup",
+                handle_pointer_up.as_ref().unchecked_ref(),
+            );
+        });
+    });
+
+    view! {
+        <PopperAnchor as_child=true>
+            <Primitive
+                element=html::button
+                as_child=as_child
+                node_ref=composed_refs
+                // We purposefully avoid adding `type=button` here because tooltip triggers are also
+                // commonly anchors and the anchor `type` attribute signifies MIME type.
+                attr:aria-describedby=move || context.open.get().then(|| context.content_id.get())
+                attr:data-state=move || context.state_attribute.get()
+                on:pointermove=compose_callbacks(
+                    on_pointer_move,
+                    Some(Callback::new(move |event: ev::PointerEvent| {
+                        if event.pointer_type() == "touch" {
+                            return;
+                        }
+                        if !has_pointer_move_opened.get_value()
+                            && !provider_context.is_pointer_in_transit.get_value()
+                        {
+                            context.on_trigger_enter.run(());
+                            has_pointer_move_opened.set_value(true);
+                        }
+                    })),
+                    None,
+                )
+                on:pointerleave=compose_callbacks(
+                    on_pointer_leave,
+                    Some(Callback::new(move |event: ev::PointerEvent| {
+                        context.on_trigger_leave.run(event);
+                        has_pointer_move_opened.set_value(false);
+                    })),
+                    None,
+                )
+                on:pointerdown=compose_callbacks(
+                    on_pointer_down,
+                    Some(Callback::new(move |_: ev::PointerEvent| {
+                        if context.open.get_untracked() {
+                            context.on_close.run(());
+                        }
+                        is_pointer_down.set_value(true);
+
+                        let options = AddEventListenerOptions::new();
+                        options.set_once(true);
+                        handle_pointer_up.with_value(|handle_pointer_up| {
+                            document()
+                                .add_event_listener_with_callback_and_add_event_listener_options(
+                                    "pointerup",
+                                    handle_pointer_up.as_ref().unchecked_ref(),
+                                    &options,
+                                )
+                                .expect("Pointer up event listener should be added.");
+                        });
+                    })),
+                    None,
+                )
+                on:focus=compose_callbacks(
+                    on_focus,
+                    Some(Callback::new(move |_: ev::FocusEvent| {
+                        if !is_pointer_down.get_value() {
+                            context.on_open.run(());
+                        }
+                    })),
+                    None,
+                )
+                on:blur=compose_callbacks(
+                    on_blur,
+                    Some(Callback::new(move |_: ev::FocusEvent| context.on_close.run(()))),
+                    None,
+                )
+                on:click=compose_callbacks(
+                    on_click,
+                    Some(Callback::new(move |_: ev::MouseEvent| context.on_close.run(()))),
+                    None,
+                )
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        </PopperAnchor>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * TooltipPortal
+ * -----------------------------------------------------------------------------------------------*/
+
+const PORTAL_NAME: &str = "TooltipPortal";
+
+#[derive(Clone, Copy)]
+struct TooltipPortalContextValue {
+    force_mount: MaybeProp<bool>,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn TooltipPortal(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    #[prop(optional, into)] container: AnyNodeRef,
+    /// Keep the portal mounted while closed, e.g. to control animations from outside.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_tooltip_context(PORTAL_NAME);
+    let container = StoredValue::new(container);
+
+    view! {
+        <Provider value=TooltipPortalContextValue { force_mount }>
+            <Presence present=Signal::derive(move || {
+                force_mount.get().unwrap_or(false) || context.open.get()
+            })>
+                <PortalPrimitive as_child=true mount=container.with_value(|container| container.get())>
+                    {children.with_value(|children| children())}
+                </PortalPrimitive>
+            </Presence>
+        </Provider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * TooltipContent
+ * -----------------------------------------------------------------------------------------------*/
+
+const CONTENT_NAME: &str = "TooltipContent";
+
+/// Positioning props forwarded to `PopperContent`.
+#[derive(Clone, Copy, Default)]
+struct TooltipContentPlacement {
+    side: MaybeProp<Side>,
+    side_offset: MaybeProp<f64>,
+    align: MaybeProp<Align>,
+    align_offset: MaybeProp<f64>,
+    avoid_collisions: MaybeProp<bool>,
+    collision_padding: MaybeProp<Padding>,
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn TooltipContent(
+    children: TypedChildrenFn<impl IntoView + 'static>,
+    /// A more descriptive label for accessibility purpose, announced instead of the content.
+    #[prop(optional, into)]
+    aria_label: MaybeProp<String>,
+    /// Keep the content mounted while closed, e.g. to control animations from outside.
+    #[prop(optional, into)]
+    force_mount: MaybeProp<bool>,
+    /// The preferred side of the trigger to render against. Defaults to `Top`.
+    #[prop(optional, into)]
+    side: MaybeProp<Side>,
+    #[prop(optional, into)] side_offset: MaybeProp<f64>,
+    #[prop(optional, into)] align: MaybeProp<Align>,
+    #[prop(optional, into)] align_offset: MaybeProp<f64>,
+    #[prop(optional, into)] avoid_collisions: MaybeProp<bool>,
+    #[prop(optional, into)] collision_padding: MaybeProp<Padding>,
+    #[prop(optional, into)] on_escape_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(optional, into)] on_pointer_down_outside: Option<Callback<PointerDownOutsideEvent>>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_tooltip_context(CONTENT_NAME);
+    let portal_context = use_context::<TooltipPortalContextValue>();
+    let force_mount = Signal::derive(move || {
+        force_mount
+            .get()
+            .or_else(|| portal_context.and_then(|portal_context| portal_context.force_mount.get()))
+            .unwrap_or(false)
+    });
+    let placement = TooltipContentPlacement {
+        side: MaybeProp::derive(move || Some(side.get().unwrap_or(Side::Top))),
+        side_offset,
+        align,
+        align_offset,
+        avoid_collisions,
+        collision_padding,
+    };
+
+    view! {
+        <Presence present=Signal::derive(move || force_mount.get() || context.open.get())>
+            <TypedFallbackShow
+                when=move || context.disable_hoverable_content.get()
+                fallback=move || {
+                    view! {
+                        <TooltipContentHoverable
+                            aria_label=aria_label
+                            placement=placement
+                            on_escape_key_down=on_escape_key_down
+                            on_pointer_down_outside=on_pointer_down_outside
+                            node_ref=node_ref
+                            as_child=as_child
+                        >
+                            {children.with_value(|children| children())}
+                        </TooltipContentHoverable>
+                    }
+                }
+            >
+                <TooltipContentImpl
+                    aria_label=aria_label
+                    placement=placement
+                    on_escape_key_down=on_escape_key_down
+                    on_pointer_down_outside=on_pointer_down_outside
+                    node_ref=node_ref
+                    as_child=as_child
+                >
+                    {children.with_value(|children| children())}
+                </TooltipContentImpl>
+            </TypedFallbackShow>
+        </Presence>
+    }
+}
+
+/* ---------------------------------------------------------------------------------------------- */
+
+/// Keeps the tooltip open while the pointer moves from the trigger onto the content, or back.
+#[component]
+#[allow(non_snake_case)]
+fn TooltipContentHoverable<C: IntoView + 'static>(
+    aria_label: MaybeProp<String>,
+    placement: TooltipContentPlacement,
+    on_escape_key_down: Option<Callback<ev::KeyboardEvent>>,
+    on_pointer_down_outside: Option<Callback<PointerDownOutsideEvent>>,
+    node_ref: AnyNodeRef,
+    as_child: MaybeProp<bool>,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_tooltip_context(CONTENT_NAME);
+    let provider_context = use_tooltip_provider_context(CONTENT_NAME);
+    let content_ref = AnyNodeRef::new();
+    let composed_refs = use_composed_refs((node_ref, content_ref));
+    let pointer_grace_area = StoredValue::new(None::<Polygon>);
+
+    let handle_remove_grace_area = move || {
+        pointer_grace_area.set_value(None);
+        provider_context.is_pointer_in_transit.set_value(false);
+    };
+
+    let handle_create_grace_area = move |event: &ev::PointerEvent, hover_target: AnyNodeRef| {
+        let (Some(current_target), Some(hover_target)) = (
+            event
+                .current_target()
+                .and_then(|target| target.dyn_into::<web_sys::Element>().ok()),
+            hover_target.get_untracked(),
+        ) else {
+            return;
+        };
+        let exit_point = Point {
+            x: event.client_x() as f64,
+            y: event.client_y() as f64,
+        };
+        let exit_side = get_exit_side_from_rect(
+            exit_point,
+            (&current_target.get_bounding_client_rect()).into(),
+        );
+        let mut points = get_padded_exit_points(exit_point, exit_side);
+        points.extend(get_points_from_rect(
+            (&hover_target.get_bounding_client_rect()).into(),
+        ));
+        pointer_grace_area.set_value(Some(get_hull(&points)));
+        provider_context.is_pointer_in_transit.set_value(true);
+    };
+
+    context
+        .trigger_leave_handler
+        .set_value(Some(Callback::new(move |event: ev::PointerEvent| {
+            handle_create_grace_area(&event, content_ref);
+        })));
+
+    let pointer_move = window_event_listener(ev::pointermove, move |event| {
+        let Some(grace_area) = pointer_grace_area.get_value() else {
+            return;
+        };
+        let target = event
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+        let contains_target = |node_ref: AnyNodeRef| {
+            node_ref
+                .get_untracked()
+                .is_some_and(|element| element.contains(target.as_ref()))
+        };
+        let point = Point {
+            x: event.client_x() as f64,
+            y: event.client_y() as f64,
+        };
+        let has_entered_target =
+            contains_target(context.trigger_ref) || contains_target(content_ref);
+        let is_pointer_outside_grace_area = !is_point_in_polygon(point, &grace_area);
+
+        if has_entered_target {
+            handle_remove_grace_area();
+        } else if is_pointer_outside_grace_area {
+            handle_remove_grace_area();
+            context.on_close.run(());
+        }
+    });
+
+    on_cleanup(move || {
+        pointer_move.remove();
+        context.trigger_leave_handler.try_set_value(None);
+        pointer_grace_area.try_set_value(None);
+        provider_context.is_pointer_in_transit.set_value(false);
+    });
+
+    view! {
+        <TooltipContentImpl
+            aria_label=aria_label
+            placement=placement
+            on_escape_key_down=on_escape_key_down
+            on_pointer_down_outside=on_pointer_down_outside
+            on_pointer_leave=Callback::new(move |event: ev::PointerEvent| {
+                handle_create_grace_area(&event, context.trigger_ref);
+            })
+            node_ref=composed_refs
+            as_child=as_child
+        >
+            {children.with_value(|children| children())}
+        </TooltipContentImpl>
+    }
+}
+
+#[component]
+#[allow(non_snake_case)]
+fn TooltipContentImpl<C: IntoView + 'static>(
+    aria_label: MaybeProp<String>,
+    placement: TooltipContentPlacement,
+    on_escape_key_down: Option<Callback<ev::KeyboardEvent>>,
+    on_pointer_down_outside: Option<Callback<PointerDownOutsideEvent>>,
+    #[prop(optional, into)] on_pointer_leave: Option<Callback<ev::PointerEvent>>,
+    node_ref: AnyNodeRef,
+    as_child: MaybeProp<bool>,
+    children: TypedChildrenFn<C>,
+) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
+    let context = use_tooltip_context(CONTENT_NAME);
+
+    // Close this tooltip if another one opens.
+    let tooltip_open = window_event_listener_untyped(TOOLTIP_OPEN, move |_| {
+        context.on_close.run(());
+    });
+    on_cleanup(move || tooltip_open.remove());
+
+    // Close when an ancestor of the trigger scrolls, as the tooltip would no longer line up with it.
+    // Scroll events don't bubble, so the listener captures them on the window.
+    let handle_scroll = Closure::<dyn Fn(web_sys::Event)>::new(move |event: web_sys::Event| {
+        let Some(trigger) = context.trigger_ref.get_untracked() else {
+            return;
+        };
+        let is_trigger_ancestor = event
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+            .is_some_and(|target| target.contains(Some(trigger.unchecked_ref())));
+        if is_trigger_ancestor {
+            context.on_close.run(());
+        }
+    });
+    window()
+        .add_event_listener_with_callback_and_bool(
+            "scroll",
+            handle_scroll.as_ref().unchecked_ref(),
+            true,
+        )
+        .expect("Scroll event listener should be added.");
+    let handle_scroll = SendWrapper::new(handle_scroll);
+    on_cleanup(move || {
+        let handle_scroll = handle_scroll.take();
+        window()
+            .remove_event_listener_with_callback_and_bool(
+                "scroll",
+                handle_scroll.as_ref().unchecked_ref(),
+                true,
+            )
+            .expect("Scroll event listener should be removed.");
+    });
+
+    view! {
+        <DismissableLayer
+            as_child=true
+            disable_outside_pointer_events=false
+            on_escape_key_down=on_escape_key_down
+            on_pointer_down_outside=on_pointer_down_outside
+            on_focus_outside=Callback::new(move |event: FocusOutsideEvent| event.prevent_default())
+            on_dismiss=Callback::new(move |_| context.on_close.run(()))
+        >
+            <PopperContent
+                side=placement.side
+                side_offset=placement.side_offset
+                align=placement.align
+                align_offset=placement.align_offset
+                avoid_collisions=placement.avoid_collisions
+                collision_padding=placement.collision_padding
+                as_child=as_child
+                node_ref=node_ref
+                attr:data-state=move || context.state_attribute.get()
+                on:pointerleave=move |event: ev::PointerEvent| {
+                    if let Some(on_pointer_leave) = on_pointer_leave {
+                        on_pointer_leave.run(event);
+                    }
+                }
+                style:--radix-tooltip-content-transform-origin="var(--radix-popper-transform-origin)"
+                style:--radix-tooltip-content-available-width="var(--radix-popper-available-width)"
+                style:--radix-tooltip-content-available-height="var(--radix-popper-available-height)"
+                style:--radix-tooltip-trigger-width="var(--radix-popper-anchor-width)"
+                style:--radix-tooltip-trigger-height="var(--radix-popper-anchor-height)"
+            >
+                {children.with_value(|children| children())}
+                // The visible content isn't announced, `aria-describedby` on the trigger points here.
+                <span
+                    role="tooltip"
+                    id=context.content_id
+                    style:position="absolute"
+                    style:border="0"
+                    style:width="1px"
+                    style:height="1px"
+                    style:padding="0"
+                    style:margin="-1px"
+                    style:overflow="hidden"
+                    style:clip="rect(0, 0, 0, 0)"
+                    style:white-space="nowrap"
+                    style:word-wrap="normal"
+                >
+                    {move || match aria_label.get() {
+                        Some(aria_label) => aria_label.into_any(),
+                        None => children.with_value(|children| children()).into_any(),
+                    }}
+                </span>
+            </PopperContent>
+        </DismissableLayer>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * TooltipArrow
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+#[allow(non_snake_case)]
+pub fn TooltipArrow(
+    #[prop(optional, into)] width: MaybeProp<f64>,
+    #[prop(optional, into)] height: MaybeProp<f64>,
+    #[prop(optional, into)] as_child: MaybeProp<bool>,
+    #[prop(optional, into)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    view! {
+        <PopperArrow width=width height=height as_child=as_child node_ref=node_ref>
+            {children.with_value(|children| children.as_ref().map(|children| children()))}
+        </PopperArrow>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Grace area
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+type Polygon = Vec<Point>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Rect {
+    left: f64,
+    top: f64,
+    right: f64,
+    bottom: f64,
+}
+
+impl From<&web_sys::DomRect> for Rect {
+    fn from(rect: &web_sys::DomRect) -> Self {
+        Self {
+            left: rect.left(),
+            top: rect.top(),
+            right: rect.right(),
+            bottom: rect.bottom(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ExitSide {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+fn get_exit_side_from_rect(point: Point, rect: Rect) -> ExitSide {
+    let top = (rect.top - point.y).abs();
+    let bottom = (rect.bottom - point.y).abs();
+    let right = (rect.right - point.x).abs();
+    let left = (rect.left - point.x).abs();
+
+    let min = top.min(bottom).min(right).min(left);
+    if min == left {
+        ExitSide::Left
+    } else if min == right {
+        ExitSide::Right
+    } else if min == top {
+        ExitSide::Top
+    } else {
+        ExitSide::Bottom
+    }
+}
+
+fn get_padded_exit_points(exit_point: Point, exit_side: ExitSide) -> Vec<Point> {
+    let padding = 5.0;
+    let Point { x, y } = exit_point;
+    match exit_side {
+        ExitSide::Top => vec![
+            Point {
+                x: x - padding,
+                y: y + padding,
+            },
+            Point {
+                x: x + padding,
+                y: y + padding,
+            },
+        ],
+        ExitSide::Bottom => vec![
+            Point {
+                x: x - padding,
+                y: y - padding,
+            },
+            Point {
+                x: x + padding,
+                y: y - padding,
+            },
+        ],
+        ExitSide::Left => vec![
+            Point {
+                x: x + padding,
+                y: y - padding,
+            },
+            Point {
+                x: x + padding,
+                y: y + padding,
+            },
+        ],
+        ExitSide::Right => vec![
+            Point {
+                x: x - padding,
+                y: y - padding,
+            },
+            Point {
+                x: x - padding,
+                y: y + padding,
+            },
+        ],
+    }
+}
+
+fn get_points_from_rect(rect: Rect) -> Vec<Point> {
+    vec![
+        Point {
+            x: rect.left,
+            y: rect.top,
+        },
+        Point {
+            x: rect.right,
+            y: rect.top,
+        },
+        Point {
+            x: rect.right,
+            y: rect.bottom,
+        },
+        Point {
+            x: rect.left,
+            y: rect.bottom,
+        },
+    ]
+}
+
+/// Determine if a point is inside of a polygon.
+fn is_point_in_polygon(point: Point, polygon: &[Point]) -> bool {
+    let Point { x, y } = point;
+    let mut inside = false;
+
+    if polygon.is_empty() {
+        return inside;
+    }
+
+    let mut j = polygon.len() - 1;
+    for (i, &Point { x: xi, y: yi }) in polygon.iter().enumerate() {
+        let Point { x: xj, y: yj } = polygon[j];
+
+        let intersect = ((yi > y) != (yj > y)) && (x < (xj - xi) * (y - yi) / (yj - yi) + xi);
+        if intersect {
+            inside = !inside;
+        }
+        j = i;
+    }
+
+    inside
+}
+
+/// Returns the convex hull of `points` in counter-clockwise order (Andrew's monotone chain).
+fn get_hull(points: &[Point]) -> Polygon {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+
+    if points.len() <= 1 {
+        return points;
+    }
+
+    fn cross(o: Point, a: Point, b: Point) -> f64 {
+        (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+    }
+
+    let half_hull = |points: &mut dyn Iterator<Item = &Point>| {
+        let mut hull: Vec<Point> = Vec::new();
+        for &point in points {
+            while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0.0
+            {
+                hull.pop();
+            }
+            hull.push(point);
+        }
+        // The last point of each half is the first point of the other.
+        hull.pop();
+        hull
+    };
+
+    let mut hull = half_hull(&mut points.iter());
+    hull.extend(half_hull(&mut points.iter().rev()));
+    hull
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Primitive re-exports
+ * -----------------------------------------------------------------------------------------------*/
+
+pub mod primitive {
+    pub use super::*;
+    pub use Tooltip as Root;
+    pub use TooltipArrow as Arrow;
+    pub use TooltipContent as Content;
+    pub use TooltipPortal as Portal;
+    pub use TooltipProvider as Provider;
+    pub use TooltipTrigger as Trigger;
+}
@@ -45,12 +45,8 @@ pub fn NavMain(items: Vec<NavMainItem>) -> impl IntoView {
                         let item = StoredValue::new(item);
 
                         view! {
-                            <Collapsible
-                                as_child=true
-                                default_open=is_open
-                                class="group/collapsible"
-                            >
-                                <SidebarMenuItem>
+                            <Collapsible as_child=true default_open=is_open>
+                                <SidebarMenuItem class="group/collapsible">
                                     <CollapsibleTrigger as_child=true>
                                         <SidebarMenuButton tooltip=item.with_value(|v| v
                                             .title
@@ -1,7 +1,9 @@
 use leptos::prelude::*;
 use lucide_leptos::{Sparkles, BadgeCheck, CreditCard, Bell, LogOut, ChevronsUpDown};
+use radix_leptos_hover_card::Side;
 use crate::components::hooks::use_is_mobile;
 use crate::components::ui::avatar::{Avatar, AvatarFallback, AvatarImage};
+use crate::components::ui::hover_card::{HoverCard, HoverCardContent, HoverCardTrigger};
 use crate::components::ui::dropdown_menu::{
     DropdownMenu, DropdownMenuTrigger, DropdownMenuContent, DropdownMenuGroup,
     DropdownMenuItem, DropdownMenuLabel, DropdownMenuSeparator,
@@ -49,10 +51,26 @@ pub fn NavUser(user: User) -> impl IntoView {
                             size=SidebarMenuButtonSize::Lg
                             class="data-[state=open]:bg-sidebar-accent data-[state=open]:text-sidebar-accent-foreground"
                         >
-                            <Avatar class="w-8 h-8 rounded-lg mr-2">
-                                <AvatarImage src=user.with_value(|v| v.avatar.clone()) alt=user.with_value(|v| v.name.clone()) />
-                                <AvatarFallback class="rounded-lg">{initials}</AvatarFallback>
-                            </Avatar>
+                            <HoverCard>
+                                <HoverCardTrigger as_child=true>
+                                    <Avatar class="w-8 h-8 rounded-lg mr-2">
+                                        <AvatarImage src=user.with_value(|v| v.avatar.clone()) alt=user.with_value(|v| v.name.clone()) />
+                                        <AvatarFallback class="rounded-lg">{initials}</AvatarFallback>
+                                    </Avatar>
+                                </HoverCardTrigger>
+                                <HoverCardContent side=Side::Right class="w-72">
+                                    <div class="flex gap-4">
+                                        <Avatar class="w-12 h-12 rounded-lg">
+                                            <AvatarImage src=user.with_value(|v| v.avatar.clone()) alt=user.with_value(|v| v.name.clone()) />
+                                            <AvatarFallback class="rounded-lg">{initials}</AvatarFallback>
+                                        </Avatar>
+                                        <div class="grid gap-1 text-sm">
+                                            <span class="font-semibold">{user.with_value(|v| v.name.clone())}</span>
+                                            <span class="text-xs text-muted-foreground">{user.with_value(|v| v.email.clone())}</span>
+                                        </div>
+                                    </div>
+                                </HoverCardContent>
+                            </HoverCard>
                             <div class="grid flex-1 text-sm leading-tight text-left">
                                 <span class="font-semibold truncate">{user.with_value(|v| v.name.clone())}</span>
                                 <span class="text-xs truncate">{user.with_value(|v| v.email.clone())}</span>
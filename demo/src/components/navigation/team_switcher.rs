@@ -4,10 +4,12 @@ use crate::components::ui::dropdown_menu::{
     DropdownMenu, DropdownMenuContent, DropdownMenuItem, DropdownMenuLabel,
     DropdownMenuSeparator, DropdownMenuShortcut, DropdownMenuTrigger,
 };
+use crate::components::ui::hover_card::{HoverCard, HoverCardContent, HoverCardTrigger};
 use crate::components::ui::sidebar::{
     SidebarMenu, SidebarMenuButton, SidebarMenuButtonSize, SidebarMenuItem, use_sidebar,
 };
 use leptos::control_flow::ForEnumerate;
+use radix_leptos_hover_card::Side;
 use tailwind_fuse::tw_merge;
 
 use crate::components::navigation::nav_main::IconViewFn;
@@ -45,10 +47,22 @@ pub fn TeamSwitcher(teams: Vec<Team>) -> impl IntoView {
                             size=SidebarMenuButtonSize::Lg
                             class="data-[state=open]:bg-sidebar-accent data-[state=open]:text-sidebar-accent-foreground"
                         >
-                            // Team logo
-                            <div class=tw_merge!(
-                                "flex aspect-square w-8 h-8 items-center justify-center rounded-lg bg-sidebar-primary text-sidebar-primary-foreground mr-2"
-                            )>{render_logo(&active_team.get())}</div>
+                            // Team logo, previews the active team on hover
+                            <HoverCard>
+                                <HoverCardTrigger as_child=true>
+                                    <div class=tw_merge!(
+                                        "flex aspect-square w-8 h-8 items-center justify-center rounded-lg bg-sidebar-primary text-sidebar-primary-foreground mr-2"
+                                    )>{render_logo(&active_team.get())}</div>
+                                </HoverCardTrigger>
+                                <HoverCardContent side=Side::Right>
+                                    <div class="grid gap-1 text-sm">
+                                        <span class="font-semibold">{move || active_team.get().name.clone()}</span>
+                                        <span class="text-xs text-muted-foreground">
+                                            {move || format!("{} plan", active_team.get().plan)}
+                                        </span>
+                                    </div>
+                                </HoverCardContent>
+                            </HoverCard>
 
                             <div class="grid flex-1 text-left text-sm leading-tight">
                                 <span class="truncate font-semibold">
@@ -0,0 +1,172 @@
+use leptos::prelude::*;
+use radix_leptos_command_palette::primitive as CommandPrimitive;
+use radix_leptos_dialog::primitive as DialogPrimitive;
+
+use crate::cn;
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn Command(
+    #[prop(optional, into)] label: MaybeProp<String>,
+    #[prop(optional, into)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    view! {
+        <CommandPrimitive::Root
+            label=label
+            attr:class=move || {
+                cn!(
+                    "flex h-full w-full flex-col overflow-hidden rounded-md bg-popover text-popover-foreground", class.get()
+                )
+            }
+        >
+            {children.with_value(|children| children())}
+        </CommandPrimitive::Root>
+    }
+}
+
+/// A `Command` in a modal dialog, e.g. opened with a keyboard shortcut.
+#[component]
+#[allow(non_snake_case)]
+pub fn CommandDialog(
+    #[prop(optional, into)] open: MaybeProp<bool>,
+    #[prop(optional, into)] on_open_change: Option<Callback<bool>>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    view! {
+        <DialogPrimitive::Root open=open on_open_change=on_open_change>
+            <DialogPrimitive::Portal>
+                <DialogPrimitive::Overlay attr:class="fixed inset-0 z-50 bg-black/80 data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0" />
+                <DialogPrimitive::Content attr:class="fixed left-[50%] top-[50%] z-50 grid w-full max-w-lg translate-x-[-50%] translate-y-[-50%] overflow-hidden border bg-background p-0 shadow-lg duration-200 data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0 data-[state=closed]:zoom-out-95 data-[state=open]:zoom-in-95 sm:rounded-lg">
+                    <DialogPrimitive::Title attr:class="sr-only">"Command palette"</DialogPrimitive::Title>
+                    <Command label="Command palette">
+                        {children.with_value(|children| children())}
+                    </Command>
+                </DialogPrimitive::Content>
+            </DialogPrimitive::Portal>
+        </DialogPrimitive::Root>
+    }
+}
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn CommandInput(
+    #[prop(optional, into)] placeholder: MaybeProp<String>,
+    #[prop(optional, into)] class: MaybeProp<String>,
+) -> impl IntoView {
+    view! {
+        <div class="flex items-center border-b px-3">
+            <CommandPrimitive::Input
+                placeholder=placeholder
+                attr:class=move || {
+                    cn!(
+                        "flex h-11 w-full rounded-md bg-transparent py-3 text-sm outline-none placeholder:text-muted-foreground disabled:cursor-not-allowed disabled:opacity-50", class.get()
+                    )
+                }
+            />
+        </div>
+    }
+}
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn CommandList(
+    #[prop(optional, into)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    view! {
+        <CommandPrimitive::List attr:class=move || cn!("max-h-[300px] overflow-y-auto overflow-x-hidden", class.get())>
+            {children.with_value(|children| children())}
+        </CommandPrimitive::List>
+    }
+}
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn CommandEmpty(
+    #[prop(optional, into)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    view! {
+        <CommandPrimitive::Empty attr:class=move || cn!("py-6 text-center text-sm", class.get())>
+            {children.with_value(|children| children())}
+        </CommandPrimitive::Empty>
+    }
+}
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn CommandGroup(
+    #[prop(optional, into)] heading: MaybeProp<String>,
+    #[prop(optional, into)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    view! {
+        <CommandPrimitive::Group
+            heading=heading
+            attr:class=move || {
+                cn!(
+                    "overflow-hidden p-1 text-foreground [&_[data-radix-command-palette-group-heading]]:px-2 [&_[data-radix-command-palette-group-heading]]:py-1.5 [&_[data-radix-command-palette-group-heading]]:text-xs [&_[data-radix-command-palette-group-heading]]:font-medium [&_[data-radix-command-palette-group-heading]]:text-muted-foreground", class.get()
+                )
+            }
+        >
+            {children.with_value(|children| children())}
+        </CommandPrimitive::Group>
+    }
+}
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn CommandSeparator(
+    #[prop(optional, into)] class: MaybeProp<String>,
+) -> impl IntoView {
+    view! {
+        <CommandPrimitive::Separator attr:class=move || cn!("-mx-1 h-px bg-border", class.get()) />
+    }
+}
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn CommandItem(
+    #[prop(into)] value: String,
+    #[prop(optional, into)] keywords: Vec<String>,
+    #[prop(optional, into)] disabled: MaybeProp<bool>,
+    #[prop(optional, into)] on_select: Option<Callback<String>>,
+    #[prop(optional, into)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    view! {
+        <CommandPrimitive::Item
+            value=value
+            keywords=keywords
+            disabled=disabled
+            on_select=on_select
+            attr:class=move || {
+                cn!(
+                    "relative flex cursor-default select-none items-center gap-2 rounded-sm px-2 py-1.5 text-sm outline-none data-[disabled]:pointer-events-none data-[highlighted]:bg-accent data-[highlighted]:text-accent-foreground data-[disabled]:opacity-50", class.get()
+                )
+            }
+        >
+            {children.with_value(|children| children())}
+        </CommandPrimitive::Item>
+    }
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn CommandShortcut(
+    #[prop(optional, into)] class: String,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <span class=cn!(
+            "ml-auto text-xs tracking-widest text-muted-foreground", &class
+        )>{children()}</span>
+    }
+}
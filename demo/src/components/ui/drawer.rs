@@ -0,0 +1,109 @@
+use leptos::prelude::*;
+use radix_leptos_drawer::primitive as DrawerPrimitive;
+use radix_leptos_drawer::{use_drawer_direction, DrawerDirection};
+
+use crate::cn;
+
+pub use DrawerPrimitive::Root as Drawer;
+
+pub use DrawerPrimitive::Trigger as DrawerTrigger;
+
+pub use DrawerPrimitive::Close as DrawerClose;
+
+pub use DrawerPrimitive::Portal as DrawerPortal;
+
+fn get_direction_classes(direction: DrawerDirection) -> &'static str {
+    match direction {
+        DrawerDirection::Top => "inset-x-0 top-0 mb-24 max-h-[80vh] rounded-b-[10px] border-b data-[state=closed]:slide-out-to-top data-[state=open]:slide-in-from-top",
+        DrawerDirection::Bottom => "inset-x-0 bottom-0 mt-24 max-h-[80vh] rounded-t-[10px] border-t data-[state=closed]:slide-out-to-bottom data-[state=open]:slide-in-from-bottom",
+        DrawerDirection::Left => "inset-y-0 left-0 h-full w-3/4 border-r data-[state=closed]:slide-out-to-left data-[state=open]:slide-in-from-left sm:max-w-sm",
+        DrawerDirection::Right => "inset-y-0 right-0 h-full w-3/4 border-l data-[state=closed]:slide-out-to-right data-[state=open]:slide-in-from-right sm:max-w-sm",
+    }
+}
+
+const BASE_DRAWER_CLASSES: &str = "fixed z-50 flex flex-col bg-background data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:duration-300 data-[state=open]:duration-500";
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn DrawerOverlay(
+    #[prop(optional, into)] class: MaybeProp<String>,
+) -> impl IntoView {
+    view! {
+        <DrawerPrimitive::Overlay
+            attr:class=move || {
+                cn!(
+                    "fixed inset-0 z-50 bg-black/80 data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0", class.get()
+                )
+            }
+        />
+    }
+}
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn DrawerContent(
+    #[prop(optional, into)] class: MaybeProp<String>,
+    #[prop(optional, into)] style: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    let direction = use_drawer_direction();
+
+    view! {
+        <DrawerPortal>
+            <DrawerOverlay />
+            <DrawerPrimitive::Content
+                attr:class=move || {
+                    cn!(BASE_DRAWER_CLASSES, get_direction_classes(direction.get()), class.get())
+                }
+                attr:style=move || style.get()
+            >
+                <Show when=move || direction.get() == DrawerDirection::Bottom>
+                    <DrawerPrimitive::Handle attr:class="mx-auto mt-4 h-2 w-[100px] shrink-0 rounded-full bg-muted" />
+                </Show>
+                {children.with_value(|children| children())}
+            </DrawerPrimitive::Content>
+        </DrawerPortal>
+    }
+}
+
+#[component]
+#[allow(non_snake_case)]
+pub fn DrawerHeader(
+    #[prop(optional, into)] class: String,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <div class=cn!(
+            "grid gap-1.5 p-4 text-center sm:text-left", &class
+        )>{children()}</div>
+    }
+}
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn DrawerTitle(
+    #[prop(optional, into)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    view! {
+        <DrawerPrimitive::Title attr:class=move || cn!("text-lg font-semibold leading-none tracking-tight", class.get())>
+            {children.with_value(|children| children())}
+        </DrawerPrimitive::Title>
+    }
+}
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn DrawerDescription(
+    #[prop(optional, into)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    view! {
+        <DrawerPrimitive::Description attr:class=move || cn!("text-sm text-muted-foreground", class.get())>
+            {children.with_value(|children| children())}
+        </DrawerPrimitive::Description>
+    }
+}
@@ -8,7 +8,7 @@ use leptos::{
 };
 use leptos_node_ref::prelude::{AnyNodeRef, IntoAnyNodeRef};
 use lucide_leptos::{Check, ChevronRight, Circle};
-// use radix_leptos_checkbox::{CheckedState, ToChecked};
+use radix_leptos_checkbox::CheckedState;
 use radix_leptos_dropdown_menu::primitive as DropdownMenuPrimitive;
 
 pub use DropdownMenuPrimitive::Root as DropdownMenu;
@@ -100,6 +100,8 @@ pub fn DropdownMenuContent(
 pub fn DropdownMenuItem(
     children: ChildrenFn,
     #[prop(optional, into)] disabled: MaybeProp<bool>,
+    #[prop(optional, into)] text_value: MaybeProp<String>,
+    #[prop(optional, into)] on_select: Option<Callback<web_sys::Event>>,
     #[prop(optional, into)] inset: MaybeProp<bool>,
     #[prop(optional, into)] class: MaybeProp<String>,
     #[prop(optional, into)] node_ref: AnyNodeRef,
@@ -108,6 +110,8 @@ pub fn DropdownMenuItem(
     view! {
         <DropdownMenuPrimitive::Item
             disabled=disabled
+            text_value=text_value
+            on_select=on_select
             node_ref=node_ref
             attr:class=move || {
                 cn!(
@@ -120,52 +124,66 @@ pub fn DropdownMenuItem(
     }
 }
 
-// #[component(transparent)]
-// #[allow(non_snake_case)]
-// pub fn DropdownMenuCheckboxItem(
-//     children: ChildrenFn,
-//     #[prop(into, optional)] checked: MaybeProp<CheckedState>,
-//     #[prop(into, optional)] node_ref: AnyNodeRef,
-// ) -> impl IntoView {
-//     let children = StoredValue::new(children);
-//     view! {
-//         <DropdownMenuPrimitive::CheckboxItem
-//             node_ref=node_ref
-//             checked={checked}
-//             attr:class=move || cn!("relative flex cursor-default select-none items-center rounded-sm py-1.5 pl-8 pr-2 text-sm outline-none transition-colors focus:bg-accent focus:text-accent-foreground data-[disabled]:pointer-events-none data-[disabled]:opacity-50")
-//         >
-//             <span class="absolute left-2 flex justify-center items-center w-3.5 h-3.5">
-//                 <DropdownMenuPrimitive::ItemIndicator>
-//                     <Check class:w-4=true class:h-4=true />
-//                 </DropdownMenuPrimitive::ItemIndicator>
-//             </span>
-//             {children.with_value(|children| children())}
-//         </DropdownMenuPrimitive::CheckboxItem>
-//     }
-// }
-
-// #[component(transparent)]
-// #[allow(non_snake_case)]
-// pub fn DropdownMenuRadioItem<T: 'static + Send + Sync>(
-//     children: ChildrenFn,
-//     #[prop(into, optional)] value: MaybeProp<T>,
-//     #[prop(into, optional)] node_ref: AnyNodeRef,
-// ) -> impl IntoView {
-//     let children = StoredValue::new(children);
-//     view! {
-//         <DropdownMenuPrimitive::RadioItem
-//             node_ref={node_ref}
-//             attr:class=move || cn!("relative flex cursor-default select-none items-center rounded-sm py-1.5 pl-8 pr-2 text-sm outline-none transition-colors focus:bg-accent focus:text-accent-foreground data-[disabled]:pointer-events-none data-[disabled]:opacity-50")
-//         >
-//             <span class="absolute left-2 flex justify-center items-center w-3.5 h-3.5">
-//                 <DropdownMenuPrimitive::ItemIndicator>
-//                     <Circle attr:class="w-2 h-2 fill-current" />
-//                 </DropdownMenuPrimitive::ItemIndicator>
-//             </span>
-//             {children.with_value(|children| children())}
-//         </DropdownMenuPrimitive::RadioItem>
-//     }
-// }
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn DropdownMenuCheckboxItem(
+    children: ChildrenFn,
+    #[prop(into, optional)] checked: MaybeProp<CheckedState>,
+    #[prop(into, optional)] default_checked: MaybeProp<CheckedState>,
+    #[prop(into, optional)] on_checked_change: Option<Callback<CheckedState>>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    view! {
+        <DropdownMenuPrimitive::CheckboxItem
+            node_ref=node_ref
+            checked=checked
+            default_checked=default_checked
+            on_checked_change=on_checked_change
+            disabled=disabled
+            attr:class=move || cn!("relative flex cursor-default select-none items-center rounded-sm py-1.5 pl-8 pr-2 text-sm outline-none transition-colors focus:bg-accent focus:text-accent-foreground data-[disabled]:pointer-events-none data-[disabled]:opacity-50", class.get())
+        >
+            <span class="absolute left-2 flex justify-center items-center w-3.5 h-3.5">
+                <DropdownMenuPrimitive::ItemIndicator>
+                    <Check class:w-4=true class:h-4=true />
+                </DropdownMenuPrimitive::ItemIndicator>
+            </span>
+            {children.with_value(|children| children())}
+        </DropdownMenuPrimitive::CheckboxItem>
+    }
+}
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn DropdownMenuRadioItem<T>(
+    children: ChildrenFn,
+    value: T,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+) -> impl IntoView
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let children = StoredValue::new(children);
+    view! {
+        <DropdownMenuPrimitive::RadioItem
+            node_ref=node_ref
+            value=value
+            disabled=disabled
+            attr:class=move || cn!("relative flex cursor-default select-none items-center rounded-sm py-1.5 pl-8 pr-2 text-sm outline-none transition-colors focus:bg-accent focus:text-accent-foreground data-[disabled]:pointer-events-none data-[disabled]:opacity-50", class.get())
+        >
+            <span class="absolute left-2 flex justify-center items-center w-3.5 h-3.5">
+                <DropdownMenuPrimitive::ItemIndicator>
+                    <Circle attr:class="w-2 h-2 fill-current" />
+                </DropdownMenuPrimitive::ItemIndicator>
+            </span>
+            {children.with_value(|children| children())}
+        </DropdownMenuPrimitive::RadioItem>
+    }
+}
 
 #[component(transparent)]
 #[allow(non_snake_case)]
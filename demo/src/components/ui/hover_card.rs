@@ -0,0 +1,40 @@
+use leptos::{component, prelude::*};
+use leptos_node_ref::prelude::AnyNodeRef;
+use radix_leptos_hover_card::primitive as HoverCardPrimitive;
+use radix_leptos_hover_card::{Align, Side};
+
+pub use HoverCardPrimitive::Root as HoverCard;
+
+pub use HoverCardPrimitive::Trigger as HoverCardTrigger;
+
+use crate::cn;
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn HoverCardContent(
+    children: ChildrenFn,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    #[prop(into, optional)] side: MaybeProp<Side>,
+    #[prop(into, optional, default = Align::Center.into())] align: MaybeProp<Align>,
+    #[prop(into, optional, default = 4.0.into())] side_offset: MaybeProp<f64>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    view! {
+        <HoverCardPrimitive::Portal>
+            <HoverCardPrimitive::Content
+                side=side
+                align=align
+                side_offset=side_offset
+                attr:class=move || {
+                    cn!(
+                        "z-50 w-64 rounded-md border bg-popover p-4 text-popover-foreground shadow-md outline-none data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0 data-[state=closed]:zoom-out-95 data-[state=open]:zoom-in-95 data-[side=bottom]:slide-in-from-top-2 data-[side=left]:slide-in-from-right-2 data-[side=right]:slide-in-from-left-2 data-[side=top]:slide-in-from-bottom-2", class.get()
+                    )
+                }
+                node_ref=node_ref
+            >
+                {children.with_value(|children| children())}
+            </HoverCardPrimitive::Content>
+        </HoverCardPrimitive::Portal>
+    }
+}
@@ -3,6 +3,11 @@ pub(crate) mod breadcrumb;
 pub(crate) mod separator;
 pub(crate) mod sidebar;
 pub(crate) mod sheet;
+pub(crate) mod drawer;
 pub(crate) mod button;
 pub(crate) mod avatar;
-pub(crate) mod collapsible;
\ No newline at end of file
+pub(crate) mod collapsible;
+pub(crate) mod tooltip;
+pub(crate) mod hover_card;
+pub(crate) mod tabs;
+pub(crate) mod command;
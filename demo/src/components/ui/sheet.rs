@@ -1,5 +1,15 @@
-use std::sync::Arc;
 use leptos::prelude::*;
+use radix_leptos_dialog::primitive as SheetPrimitive;
+
+use crate::cn;
+
+pub use SheetPrimitive::Root as Sheet;
+
+pub use SheetPrimitive::Trigger as SheetTrigger;
+
+pub use SheetPrimitive::Close as SheetClose;
+
+pub use SheetPrimitive::Portal as SheetPortal;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub enum SheetSide {
@@ -46,157 +56,64 @@ fn get_side_classes(side: &SheetSide) -> &'static str {
 
 const BASE_SHEET_CLASSES: &str = "fixed z-50 gap-4 bg-background p-6 shadow-lg transition ease-in-out data-[state=closed]:duration-300 data-[state=open]:duration-500 data-[state=open]:animate-in data-[state=closed]:animate-out";
 
-#[derive(Clone)]
-pub struct SheetContext {
-    pub is_open: RwSignal<bool>,
-    pub on_close: Callback<()>,
-}
-
-#[component]
-#[allow(non_snake_case)]
-pub fn Sheet(
-    #[prop(optional)] open: Option<RwSignal<bool>>,
-    #[prop(optional)] default_open: Option<bool>,
-    #[prop(optional)] on_open_change: Option<Callback<bool>>,
-    children: Children,
-) -> impl IntoView {
-    let internal_open = RwSignal::new(default_open.unwrap_or(false));
-    let is_open = open.unwrap_or(internal_open);
-
-    // Ensure on_open_change is properly cloned and thread-safe
-    let on_open_change = on_open_change.clone();
-
-    let on_close = Callback::new(move |_| {
-        is_open.set(false);
-        if let Some(callback) = on_open_change.as_ref() {
-            callback.run(false);
-        }
-    });
-
-    let context = SheetContext {
-        is_open: is_open.clone(),
-        on_close: on_close.clone(),
-    };
-
-    provide_context(context);
-
-    view! {
-        <div class="sheet-root" data-state=move || if is_open.get() { "open" } else { "closed" }>
-            {children()}
-        </div>
-    }
-}
-
-use leptos::*;
-use leptos::prelude::*;
-use crate::cn;
-
-#[component]
-#[allow(non_snake_case)]
-pub fn SheetTrigger(
-    #[prop(optional, into)] class: String,
-    children: Children,
-) -> impl IntoView {
-    let context = use_context::<SheetContext>().expect("SheetTrigger must be used within Sheet");
-    // let children = children()
-    //     .nodes
-    //     .into_iter()
-    //     .map(|child| view! { <li>{child.as_element().}</li> })
-    //     .collect_view();
-
-    // view! {
-    //     <ul>{children}</ul>
-    // }
-
-
-    view! {
-        <div class=class role="button" on:click=move |_| context.is_open.set(true)>
-            {children()}
-        </div>
-    }
-}
-
-#[component]
+#[component(transparent)]
 #[allow(non_snake_case)]
 pub fn SheetOverlay(
-    #[prop(optional, into)] class: String,
+    #[prop(optional, into)] class: MaybeProp<String>,
 ) -> impl IntoView {
-    let context = use_context::<SheetContext>().expect("SheetOverlay must be used within Sheet");
-
     view! {
-        <div
-            class=cn!(
-                "fixed inset-0 z-50 bg-black/80 data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0", &class
-            )
-            data-state=move || if context.is_open.get() { "open" } else { "closed" }
-            on:click=move |_| context.on_close.run(())
+        <SheetPrimitive::Overlay
+            attr:class=move || {
+                cn!(
+                    "fixed inset-0 z-50 bg-black/80 data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0", class.get()
+                )
+            }
         />
     }
 }
-use derive_more::Display;
 
-#[component]
+#[component(transparent)]
 #[allow(non_snake_case)]
 pub fn SheetContent(
-    #[prop(optional, into)] class: String,
+    #[prop(optional, into)] class: MaybeProp<String>,
+    #[prop(optional, into)] style: MaybeProp<String>,
     #[prop(optional, into)] side: SheetSide,
-    children: Children,
+    children: ChildrenFn,
 ) -> impl IntoView {
-    let context = use_context::<SheetContext>().expect("SheetContent must be used within Sheet");
+    let children = StoredValue::new(children);
     let variant_class = get_side_classes(&side);
 
-    let is_open = context.is_open;
-    let fragment = children();
-
     view! {
-        <div class="sheet-portal" class:hidden=move || !is_open.get()>
+        <SheetPortal>
             <SheetOverlay />
-            <div
-                class=cn!(BASE_SHEET_CLASSES, variant_class, &class)
-                data-state=move || if is_open.get() { "open" } else { "closed" }
-            >
-                <SheetClose />
-                {fragment}
-            </div>
-        </div>
-    }
-}
-
-#[component]
-pub fn SheetClose() -> impl IntoView {
-    let context = use_context::<SheetContext>().expect("SheetClose must be used within Sheet");
-
-    view! {
-        <button
-            class="absolute top-4 right-4 rounded-sm opacity-70 transition-opacity hover:opacity-100 focus:ring-2 focus:ring-offset-2 focus:outline-none disabled:pointer-events-none ring-offset-background data-[state=open]:bg-secondary focus:ring-ring"
-            on:click=move |_| context.on_close.run(())
-        >
-            <svg
-                class="w-4 h-4"
-                xmlns="http://www.w3.org/2000/svg"
-                width="24"
-                height="24"
-                viewBox="0 0 24 24"
-                fill="none"
-                stroke="currentColor"
-                stroke-width="2"
-                stroke-linecap="round"
-                stroke-linejoin="round"
+            <SheetPrimitive::Content
+                attr:class=move || cn!(BASE_SHEET_CLASSES, variant_class, class.get())
+                attr:style=move || style.get()
             >
-                <line x1="18" y1="6" x2="6" y2="18"></line>
-                <line x1="6" y1="6" x2="18" y2="18"></line>
-            </svg>
-            <span class="sr-only">"Close"</span>
-        </button>
+                {children.with_value(|children| children())}
+                <SheetPrimitive::Close attr:class="absolute top-4 right-4 rounded-sm opacity-70 transition-opacity hover:opacity-100 focus:ring-2 focus:ring-offset-2 focus:outline-none disabled:pointer-events-none ring-offset-background data-[state=open]:bg-secondary focus:ring-ring">
+                    <svg
+                        class="w-4 h-4"
+                        xmlns="http://www.w3.org/2000/svg"
+                        width="24"
+                        height="24"
+                        viewBox="0 0 24 24"
+                        fill="none"
+                        stroke="currentColor"
+                        stroke-width="2"
+                        stroke-linecap="round"
+                        stroke-linejoin="round"
+                    >
+                        <line x1="18" y1="6" x2="6" y2="18"></line>
+                        <line x1="6" y1="6" x2="18" y2="18"></line>
+                    </svg>
+                    <span class="sr-only">"Close"</span>
+                </SheetPrimitive::Close>
+            </SheetPrimitive::Content>
+        </SheetPortal>
     }
 }
 
-// Other components remain the same...
-
-// Other components remain the same...
-
-// Rest of the components remain the same...
-
 #[component]
 #[allow(non_snake_case)]
 pub fn SheetHeader(
@@ -223,20 +140,30 @@ pub fn SheetFooter(
     }
 }
 
-#[component]
+#[component(transparent)]
 #[allow(non_snake_case)]
 pub fn SheetTitle(
-    #[prop(optional, into)] class: String,
-    children: Children,
+    #[prop(optional, into)] class: MaybeProp<String>,
+    children: ChildrenFn,
 ) -> impl IntoView {
-    view! { <h2 class=cn!("text-lg font-semibold text-foreground", &class)>{children()}</h2> }
+    let children = StoredValue::new(children);
+    view! {
+        <SheetPrimitive::Title attr:class=move || cn!("text-lg font-semibold text-foreground", class.get())>
+            {children.with_value(|children| children())}
+        </SheetPrimitive::Title>
+    }
 }
 
-#[component]
+#[component(transparent)]
 #[allow(non_snake_case)]
 pub fn SheetDescription(
-    #[prop(optional, into)] class: String,
-    children: Children,
+    #[prop(optional, into)] class: MaybeProp<String>,
+    children: ChildrenFn,
 ) -> impl IntoView {
-    view! { <p class=cn!("text-sm text-muted-foreground", &class)>{children()}</p> }
+    let children = StoredValue::new(children);
+    view! {
+        <SheetPrimitive::Description attr:class=move || cn!("text-sm text-muted-foreground", class.get())>
+            {children.with_value(|children| children())}
+        </SheetPrimitive::Description>
+    }
 }
@@ -9,13 +9,16 @@ use leptos::{html::{Li, Ul}};
 use codee::string::FromToStringCodec;
 use derive_more::Display;
 use leptos_use::{use_cookie_with_options, SameSite, UseCookieOptions};
+use radix_leptos_drawer::DrawerDirection;
 use radix_leptos_primitive::Primitive;
+use radix_leptos_tooltip::{Align, Side};
 use serde::{Deserialize, Serialize};
 use tailwind_fuse::*;
 
 // Local crate imports
 use crate::components::hooks::use_is_mobile;
 use crate::components::hooks::use_keypress::{use_keypress, Key};
+use crate::components::ui::tooltip::{Tooltip, TooltipContent, TooltipProvider, TooltipTrigger};
 
 /// Name of the cookie used to store sidebar state
 pub const SIDEBAR_COOKIE_NAME: &str = "sidebar:state";
@@ -145,24 +148,27 @@ pub fn SidebarProvider(
     );
     let style = StoredValue::new(style);
     view! {
-        // <TooltipProvider>
-        <div
-            class=class
-            style=move || {
-                format!(
-                    "--sidebar-width: {}; --sidebar-width-icon: {};{}",
-                    SIDEBAR_WIDTH,
-                    SIDEBAR_WIDTH_ICON,
-                    style.with_value(|x| x.clone().unwrap_or_default()),
-                )
-            }
-        >
-            {children()}
-        </div>
+        <TooltipProvider delay_duration=0.0>
+            <div
+                class=class
+                style=move || {
+                    format!(
+                        "--sidebar-width: {}; --sidebar-width-icon: {};{}",
+                        SIDEBAR_WIDTH,
+                        SIDEBAR_WIDTH_ICON,
+                        style.with_value(|x| x.clone().unwrap_or_default()),
+                    )
+                }
+            >
+                {children()}
+            </div>
+        </TooltipProvider>
     }
 }
 
-use crate::components::ui::sheet::{Sheet, SheetContent, SheetSide};
+use crate::components::ui::drawer::{
+    Drawer, DrawerContent, DrawerDescription, DrawerHeader, DrawerTitle,
+};
 use leptos::*;
 use leptos_router::components::A;
 use tailwind_fuse::*;
@@ -290,9 +296,13 @@ pub fn Sidebar(
         group-data-[variant=floating]:shadow",
     );
 
-    let sheet_styles = StoredValue::new(
-        "w-[--sidebar-width] bg-sidebar p-0 text-sidebar-foreground [&>button]:hidden",
+    let drawer_styles = StoredValue::new(
+        "w-[--sidebar-width] bg-sidebar p-0 text-sidebar-foreground",
     );
+    let drawer_direction = match side.with_value(|s| s.clone()) {
+        SidebarSide::Left => DrawerDirection::Left,
+        SidebarSide::Right => DrawerDirection::Right,
+    };
 
     let children = StoredValue::new(children);
 
@@ -346,22 +356,29 @@ pub fn Sidebar(
                     }
                 }
             >
-                // Mobile sidebar with Sheet
-                <Sheet open=open_mobile on_open_change=set_open_mobile>
-                    <SheetContent
-                        class=sheet_styles.with_value(|x| x.to_string())
-                        attr:data-sidebar="sidebar"
-                        attr:data-mobile="true"
-                        attr:style=format!("--sidebar-width: {}", SIDEBAR_WIDTH_MOBILE)
-                        side=match side.with_value(|s| s.clone()) {
-                            SidebarSide::Left => SheetSide::Left,
-                            SidebarSide::Right => SheetSide::Right,
-                        }
+                // Mobile sidebar in a drawer that can be swiped back towards its side
+                <Drawer
+                    open=open_mobile
+                    on_open_change=set_open_mobile
+                    direction=drawer_direction
+                >
+                    <DrawerContent
+                        class=drawer_styles.with_value(|x| x.to_string())
+                        style=format!("--sidebar-width: {}", SIDEBAR_WIDTH_MOBILE)
                     >
-                        <div class=content_styles
-                            .with_value(|x| *x)>{children.with_value(|c| c())}</div>
-                    </SheetContent>
-                </Sheet>
+                        <DrawerHeader class="sr-only">
+                            <DrawerTitle>"Sidebar"</DrawerTitle>
+                            <DrawerDescription>"Displays the mobile sidebar."</DrawerDescription>
+                        </DrawerHeader>
+                        <div
+                            class=content_styles.with_value(|x| *x)
+                            data-sidebar="sidebar"
+                            data-mobile="true"
+                        >
+                            {children.with_value(|c| c())}
+                        </div>
+                    </DrawerContent>
+                </Drawer>
             </Show>
         </Show>
     }
@@ -696,61 +713,51 @@ pub fn SidebarMenuButton(
     /// Children elements
     children: TypedChildrenFn<impl IntoView + 'static>,
 ) -> impl IntoView {
+    let children = StoredValue::new(children.into_inner());
     let class = Signal::derive(move ||
         SidebarMenuButtonStyles::builder()
             .variant(variant.unwrap_or_default())
             .size(size.unwrap_or_default())
             .with_class(class.get().unwrap_or_default()),
     );
-    view! {
+    let button = move || view! {
         <Primitive
             element=html::button
             as_child=as_child
-            children=children
             node_ref={node_ref}
             {..}
             class=move || class.get()
             data-sidebar="menu-button"
             data-active=move || is_active.get().unwrap_or_default().to_string()
             on:click=on_click.into_handler()
-        />
-    }
+        >
+            {children.with_value(|children| children())}
+        </Primitive>
+    };
 
-    // // Return early if no tooltip
-    // if tooltip.is_none() {
-    //     return button();
-    // }
-    //
-    // // Wrap with tooltip if provided
-    // let sidebar = use_sidebar();
-    //
-    // view! {
-    //     <Tooltip>
-    //         <TooltipTrigger as_child=true>
-    //             {button()}
-    //         </TooltipTrigger>
-    //         {move || match &tooltip {
-    //             Some(TooltipProp::String(text)) => view! {
-    //                 <TooltipContent
-    //                     side="right"
-    //                     align="center"
-    //                     hidden=sidebar.state.get() != "collapsed" || sidebar.is_mobile.get()
-    //                 >
-    //                     {text}
-    //                 </TooltipContent>
-    //             },
-    //             Some(TooltipProp::Props(props)) => view! {
-    //                 <TooltipContent
-    //                     ..props.clone()
-    //                     hidden=props.hidden.unwrap_or_else(||
-    //                         sidebar.state.get() != "collapsed" || sidebar.is_mobile.get()
-    //                     )
-    //                 />
-    //             },
-    //             None => view! { <></> }
-    //         }}
-    //     </Tooltip>
-    // }
+    // Return early if no tooltip
+    let Some(tooltip) = tooltip else {
+        return button().into_any();
+    };
+
+    // Wrap with tooltip if provided, only shown while the sidebar is collapsed to icons
+    let sidebar = use_sidebar();
+
+    view! {
+        <Tooltip>
+            <TooltipTrigger as_child=true>{button()}</TooltipTrigger>
+            <TooltipContent
+                side=Side::Right
+                align=Align::Center
+                hidden=Signal::derive(move || {
+                    sidebar.state.get() != SidebarState::Collapsed || sidebar.is_mobile.get()
+                })
+            >
+                {tooltip.clone()}
+            </TooltipContent>
+        </Tooltip>
+    }
+    .into_any()
 }
 
 #[component]
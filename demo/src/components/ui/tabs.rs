@@ -0,0 +1,72 @@
+use leptos::prelude::*;
+use radix_leptos_tabs::primitive as TabsPrimitive;
+
+use crate::cn;
+
+pub use TabsPrimitive::Root as Tabs;
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn TabsList(
+    #[prop(optional, into)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    view! {
+        <TabsPrimitive::List
+            attr:class=move || {
+                cn!(
+                    "inline-flex h-9 items-center justify-center rounded-lg bg-muted p-1 text-muted-foreground", class.get()
+                )
+            }
+        >
+            {children.with_value(|children| children())}
+        </TabsPrimitive::List>
+    }
+}
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn TabsTrigger(
+    #[prop(into)] value: String,
+    #[prop(optional, into)] disabled: MaybeProp<bool>,
+    #[prop(optional, into)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    view! {
+        <TabsPrimitive::Trigger
+            value=value
+            disabled=disabled
+            attr:class=move || {
+                cn!(
+                    "inline-flex items-center justify-center whitespace-nowrap rounded-md px-3 py-1 text-sm font-medium ring-offset-background transition-all focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50 data-[state=active]:bg-background data-[state=active]:text-foreground data-[state=active]:shadow", class.get()
+                )
+            }
+        >
+            {children.with_value(|children| children())}
+        </TabsPrimitive::Trigger>
+    }
+}
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn TabsContent(
+    #[prop(into)] value: String,
+    #[prop(optional, into)] class: MaybeProp<String>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    view! {
+        <TabsPrimitive::Content
+            value=value
+            attr:class=move || {
+                cn!(
+                    "mt-2 ring-offset-background focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2", class.get()
+                )
+            }
+        >
+            {children.with_value(|children| children())}
+        </TabsPrimitive::Content>
+    }
+}
@@ -0,0 +1,44 @@
+use leptos::{component, prelude::*};
+use leptos_node_ref::prelude::AnyNodeRef;
+use radix_leptos_tooltip::primitive as TooltipPrimitive;
+use radix_leptos_tooltip::{Align, Side};
+
+pub use TooltipPrimitive::Provider as TooltipProvider;
+
+pub use TooltipPrimitive::Root as Tooltip;
+
+pub use TooltipPrimitive::Trigger as TooltipTrigger;
+
+use crate::cn;
+
+#[component(transparent)]
+#[allow(non_snake_case)]
+pub fn TooltipContent(
+    children: ChildrenFn,
+    #[prop(into, optional)] class: MaybeProp<String>,
+    #[prop(into, optional)] side: MaybeProp<Side>,
+    #[prop(into, optional)] align: MaybeProp<Align>,
+    #[prop(into, optional, default = 4.0.into())] side_offset: MaybeProp<f64>,
+    #[prop(into, optional)] hidden: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+    view! {
+        <TooltipPrimitive::Portal>
+            <TooltipPrimitive::Content
+                side=side
+                align=align
+                side_offset=side_offset
+                attr:class=move || {
+                    cn!(
+                        "z-50 overflow-hidden rounded-md bg-primary px-3 py-1.5 text-xs text-primary-foreground animate-in fade-in-0 zoom-in-95 data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=closed]:zoom-out-95 data-[side=bottom]:slide-in-from-top-2 data-[side=left]:slide-in-from-right-2 data-[side=right]:slide-in-from-left-2 data-[side=top]:slide-in-from-bottom-2", class.get()
+                    )
+                }
+                attr:hidden=move || hidden.get().unwrap_or_default()
+                node_ref=node_ref
+            >
+                {children.with_value(|children| children())}
+            </TooltipPrimitive::Content>
+        </TooltipPrimitive::Portal>
+    }
+}
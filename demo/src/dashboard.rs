@@ -7,6 +7,7 @@ use crate::components::navigation::app_sidebar::AppSidebar;
 use crate::components::ui::breadcrumb::{Breadcrumb, BreadcrumbItem, BreadcrumbLink, BreadcrumbList, BreadcrumbPage, BreadcrumbSeparator};
 use crate::components::ui::separator::{Separator, SeparatorOrientation};
 use crate::components::ui::sidebar::{SidebarInset, SidebarProvider, SidebarTrigger};
+use crate::components::ui::tabs::{Tabs, TabsContent, TabsList, TabsTrigger};
 use crate::demo::DropdownMenuDemo;
 
 #[component]
@@ -35,13 +36,23 @@ pub fn Page() -> impl IntoView {
                     </div>
                 </header>
                 <div class="flex flex-1 flex-col gap-4 p-4 pt-0">
-                    <DropdownMenuDemo/>
-                    // <div class="grid auto-rows-min gap-4 md:grid-cols-3">
-                    //     <div class="aspect-video rounded-xl bg-muted/50" />
-                    //     <div class="aspect-video rounded-xl bg-muted/50" />
-                    //     <div class="aspect-video rounded-xl bg-muted/50" />
-                    // </div>
-                    // <div class="min-h-[100vh] flex-1 rounded-xl bg-muted/50 md:min-h-min" />
+                    <Tabs default_value="demo">
+                        <TabsList>
+                            <TabsTrigger value="demo">"Dropdown Menu"</TabsTrigger>
+                            <TabsTrigger value="overview">"Overview"</TabsTrigger>
+                        </TabsList>
+                        <TabsContent value="demo">
+                            <DropdownMenuDemo/>
+                        </TabsContent>
+                        <TabsContent value="overview" class="flex flex-col gap-4">
+                            <div class="grid auto-rows-min gap-4 md:grid-cols-3">
+                                <div class="aspect-video rounded-xl bg-muted/50" />
+                                <div class="aspect-video rounded-xl bg-muted/50" />
+                                <div class="aspect-video rounded-xl bg-muted/50" />
+                            </div>
+                            <div class="min-h-[100vh] flex-1 rounded-xl bg-muted/50 md:min-h-min" />
+                        </TabsContent>
+                    </Tabs>
                 </div>
             </SidebarInset>
         </SidebarProvider>
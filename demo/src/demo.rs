@@ -1,6 +1,10 @@
 
-use leptos::prelude::*;
+use leptos::{ev, prelude::*};
 use crate::components::ui::button::{Button, ButtonVariant};
+use crate::components::ui::command::{
+    CommandDialog, CommandEmpty, CommandGroup, CommandInput, CommandItem, CommandList,
+    CommandSeparator, CommandShortcut,
+};
 use crate::components::ui::dropdown_menu::{
     DropdownMenu, DropdownMenuContent, DropdownMenuGroup, DropdownMenuItem,
     DropdownMenuLabel, DropdownMenuPortal, DropdownMenuSeparator,
@@ -11,7 +15,58 @@ use crate::components::ui::dropdown_menu::{
 #[component]
 #[allow(non_snake_case)]
 pub fn DropdownMenuDemo() -> impl IntoView {
+    let command_open = RwSignal::new(false);
+
+    // Toggle the command palette with ⌘K (Ctrl+K outside of macOS).
+    // The palette handles Ctrl+K itself while open, for navigation, and prevents its default.
+    let handle = window_event_listener(ev::keydown, move |event| {
+        if !event.default_prevented() && event.key() == "k" && (event.meta_key() || event.ctrl_key()) {
+            event.prevent_default();
+            command_open.update(|open| *open = !*open);
+        }
+    });
+    on_cleanup(move || handle.remove());
+
+    let close_command = Callback::new(move |_: String| command_open.set(false));
+
     view! {
+        <CommandDialog
+            open=command_open
+            on_open_change=Callback::new(move |open| command_open.set(open))
+        >
+            <CommandInput placeholder="Type a command or search..." />
+            <CommandList>
+                <CommandEmpty>"No results found."</CommandEmpty>
+                <CommandGroup heading="Account">
+                    <CommandItem value="Profile" on_select=close_command>
+                        "Profile" <CommandShortcut>"⇧⌘P"</CommandShortcut>
+                    </CommandItem>
+                    <CommandItem value="Billing" on_select=close_command>
+                        "Billing" <CommandShortcut>"⌘B"</CommandShortcut>
+                    </CommandItem>
+                    <CommandItem value="Settings" keywords=vec!["preferences".to_string()] on_select=close_command>
+                        "Settings" <CommandShortcut>"⌘S"</CommandShortcut>
+                    </CommandItem>
+                </CommandGroup>
+                <CommandSeparator />
+                <CommandGroup heading="Team">
+                    <CommandItem value="New Team" on_select=close_command>
+                        "New Team" <CommandShortcut>"⌘+T"</CommandShortcut>
+                    </CommandItem>
+                    <CommandItem value="Invite users" keywords=vec!["email".to_string(), "message".to_string()] on_select=close_command>
+                        "Invite users"
+                    </CommandItem>
+                </CommandGroup>
+                <CommandSeparator />
+                <CommandGroup heading="Help">
+                    <CommandItem value="GitHub" on_select=close_command>"GitHub"</CommandItem>
+                    <CommandItem value="Support" on_select=close_command>"Support"</CommandItem>
+                    <CommandItem value="Log out" on_select=close_command>
+                        "Log out" <CommandShortcut>"⇧⌘Q"</CommandShortcut>
+                    </CommandItem>
+                </CommandGroup>
+            </CommandList>
+        </CommandDialog>
         <DropdownMenu>
             <DropdownMenuTrigger as_child=true>
                 <Button variant=ButtonVariant::Outline>"Open"</Button>
@@ -29,7 +84,7 @@ pub fn DropdownMenuDemo() -> impl IntoView {
                     <DropdownMenuItem>
                         "Settings" <DropdownMenuShortcut>"⌘S"</DropdownMenuShortcut>
                     </DropdownMenuItem>
-                    <DropdownMenuItem>
+                    <DropdownMenuItem on_select=Callback::new(move |_| command_open.set(true))>
                         "Keyboard shortcuts" <DropdownMenuShortcut>"⌘K"</DropdownMenuShortcut>
                     </DropdownMenuItem>
                 </DropdownMenuGroup>
@@ -39,10 +39,23 @@ module.exports = {
                 'sans': ['Inter', 'system-ui', 'sans-serif'],
                 'mono': ['Fira Code', 'monospace'],
             },
+            keyframes: {
+                // Collapsible content, sized by `--radix-collapsible-content-height`
+                'collapsible-down': {
+                    from: { height: '0' },
+                    to: { height: 'var(--radix-collapsible-content-height)' },
+                },
+                'collapsible-up': {
+                    from: { height: 'var(--radix-collapsible-content-height)' },
+                    to: { height: '0' },
+                },
+            },
             animation: {
                 // Custom animations
                 'spin-slow': 'spin 3s linear infinite',
                 'pulse-slow': 'pulse 2s cubic-bezier(0.4, 0, 0.6, 1) infinite',
+                'collapsible-down': 'collapsible-down 0.2s ease-out',
+                'collapsible-up': 'collapsible-up 0.2s ease-out',
             },
         },
     },